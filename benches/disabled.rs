@@ -0,0 +1,49 @@
+use std::hint::black_box;
+
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use goolog::{
+    log::{
+        Level,
+        LevelFilter,
+        Record,
+    },
+    *,
+};
+
+fn disabled_trace(c: &mut Criterion) {
+    init_logger(
+        Some(LevelFilter::Info),
+        None,
+        #[cfg(not(feature = "wasm"))]
+        None,
+    );
+
+    let mut group = c.benchmark_group("disabled trace");
+    // the level gets checked against the cached max level before the arguments are built
+    group.bench_function("macro", |b| {
+        b.iter(|| trace!("Bench"; "The current state is {:?}", black_box([0u8; 64])))
+    });
+    // the arguments get built and the logger has to check the level itself
+    group.bench_function("logger", |b| {
+        b.iter(|| {
+            log::logger().log(
+                &Record::builder()
+                    .level(Level::Trace)
+                    .target("Bench")
+                    .args(format_args!(
+                        "The current state is {:?}",
+                        black_box([0u8; 64])
+                    ))
+                    .build(),
+            )
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, disabled_trace);
+criterion_main!(benches);
@@ -0,0 +1,169 @@
+//! This module provides the [`set_adaptive_debug`] function used to print the debug logs of a target for a while after an error.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{
+            AtomicBool,
+            AtomicUsize,
+            Ordering,
+        },
+        OnceLock,
+        RwLock,
+    },
+    time::Duration,
+};
+
+use log::{
+    Level,
+    LevelFilter,
+    Metadata,
+    Record,
+};
+
+use crate::{
+    delta,
+    target_level,
+};
+
+/// The targets whose debug logs get printed for a while after an error. \
+/// Every target is mapped to the time its debug logs get printed for and the time, as returned by [`delta::now`], they will be
+/// printed until.
+static ADAPTIVE_TARGETS: OnceLock<RwLock<HashMap<String, (Duration, u64)>>> = OnceLock::new();
+
+/// Whether any target has been set using [`set_adaptive_debug`]. \
+/// This lets error logs skip locking the targets while the feature is not used.
+static ANY_TARGETS: AtomicBool = AtomicBool::new(false);
+
+/// The log level the goolog logger has been initiated with. \
+/// [`usize::MAX`] means the goolog logger has not been initiated yet.
+static LOG_LEVEL: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// Get the targets whose debug logs get printed for a while after an error.
+fn adaptive_targets() -> &'static RwLock<HashMap<String, (Duration, u64)>> {
+    ADAPTIVE_TARGETS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Print the debug logs of the given `target` for the given `window` of time after every error sent under it. \
+/// \
+/// Running at the debug level all the time is often too noisy, while the debug logs sent right after a failure are exactly the
+/// ones needed to understand it. Once an error gets logged under the target, its debug logs get printed until `window` has
+/// passed, even if the log level of the goolog logger is `info` or lower. Every further error restarts the window. Passing
+/// [`None`] will restore the default of never printing more logs of the target than the log level allows. Trace logs and
+/// the log file are not affected.
+///
+/// Targets can be added or removed at any time, before or after [`init_logger`](crate::init_logger). As long as any target has
+/// been set, the maximum level of the [log] crate is raised to debug, so every debug log is checked against the targets before it
+/// gets formatted.
+///
+/// # Thread-safety
+///
+/// The targets and the time their debug logs get printed until are shared across all threads. An error sent on one thread
+/// therefore also makes the debug logs of the same target sent on every other thread get printed.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_adaptive_debug("Database", Some(Duration::from_secs(30)));
+///
+/// // this will not be printed
+/// debug!("Database"; "Opening a connection...");
+/// error!("Database"; "The connection has been refused.");
+/// // but this will, since it has been sent less than 30 seconds after the error
+/// debug!("Database"; "Retrying in 5 seconds...");
+/// # }
+/// ```
+pub fn set_adaptive_debug(target: &str, window: Option<Duration>) {
+    let mut adaptive_targets = adaptive_targets()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match window {
+        Some(window) => {
+            adaptive_targets.insert(target.to_string(), (window, 0));
+        }
+        None => {
+            adaptive_targets.remove(target);
+        }
+    }
+    ANY_TARGETS.store(!adaptive_targets.is_empty(), Ordering::Relaxed);
+    drop(adaptive_targets);
+
+    target_level::refresh_max_level();
+}
+
+/// Get the time the debug logs of the given `target` get printed for after an error, if it has been set using
+/// [`set_adaptive_debug`].
+pub fn adaptive_debug(target: &str) -> Option<Duration> {
+    adaptive_targets()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(target)
+        .map(|(window, _)| *window)
+}
+
+/// Get the level the destinations of a logger with the given `log_level` need to handle for [`set_adaptive_debug`] to work.
+pub(crate) fn print_level(log_level: LevelFilter) -> LevelFilter {
+    match ANY_TARGETS.load(Ordering::Relaxed) {
+        true => log_level.max(LevelFilter::Debug),
+        false => log_level,
+    }
+}
+
+/// Remember the given `log_level` the goolog logger has been initiated with, so debug logs reaching the goolog logger only
+/// because of [`set_adaptive_debug`] can be told apart. This needs to be called once the goolog logger has been set.
+pub(crate) fn init(log_level: LevelFilter) {
+    LOG_LEVEL.store(log_level as usize, Ordering::Relaxed);
+}
+
+/// Start the window of the target of the given `record` if it is an error and the target has been set using
+/// [`set_adaptive_debug`].
+pub(crate) fn track(record: &Record) {
+    if record.level() != Level::Error || !ANY_TARGETS.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let mut adaptive_targets = adaptive_targets()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some((window, until)) = adaptive_targets.get_mut(record.target()) {
+        *until = delta::now().saturating_add(window.as_micros() as u64);
+    }
+}
+
+/// Check whether a log with the given `metadata` passes the given `log_level` or is a debug log sent during the window of its
+/// target.
+pub(crate) fn allows(metadata: &Metadata, log_level: LevelFilter) -> bool {
+    if metadata.level() <= log_level {
+        return true;
+    }
+    if metadata.level() != Level::Debug || !ANY_TARGETS.load(Ordering::Relaxed) {
+        return false;
+    }
+
+    adaptive_targets()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(metadata.target())
+        .is_some_and(|(_, until)| delta::now() < *until)
+}
+
+/// Check whether a log with the given `metadata` reached the goolog logger only because of [`set_adaptive_debug`], but gets
+/// suppressed by the log level since it has been sent outside the window of its target.
+pub(crate) fn suppresses(metadata: &Metadata) -> bool {
+    let log_level = match LOG_LEVEL.load(Ordering::Relaxed) {
+        usize::MAX => return false,
+        log_level => LevelFilter::iter()
+            .nth(log_level)
+            .unwrap_or(LevelFilter::Trace),
+    };
+
+    !allows(
+        metadata,
+        target_level::level_for(metadata.target(), log_level),
+    )
+}
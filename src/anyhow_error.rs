@@ -0,0 +1,20 @@
+//! This module provides the function used by the [`log_anyhow!`](crate::log_anyhow) macro to log an [`anyhow::Error`].
+
+use std::backtrace::BacktraceStatus;
+
+use log::Level;
+
+/// # DO NOT TOUCH THIS FUNCTION
+///
+/// Log the given `error` together with its context chain at the given `level`. If a backtrace has been captured, it will be sent
+/// at the trace level. This is used by the [`log_anyhow!`](crate::log_anyhow) macro.
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub fn INTERNAL__log_anyhow(level: Level, target: &str, error: &anyhow::Error) {
+    log::log!(target: target, level, "{error:#}");
+
+    let backtrace = error.backtrace();
+    if backtrace.status() == BacktraceStatus::Captured {
+        log::log!(target: target, Level::Trace, "Backtrace:\n{backtrace}");
+    }
+}
@@ -0,0 +1,106 @@
+//! This module provides the [`set_audit_writer`] function used to set the destination of the [`audit!`](crate::audit) macro.
+
+use std::{
+    fmt::{
+        Display,
+        Write as _,
+    },
+    io::Write,
+    sync::Mutex,
+    time::{
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+use crate::dropped;
+
+/// The writer every audit event gets written to. \
+/// [`None`] means audit events will be dropped.
+static AUDIT_WRITER: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+
+/// Set the `writer` every audit event sent by the [`audit!`](crate::audit) macro gets written to. \
+/// \
+/// Audit events are kept apart from the other logs: they are never printed to the console, the log file or any
+/// [`Sink`](crate::Sink). Every event is written as a single line of JSON and flushed right away, so it does not get lost if the
+/// application crashes afterwards. To keep the audit trail append-only, open the file in append mode, like in the example below.
+/// While no writer has been set, or if writing an event fails, the event will be counted as dropped, see
+/// [`dropped_count`](crate::dropped_count). Passing [`None`] removes the writer.
+///
+/// # Example
+///
+/// ```
+/// use std::fs::OpenOptions;
+///
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+///
+/// let audit_file = OpenOptions::new().create(true).append(true).open("audit.jsonl").unwrap();
+/// set_audit_writer(Some(Box::new(audit_file)));
+///
+/// audit!("Auth"; user = "alice", action = "login");
+/// # std::fs::remove_file("audit.jsonl").ok();
+/// # }
+/// ```
+pub fn set_audit_writer(writer: Option<Box<dyn Write + Send>>) {
+    *AUDIT_WRITER
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = writer;
+}
+
+/// # DO NOT TOUCH THIS FUNCTION
+///
+/// Write an audit event with the given `target` and `fields` to the writer set using [`set_audit_writer`]. This is used by the
+/// [`audit!`](crate::audit) macro.
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub fn INTERNAL__audit(target: &str, fields: &[(&str, &dyn Display)]) {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis())
+        .unwrap_or_default();
+
+    let mut line = format!("{{\"timestamp\":{timestamp},\"target\":");
+    push_json_string(&mut line, target);
+    for (key, value) in fields {
+        line.push(',');
+        push_json_string(&mut line, key);
+        line.push(':');
+        push_json_string(&mut line, &value.to_string());
+    }
+    line.push_str("}\n");
+
+    let mut writer = AUDIT_WRITER
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let written = writer.as_mut().is_some_and(|writer| {
+        writer
+            .write_all(line.as_bytes())
+            .and_then(|_| writer.flush())
+            .is_ok()
+    });
+    drop(writer);
+    if !written {
+        dropped::record_drop();
+    }
+}
+
+/// Append the given `value` to `out` as a JSON string.
+fn push_json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for char in value.chars() {
+        match char {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            char if char.is_control() => {
+                write!(out, "\\u{:04x}", char as u32).ok();
+            }
+            char => out.push(char),
+        }
+    }
+    out.push('"');
+}
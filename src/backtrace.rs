@@ -0,0 +1,24 @@
+//! This module provides the function used by the [`log_backtrace!`](crate::log_backtrace) macro to render the current backtrace.
+
+use std::backtrace::{
+    Backtrace,
+    BacktraceStatus,
+};
+
+/// # DO NOT TOUCH THIS FUNCTION
+///
+/// Capture the current backtrace and render it, or explain why it could not be captured. This is used by the
+/// [`log_backtrace!`](crate::log_backtrace) macro.
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub fn INTERNAL__backtrace() -> String {
+    let backtrace = Backtrace::capture();
+    match backtrace.status() {
+        BacktraceStatus::Captured => format!("Backtrace:\n{backtrace}"),
+        BacktraceStatus::Disabled => {
+            "Backtrace: disabled, set `RUST_BACKTRACE=1` or `RUST_LIB_BACKTRACE=1` to capture it"
+                .to_string()
+        }
+        _ => "Backtrace: not supported on this platform".to_string(),
+    }
+}
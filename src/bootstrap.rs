@@ -0,0 +1,256 @@
+//! This module provides the [`pre_init`] and [`set_uninitialized_behavior`] functions used to handle logs sent before the goolog
+//! logger has been initiated.
+
+use std::sync::{
+    atomic::{
+        AtomicBool,
+        AtomicU8,
+        Ordering,
+    },
+    Mutex,
+    OnceLock,
+};
+
+use log::{
+    Level,
+    LevelFilter,
+    Log,
+    Metadata,
+    Record,
+    SetLoggerError,
+};
+
+use crate::dropped;
+
+/// The maximum number of logs buffered by the bootstrap logger. \
+/// Any log sent after this limit has been reached will be dropped.
+pub const PRE_INIT_BUFFER_CAP: usize = 1024;
+
+/// The bootstrap logger installed by [`pre_init`].
+static BOOTSTRAP_LOGGER: BootstrapLogger = BootstrapLogger {
+    buffer: Mutex::new(Vec::new()),
+    logger: OnceLock::new(),
+    buffering: AtomicBool::new(false),
+    replayed: AtomicBool::new(false),
+};
+
+/// What happens to logs sent before the goolog logger has been initiated. \
+/// This is stored as the discriminant of an [`Uninit`] so no lock is needed to read it.
+static UNINIT: AtomicU8 = AtomicU8::new(Uninit::Silent as u8);
+
+/// A log buffered by the [`BootstrapLogger`].
+struct BufferedRecord {
+    /// The level of the log.
+    level: Level,
+    /// The target of the log.
+    target: String,
+    /// The already formatted message of the log.
+    message: String,
+}
+
+/// A logger buffering every log until the goolog logger has been initiated.
+struct BootstrapLogger {
+    /// The logs sent before the goolog logger has been initiated.
+    buffer: Mutex<Vec<BufferedRecord>>,
+    /// The goolog logger every log will be forwarded to once it has been initiated.
+    logger: OnceLock<Box<dyn Log>>,
+    /// Whether logs get buffered, which is the case once [`pre_init`] has been called.
+    buffering: AtomicBool,
+    /// Whether the buffered logs have already been replayed.
+    replayed: AtomicBool,
+}
+impl Log for BootstrapLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        match self.logger.get() {
+            Some(logger) => logger.enabled(metadata),
+            None => true,
+        }
+    }
+
+    fn log(&self, record: &Record) {
+        if self.replayed.load(Ordering::Acquire) {
+            if let Some(logger) = self.logger.get() {
+                logger.log(record);
+            }
+            return;
+        }
+
+        let mut buffer = self
+            .buffer
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        // the logger may have been set while we were waiting on the lock
+        if let Some(logger) = self.logger.get() {
+            logger.log(record);
+        } else if !self.buffering.load(Ordering::Acquire) {
+            drop(buffer);
+            log_uninitialized(record);
+        } else if buffer.len() < PRE_INIT_BUFFER_CAP {
+            buffer.push(BufferedRecord {
+                level: record.level(),
+                target: record.target().to_string(),
+                message: record.args().to_string(),
+            });
+        } else {
+            dropped::record_drop();
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(logger) = self.logger.get() {
+            logger.flush();
+        }
+    }
+}
+
+/// Install a lightweight bootstrap logger buffering every log sent before [`init_logger`](crate::init_logger) gets called. \
+/// \
+/// Once the goolog logger has been initiated, all buffered logs will be replayed through it in the order they were sent and before
+/// any new log. This is useful when logs are sent while parsing the configuration which determines how the goolog logger should be
+/// initiated. \
+/// \
+/// At most [`PRE_INIT_BUFFER_CAP`] logs will be buffered. Any log sent after this limit has been reached will be dropped. Since
+/// the logs will be formatted while being replayed, their timestamps will show the time of the replay.
+///
+/// # Panics
+///
+/// This function will panic if a global logger has already been set.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// pre_init();
+///
+/// // this will be printed once the logger has been initiated
+/// info!("Main"; "Parsing the config file...");
+///
+/// init_logger(None, None, None);
+/// # }
+/// ```
+pub fn pre_init() {
+    BOOTSTRAP_LOGGER.buffering.store(true, Ordering::Release);
+    if !is_active() {
+        log::set_logger(&BOOTSTRAP_LOGGER)
+            .unwrap_or_else(|error| crate::fatal!(crate::GOOLOG_CALLER; "Failed to install the bootstrap logger. Error: {error}"));
+        log::set_max_level(LevelFilter::Trace);
+    }
+}
+
+/// This enum describes what happens to logs sent before the goolog logger has been initiated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Uninit {
+    /// The logs will be dropped silently. This is the default.
+    #[default]
+    Silent,
+    /// The logs will be printed to stderr without any formatting.
+    Stderr,
+    /// Sending a log will panic in debug builds. In release builds, the logs will be printed to stderr instead.
+    Panic,
+}
+
+/// Set what happens to logs sent before the goolog logger has been initiated. \
+/// \
+/// By default, such logs vanish silently, which can make a logger initiated too late hard to notice. Using [`Uninit::Stderr`] or
+/// [`Uninit::Panic`] helps to catch these setup-order bugs. Once [`init_logger`](crate::init_logger) has been called, logs will be
+/// handled normally again. Logs buffered using [`pre_init`] are not affected by this setting.
+///
+/// # Panics
+///
+/// This function will panic if a global logger other than the goolog logger has already been set.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// set_uninitialized_behavior(Uninit::Stderr);
+///
+/// // this will be printed to stderr as `Main | INFO | Hello World!`
+/// info!("Main"; "Hello World!");
+///
+/// init_logger(None, None, None);
+/// # }
+/// ```
+pub fn set_uninitialized_behavior(behavior: Uninit) {
+    UNINIT.store(behavior as u8, Ordering::Relaxed);
+    // once the goolog logger has been initiated, there is nothing left to do
+    if behavior == Uninit::Silent || is_active() || crate::INTERNAL__LOGGER_ACTIVE.get().is_some() {
+        return;
+    }
+
+    log::set_logger(&BOOTSTRAP_LOGGER)
+        .unwrap_or_else(|error| crate::fatal!(crate::GOOLOG_CALLER; "Failed to install the bootstrap logger. Error: {error}"));
+    log::set_max_level(LevelFilter::Trace);
+}
+
+/// Get what happens to logs sent before the goolog logger has been initiated.
+pub fn uninitialized_behavior() -> Uninit {
+    match UNINIT.load(Ordering::Relaxed) {
+        1 => Uninit::Stderr,
+        2 => Uninit::Panic,
+        _ => Uninit::Silent,
+    }
+}
+
+/// Handle the given `record` sent before the goolog logger has been initiated as set by [`set_uninitialized_behavior`].
+fn log_uninitialized(record: &Record) {
+    match uninitialized_behavior() {
+        Uninit::Silent => {}
+        Uninit::Panic if cfg!(debug_assertions) => panic!(
+            "A log has been sent before the goolog logger has been initiated: {} | {} | {}",
+            record.target(),
+            record.level(),
+            record.args()
+        ),
+        Uninit::Stderr | Uninit::Panic => eprintln!(
+            "{} | {} | {}",
+            record.target(),
+            record.level(),
+            record.args()
+        ),
+    }
+}
+
+/// Check whether the bootstrap logger has been installed using [`pre_init`] or [`set_uninitialized_behavior`].
+fn is_active() -> bool {
+    log::max_level() != LevelFilter::Off
+        && std::ptr::eq(
+            log::logger() as *const dyn Log as *const (),
+            &BOOTSTRAP_LOGGER as *const BootstrapLogger as *const (),
+        )
+}
+
+/// Set the given `logger` as the global logger. \
+/// If the bootstrap logger has been installed, every buffered log will be replayed through the given `logger`.
+pub(crate) fn set_logger(
+    logger: Box<dyn Log>,
+    max_level: LevelFilter,
+) -> Result<(), SetLoggerError> {
+    if !is_active() {
+        log::set_boxed_logger(logger)?;
+        log::set_max_level(max_level);
+        return Ok(());
+    }
+
+    let mut buffer = BOOTSTRAP_LOGGER
+        .buffer
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let logger = BOOTSTRAP_LOGGER.logger.get_or_init(|| logger);
+    for record in buffer.drain(..) {
+        logger.log(
+            &Record::builder()
+                .level(record.level)
+                .target(&record.target)
+                .args(format_args!("{}", record.message))
+                .build(),
+        );
+    }
+    BOOTSTRAP_LOGGER.replayed.store(true, Ordering::Release);
+    log::set_max_level(max_level);
+
+    Ok(())
+}
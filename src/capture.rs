@@ -0,0 +1,65 @@
+//! This module provides the [`capture`] function used to collect the log lines sent by a block of code.
+
+use std::cell::RefCell;
+
+use crate::format::strip_ansi;
+
+thread_local! {
+    /// The log lines collected by every [`capture`] currently running on this thread, from the outermost to the innermost one.
+    static CAPTURES: RefCell<Vec<Vec<String>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Removes the innermost capture of this thread once dropped, even if the captured closure panicked.
+struct CaptureFrame;
+impl Drop for CaptureFrame {
+    fn drop(&mut self) {
+        CAPTURES.with(|captures| captures.borrow_mut().pop());
+    }
+}
+
+/// Run the given closure and return every log line it sent, without any ANSI escape codes. \
+/// \
+/// The log lines will still be printed as usual. This can be used to attach the logs of a failed operation to an error report.
+/// Only the logs passing the log level of the console and sent on the current thread will be collected. Logs sent by other
+/// threads, including threads spawned by the closure, will not be collected. Captures can be nested: the lines collected by an
+/// inner capture will be part of the outer one too.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+///
+/// let lines = capture(|| {
+///     info!("Main"; "Hello World!");
+/// });
+///
+/// assert!(lines[0].ends_with("Main             | INFO  | Hello World!"));
+/// # }
+/// ```
+pub fn capture(f: impl FnOnce()) -> Vec<String> {
+    CAPTURES.with(|captures| captures.borrow_mut().push(vec![]));
+    let frame = CaptureFrame;
+
+    f();
+
+    let lines = CAPTURES.with(|captures| captures.borrow_mut().last_mut().map(std::mem::take));
+    drop(frame);
+    lines.unwrap_or_default()
+}
+
+/// Add the given log `line` to every capture running on this thread.
+pub(crate) fn record(line: &str) {
+    CAPTURES.with(|captures| {
+        let mut captures = captures.borrow_mut();
+        if captures.is_empty() {
+            return;
+        }
+
+        let line = strip_ansi(line);
+        for capture in captures.iter_mut() {
+            capture.push(line.clone());
+        }
+    });
+}
@@ -0,0 +1,165 @@
+//! This module provides the [`set_column_order`] function used to change the order of the columns of the default layout.
+
+use std::{
+    fmt::{
+        self,
+        Display,
+    },
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        RwLock,
+    },
+};
+
+/// The order of the columns of the default layout. \
+/// [`None`] means the default order will be used.
+static COLUMN_ORDER: RwLock<Option<Vec<Column>>> = RwLock::new(None);
+
+/// Whether a header naming the columns gets printed once while initiating the goolog logger and written to every new log file.
+static COLUMN_HEADER: AtomicBool = AtomicBool::new(false);
+
+/// The default order of the columns.
+const DEFAULT_COLUMN_ORDER: [Column; 7] = [
+    Column::Timestamp,
+    Column::Version,
+    Column::Pid,
+    Column::Delta,
+    Column::Target,
+    Column::Level,
+    Column::Message,
+];
+
+/// A column of the default layout of a log line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    /// The date and time of the log. This will be skipped if the `timestamp` feature is disabled.
+    Timestamp,
+    /// The version of the application. This will be skipped unless enabled using [`set_show_version`](crate::set_show_version)
+    /// together with the [`VersionPlacement::Line`](crate::VersionPlacement::Line).
+    Version,
+    /// The id of the process which sent the log. This will be skipped unless enabled using [`set_show_pid`](crate::set_show_pid).
+    Pid,
+    /// The time passed since the previous log. This will be skipped unless enabled using
+    /// [`set_show_delta`](crate::set_show_delta).
+    Delta,
+    /// The name under which the log was sent.
+    Target,
+    /// The level of the log.
+    Level,
+    /// The message of the log. This always needs to be the last column.
+    Message,
+}
+
+/// An error returned by [`set_column_order`] if the given order is invalid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ColumnOrderError {
+    /// The order does not contain the [`Column::Message`].
+    MissingMessage,
+    /// The [`Column::Message`] is not the last column.
+    MessageNotLast,
+    /// The order contains the given column more than once.
+    DuplicateColumn(Column),
+}
+impl Display for ColumnOrderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingMessage => write!(f, "the column order needs to contain the message"),
+            Self::MessageNotLast => write!(f, "the message needs to be the last column"),
+            Self::DuplicateColumn(column) => {
+                write!(f, "the column `{column:?}` is contained more than once")
+            }
+        }
+    }
+}
+impl std::error::Error for ColumnOrderError {}
+
+/// Set the order of the columns of the default layout. \
+/// \
+/// Columns missing from the given `order` will not be printed, but the [`Column::Message`] always needs to be the last column.
+/// Passing [`None`] will restore the default order of timestamp, version, process id, delta, target, level and message. A template set using
+/// [`set_format`](crate::set_format) and the [`LayoutPreset::Compact`](crate::LayoutPreset::Compact) layout take precedence over
+/// this order.
+///
+/// # Errors
+///
+/// This function will return an error if the message is not the last column or if a column is contained more than once. The
+/// previous order will be kept in that case.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_column_order(Some(&[Column::Level, Column::Timestamp, Column::Target, Column::Message])).unwrap();
+///
+/// // this will be printed as `INFO  | 29.05.2023 | 14:34:33 | Main             | Hello World!`
+/// info!("Main"; "Hello World!");
+/// # }
+/// ```
+pub fn set_column_order(order: Option<&[Column]>) -> Result<(), ColumnOrderError> {
+    if let Some(order) = order {
+        match order.iter().position(|column| *column == Column::Message) {
+            None => return Err(ColumnOrderError::MissingMessage),
+            Some(index) if index != order.len() - 1 => {
+                return Err(ColumnOrderError::MessageNotLast)
+            }
+            Some(_) => {}
+        }
+        for (index, column) in order.iter().enumerate() {
+            if order[..index].contains(column) {
+                return Err(ColumnOrderError::DuplicateColumn(*column));
+            }
+        }
+    }
+
+    *COLUMN_ORDER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = order.map(<[Column]>::to_vec);
+    Ok(())
+}
+
+/// Get the order of the columns of the default layout.
+pub fn column_order() -> Vec<Column> {
+    COLUMN_ORDER
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+        .unwrap_or_else(|| DEFAULT_COLUMN_ORDER.to_vec())
+}
+
+/// Set whether a header naming the columns gets printed once while initiating the goolog logger and written to every new log file,
+/// like `DATE       | TIME     | TARGET           | LEVEL | MESSAGE`. \
+/// \
+/// The width of every column in the header is computed from the settings active at the time it gets printed, like the length of
+/// caller names, the width of the level column and the order of the columns. Change these settings before initiating the logger
+/// to keep the header aligned with the log lines. A column too narrow for its name gets a shortened one. For log files, the
+/// header follows the one set using [`set_file_header`](crate::set_file_header) and is written again to every file created by a
+/// rotation. The header is only printed for the default layout, so a template set using [`set_format`](crate::set_format), the
+/// [`LayoutPreset::Compact`](crate::LayoutPreset::Compact) layout or a custom [`Formatter`](crate::Formatter) disable it. It is
+/// not printed by default, so piped output only contains log lines.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// set_column_header(true);
+/// // this will print `DATE       | TIME     | TARGET           | LEVEL | MESSAGE` first
+/// init_logger(None, None, None);
+///
+/// info!("Main"; "Hello World!");
+/// # }
+/// ```
+pub fn set_column_header(column_header: bool) {
+    COLUMN_HEADER.store(column_header, Ordering::Relaxed);
+}
+
+/// Get whether a header naming the columns gets printed once while initiating the goolog logger and written to every new log
+/// file.
+pub fn column_header() -> bool {
+    COLUMN_HEADER.load(Ordering::Relaxed)
+}
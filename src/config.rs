@@ -0,0 +1,211 @@
+//! This module provides the [`effective_config`] function used to get a snapshot of every setting of the goolog logger.
+
+use std::{
+    sync::atomic::Ordering,
+    time::Duration,
+};
+
+use fern::colors::Color;
+use log::{
+    Level,
+    LevelFilter,
+};
+
+#[cfg(not(feature = "wasm"))]
+use crate::RotationPolicy;
+use crate::{
+    format::MAX_NAME_LENGTH,
+    formatter,
+    ColorScope,
+    Column,
+    FlushPolicy,
+    LayoutPreset,
+    MultilineMode,
+    SinkInfo,
+    Template,
+    TrimMode,
+    Uninit,
+    VersionPlacement,
+};
+
+/// A snapshot of every setting of the goolog logger, as returned by [`effective_config`]. \
+/// \
+/// Every field holds the value returned by the getter of the same name at the time the snapshot has been taken.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct Config {
+    /// The maximum level of the logs to be printed.
+    pub max_level: LevelFilter,
+    /// The length of caller names given while initiating the goolog logger.
+    pub max_name_length: u32,
+    /// The length of caller names for every level, as set using [`set_target_length_for`](crate::set_target_length_for). \
+    /// The lengths are given in the order `[error, warn, info, debug, trace]`.
+    pub target_lengths: [Option<u32>; 5],
+    /// Whether log lines get colored at all.
+    pub colors_enabled: bool,
+    /// Whether the logs sent by the [`fatal!`](crate::fatal) macro get a red background on the console.
+    pub fatal_highlight: bool,
+    /// The part of a log line that gets colored based on its level.
+    pub color_scope: ColorScope,
+    /// The color of the target column.
+    pub target_color: Option<Color>,
+    /// Whether the target column gets a color picked automatically based on the target.
+    pub auto_target_colors: bool,
+    /// The number of colors used to color the target column automatically.
+    pub target_palette_size: usize,
+    /// The separator printed between the message and the columns in front of it.
+    pub target_message_separator: Option<&'static str>,
+    /// The icons printed in front of the level of every log.
+    pub level_icons: Option<[&'static str; 5]>,
+    /// The width the level column gets padded to.
+    pub level_width: usize,
+    /// The preset describing the default layout of a log line.
+    pub layout_preset: LayoutPreset,
+    /// The template used to print the log lines.
+    pub format: Option<Template>,
+    /// Whether a custom [`Formatter`](crate::Formatter) has been set using [`set_formatter`](crate::set_formatter).
+    pub custom_formatter: bool,
+    /// The order of the columns of the default layout.
+    pub column_order: Vec<Column>,
+    /// Whether a header naming the columns gets printed.
+    pub column_header: bool,
+    /// The format of the timestamp column.
+    #[cfg(feature = "timestamp")]
+    pub timestamp_format: Option<String>,
+    /// Whether the timestamp column is printed.
+    #[cfg(feature = "timestamp")]
+    pub show_timestamp: bool,
+    /// Whether a counter is appended to timestamps without sub-second precision.
+    #[cfg(feature = "timestamp")]
+    pub timestamp_counter: bool,
+    /// Whether the id of the process is printed in its own column.
+    pub show_pid: bool,
+    /// The version of the application included in the logs.
+    pub show_version: Option<&'static str>,
+    /// Where the version of the application gets included.
+    pub version_placement: VersionPlacement,
+    /// Whether ANSI escape codes get removed from every message.
+    pub strip_incoming_ansi: bool,
+    /// The whitespace trimmed from every message.
+    pub trim_message: TrimMode,
+    /// Whether the time passed since the previous log is printed in its own column.
+    pub show_delta: bool,
+    /// The maximum length of a message in bytes.
+    pub max_message_len: Option<usize>,
+    /// The way messages spanning multiple lines are printed.
+    pub multiline: MultilineMode,
+    /// The seed used to decide which logs of a sampled target get printed.
+    pub sampling_seed: Option<u64>,
+    /// The number of spaces every level of indentation adds in front of a message.
+    pub indent_width: usize,
+    /// The policy deciding when the log file gets rotated.
+    #[cfg(not(feature = "wasm"))]
+    pub rotation_policy: Option<RotationPolicy>,
+    /// The maximum number of bytes all log files may take up in total.
+    #[cfg(not(feature = "wasm"))]
+    pub max_total_size: Option<u64>,
+    /// The header written as the first line of every new log file.
+    #[cfg(not(feature = "wasm"))]
+    pub file_header: Option<String>,
+    /// Whether a resolver deciding which file each log gets written to has been set.
+    #[cfg(not(feature = "wasm"))]
+    pub file_path_resolver: bool,
+    /// The maximum number of files opened for the paths returned by the resolver at the same time.
+    #[cfg(not(feature = "wasm"))]
+    pub max_open_files: usize,
+    /// The policy deciding when the sinks get flushed.
+    pub flush_policy: FlushPolicy,
+    /// The maximum time the [`fatal!`](crate::fatal) macro waits for the logs to be flushed.
+    pub fatal_flush_timeout: Duration,
+    /// The exit code the [`fatal!`](crate::fatal) macro exits the application with.
+    pub fatal_exit_code: i32,
+    /// What happens to logs sent before the goolog logger has been initiated.
+    pub uninitialized_behavior: Uninit,
+    /// Every destination logs get sent to.
+    pub sinks: Vec<SinkInfo>,
+    /// The number of log lines dropped so far.
+    pub dropped_count: usize,
+    /// Whether the logs suppressed by the log level get counted.
+    pub count_suppressed: bool,
+    /// The number of logs suppressed by the log level so far, in the order `[error, warn, info, debug, trace]`.
+    pub suppressed_counts: [usize; 5],
+}
+
+/// Get a snapshot of every setting of the goolog logger. \
+/// \
+/// This is useful for printing the configuration in a startup banner or when debugging why the output looks different than
+/// expected. Every setting is read on its own, so a setting changed by another thread while the snapshot is being taken may or
+/// may not be part of it.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+///
+/// debug!("Main"; "The logger has been initiated using {:#?}", effective_config());
+/// # }
+/// ```
+pub fn effective_config() -> Config {
+    Config {
+        max_level: log::max_level(),
+        max_name_length: MAX_NAME_LENGTH.load(Ordering::Relaxed),
+        target_lengths: [
+            Level::Error,
+            Level::Warn,
+            Level::Info,
+            Level::Debug,
+            Level::Trace,
+        ]
+        .map(crate::target_length_for),
+        colors_enabled: crate::colors_enabled(),
+        fatal_highlight: crate::fatal_highlight(),
+        color_scope: crate::color_scope(),
+        target_color: crate::target_color(),
+        auto_target_colors: crate::auto_target_colors(),
+        target_palette_size: crate::target_palette_size(),
+        target_message_separator: crate::target_message_separator(),
+        level_icons: crate::level_icons(),
+        level_width: crate::level_width(),
+        layout_preset: crate::layout_preset(),
+        format: crate::format(),
+        custom_formatter: formatter::is_set(),
+        column_order: crate::column_order(),
+        column_header: crate::column_header(),
+        #[cfg(feature = "timestamp")]
+        timestamp_format: crate::timestamp_format(),
+        #[cfg(feature = "timestamp")]
+        show_timestamp: crate::show_timestamp(),
+        #[cfg(feature = "timestamp")]
+        timestamp_counter: crate::timestamp_counter(),
+        show_pid: crate::show_pid(),
+        show_version: crate::show_version(),
+        version_placement: crate::version_placement(),
+        strip_incoming_ansi: crate::strip_incoming_ansi(),
+        trim_message: crate::trim_message(),
+        show_delta: crate::show_delta(),
+        max_message_len: crate::max_message_len(),
+        multiline: crate::multiline(),
+        sampling_seed: crate::sampling_seed(),
+        indent_width: crate::indent_width(),
+        #[cfg(not(feature = "wasm"))]
+        rotation_policy: crate::rotation_policy(),
+        #[cfg(not(feature = "wasm"))]
+        max_total_size: crate::max_total_size(),
+        #[cfg(not(feature = "wasm"))]
+        file_header: crate::file_header(),
+        #[cfg(not(feature = "wasm"))]
+        file_path_resolver: crate::rotation::file_path_resolver_set(),
+        #[cfg(not(feature = "wasm"))]
+        max_open_files: crate::max_open_files(),
+        flush_policy: crate::flush_policy(),
+        fatal_flush_timeout: crate::fatal_flush_timeout(),
+        fatal_exit_code: crate::fatal_exit_code(),
+        uninitialized_behavior: crate::uninitialized_behavior(),
+        sinks: crate::sinks(),
+        dropped_count: crate::dropped_count(),
+        count_suppressed: crate::count_suppressed(),
+        suppressed_counts: crate::suppressed_counts(),
+    }
+}
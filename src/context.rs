@@ -0,0 +1,44 @@
+//! This module provides functions used to attach additional context to every log sent from the current thread.
+
+use std::cell::RefCell;
+
+thread_local! {
+    /// The trace and span id attached to every log sent from this thread.
+    static TRACE_IDS: RefCell<Option<(String, String)>> = const { RefCell::new(None) };
+}
+
+/// Attach the given `trace_id` and `span_id` to every log sent from the current thread. \
+/// \
+/// This can be used to correlate log lines with the traces of a distributed system, for example one instrumented using
+/// OpenTelemetry. The ids will be printed in their own column right before the message.
+///
+/// # Thread-safety
+///
+/// The ids are stored thread-local. They therefore need to be set for every request or task handled by a thread and will not be
+/// attached to logs sent from any other thread. Use [`clear_trace_ids`] to remove them again once the request has been handled.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+///
+/// set_trace_ids("4bf92f3577b34da6a3ce929d0e0e4736", "00f067aa0ba902b7");
+/// info!("Main"; "Handling request...");
+/// clear_trace_ids();
+/// # }
+/// ```
+pub fn set_trace_ids(trace_id: impl Into<String>, span_id: impl Into<String>) {
+    TRACE_IDS.with(|trace_ids| *trace_ids.borrow_mut() = Some((trace_id.into(), span_id.into())));
+}
+
+/// Remove the trace and span id set for the current thread using [`set_trace_ids`].
+pub fn clear_trace_ids() {
+    TRACE_IDS.with(|trace_ids| *trace_ids.borrow_mut() = None);
+}
+
+/// Get the trace and span id set for the current thread.
+pub(crate) fn trace_ids() -> Option<(String, String)> {
+    TRACE_IDS.with(|trace_ids| trace_ids.borrow().clone())
+}
@@ -0,0 +1,100 @@
+//! This module provides the [`set_show_delta`] function used to print the time passed since the previous log.
+
+use std::{
+    cell::Cell,
+    sync::atomic::{
+        AtomicBool,
+        AtomicU64,
+        Ordering,
+    },
+    time::Duration,
+};
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+use std::{
+    sync::OnceLock,
+    time::Instant,
+};
+
+/// Whether the time passed since the previous log is printed in its own column.
+static SHOW_DELTA: AtomicBool = AtomicBool::new(false);
+/// The time the previous log has been sent at in microseconds, as returned by [`now`]. \
+/// [`u64::MAX`] means no log has been sent yet.
+static LAST_LOG: AtomicU64 = AtomicU64::new(u64::MAX);
+
+thread_local! {
+    /// The time passed between the log currently being sent on this thread and the previous log.
+    static DELTA: Cell<Option<Duration>> = const { Cell::new(None) };
+}
+
+/// Set whether the time passed since the previous log is printed in its own column, like `+12ms`. \
+/// \
+/// This is handy for spotting where time is spent without measuring it explicitly. By default, the time is not printed.
+///
+/// # Thread-safety
+///
+/// The time of the previous log is shared across all threads. If multiple threads are logging at the same time, the printed time
+/// may therefore be the time passed since a log of a different thread.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_show_delta(true);
+///
+/// // this will be printed as `29.05.2023 | 14:34:33 | +0ms     | Main             | INFO  | Loading...`
+/// info!("Main"; "Loading...");
+/// // this will be printed as `29.05.2023 | 14:34:33 | +12ms    | Main             | INFO  | Done.`
+/// info!("Main"; "Done.");
+/// # }
+/// ```
+pub fn set_show_delta(show_delta: bool) {
+    SHOW_DELTA.store(show_delta, Ordering::Relaxed);
+}
+
+/// Get whether the time passed since the previous log is printed in its own column.
+pub fn show_delta() -> bool {
+    SHOW_DELTA.load(Ordering::Relaxed)
+}
+
+/// Get the current time in microseconds. \
+/// \
+/// On `wasm32` targets, the time is read using the JavaScript `Date` API, since the [`Instant`](std::time::Instant) API is not
+/// available there.
+pub(crate) fn now() -> u64 {
+    #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+    return (js_sys::Date::now() * 1000.0) as u64;
+
+    #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+    {
+        /// The time this function has been called for the first time.
+        static START: OnceLock<Instant> = OnceLock::new();
+
+        START.get_or_init(Instant::now).elapsed().as_micros() as u64
+    }
+}
+
+/// Store the time passed since the previous log for the log currently being sent on this thread.
+pub(crate) fn track() {
+    if !show_delta() {
+        return;
+    }
+
+    let now = now();
+    let delta = match LAST_LOG.swap(now, Ordering::Relaxed) {
+        u64::MAX => 0,
+        last_log => now.saturating_sub(last_log),
+    };
+    DELTA.with(|delta_cell| delta_cell.set(Some(Duration::from_micros(delta))));
+}
+
+/// Get the time passed since the previous log for the log currently being sent on this thread. \
+/// Returns [`None`] if this time should not be printed.
+pub(crate) fn delta() -> Option<Duration> {
+    if !show_delta() {
+        return None;
+    }
+
+    DELTA.with(Cell::get)
+}
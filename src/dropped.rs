@@ -0,0 +1,60 @@
+//! This module provides the functions used to get notified of log lines dropped by the goolog logger.
+
+use std::sync::{
+    atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+    RwLock,
+};
+
+/// The number of log lines dropped so far.
+static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+/// The function called whenever a log line gets dropped.
+static ON_DROP: RwLock<Option<OnDrop>> = RwLock::new(None);
+
+/// A function called whenever a log line gets dropped. \
+/// It receives the number of log lines dropped so far.
+pub type OnDrop = &'static (dyn Fn(usize) + Sync);
+
+/// Get the number of log lines dropped so far. \
+/// \
+/// Log lines get dropped when a bounded buffer is full, like the one of the [`pre_init`](crate::pre_init) logger or of a sink
+/// waiting to reconnect. A growing number indicates that such a buffer is too small or a destination is unavailable.
+pub fn dropped_count() -> usize {
+    DROPPED.load(Ordering::Relaxed)
+}
+
+/// Set a `callback` which will be called whenever a log line gets dropped. \
+/// \
+/// The callback receives the number of log lines dropped so far, just like [`dropped_count`] would return. It will be called
+/// while the log dropping the line is being sent. Any log sent by the callback will therefore be printed to stderr without any
+/// formatting. Setting a new callback will replace the previous one.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_on_drop(&|dropped| eprintln!("{dropped} log lines have been dropped so far"));
+/// # }
+/// ```
+pub fn set_on_drop(callback: OnDrop) {
+    *ON_DROP
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(callback);
+}
+
+/// Count a dropped log line and call the callback set using [`set_on_drop`].
+pub(crate) fn record_drop() {
+    let dropped = DROPPED.fetch_add(1, Ordering::Relaxed) + 1;
+
+    let callback = *ON_DROP
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(callback) = callback {
+        callback(dropped);
+    }
+}
@@ -0,0 +1,79 @@
+//! This module provides the [`HumanDuration`] used to print durations in a human-friendly format.
+
+use std::{
+    fmt::{
+        self,
+        Display,
+    },
+    time::Duration,
+};
+
+/// A wrapper around a [`Duration`] printing it in a short, human-friendly format. \
+/// \
+/// Durations below a minute are printed with at most three significant digits in the largest fitting unit, like `850ns`,
+/// `12.3µs`, `450ms` or `1.23s`. Longer durations are printed in minutes and seconds, like `2m03s`, or in hours, minutes and
+/// seconds, like `1h02m03s`, leaving out any fraction of a second. Unlike the [`Debug`](std::fmt::Debug) output of a
+/// [`Duration`], like `1.234567891s`, this keeps timing logs readable.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Instant;
+///
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+///
+/// let start = Instant::now();
+/// // ...
+/// info!("Main"; "Finished the build in {}", human_duration(start.elapsed()));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HumanDuration {
+    /// The duration to be printed.
+    duration: Duration,
+}
+impl Display for HumanDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seconds = self.duration.as_secs();
+        if seconds >= 60 * 60 {
+            return write!(
+                f,
+                "{}h{:02}m{:02}s",
+                seconds / (60 * 60),
+                seconds / 60 % 60,
+                seconds % 60
+            );
+        }
+        if seconds >= 60 {
+            return write!(f, "{}m{:02}s", seconds / 60, seconds % 60);
+        }
+
+        let nanos = self.duration.as_nanos();
+        let (value, unit) = match nanos {
+            0..=999 => return write!(f, "{nanos}ns"),
+            1_000..=999_999 => (nanos as f64 / 1e3, "µs"),
+            1_000_000..=999_999_999 => (nanos as f64 / 1e6, "ms"),
+            _ => (nanos as f64 / 1e9, "s"),
+        };
+        let decimals = match value {
+            value if value < 10.0 => 2,
+            value if value < 100.0 => 1,
+            _ => 0,
+        };
+        let value = format!("{value:.decimals$}");
+        let value = if value.contains('.') {
+            value.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            &value
+        };
+
+        write!(f, "{value}{unit}")
+    }
+}
+
+/// Wrap the given `duration` to print it in a short, human-friendly format as described by [`HumanDuration`].
+pub fn human_duration(duration: Duration) -> HumanDuration {
+    HumanDuration { duration }
+}
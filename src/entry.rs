@@ -0,0 +1,69 @@
+//! This module provides the [`Entry`] every log gets captured into once before being sent to its destinations.
+
+use std::{
+    cell::RefCell,
+    rc::Rc,
+};
+
+use log::Record;
+
+use crate::format::{
+    strip_fatal_marker,
+    transform_message,
+};
+
+thread_local! {
+    /// The log currently being sent on this thread.
+    static CURRENT: RefCell<Option<Rc<Entry>>> = const { RefCell::new(None) };
+}
+
+/// A log captured once while being sent, so every destination renders it from the same data. \
+/// \
+/// The arguments of a record get materialized into a string exactly once, before the log reaches any destination. The console,
+/// the log file and every sink then render their lines from this entry instead of formatting the arguments, removing the
+/// marker of the [`fatal!`](crate::fatal) macro, and calling the transform set using
+/// [`set_message_transform`](crate::set_message_transform) again. The time of the log is captured at the same moment, so every
+/// destination prints the same timestamp.
+pub(crate) struct Entry {
+    /// The processed message of the log.
+    pub(crate) message: String,
+    /// Whether the log has been sent by the [`fatal!`](crate::fatal) macro.
+    pub(crate) fatal: bool,
+    /// The time the log has been sent at.
+    #[cfg(all(
+        feature = "timestamp",
+        not(all(feature = "wasm", target_arch = "wasm32"))
+    ))]
+    pub(crate) time: chrono::DateTime<chrono::Local>,
+}
+
+/// Makes the destinations stop rendering from the [`Entry`] of the log currently being sent on this thread once dropped.
+pub(crate) struct EntryGuard;
+impl Drop for EntryGuard {
+    fn drop(&mut self) {
+        CURRENT.with(|current| current.replace(None));
+    }
+}
+
+/// Capture the given `record`, whose arguments have already been materialized into the given `message`, as the log currently
+/// being sent on this thread.
+pub(crate) fn capture(record: &Record, message: String) -> EntryGuard {
+    let (fatal, message) = strip_fatal_marker(record.level(), message);
+
+    let entry = Some(Rc::new(Entry {
+        message: transform_message(message),
+        fatal,
+        #[cfg(all(
+            feature = "timestamp",
+            not(all(feature = "wasm", target_arch = "wasm32"))
+        ))]
+        time: chrono::Local::now(),
+    }));
+    CURRENT.with(|current| current.replace(entry));
+    EntryGuard
+}
+
+/// Get the log currently being sent on this thread, if it has been captured using [`capture`].
+pub(crate) fn current() -> Option<Rc<Entry>> {
+    CURRENT.with(|current| current.borrow().clone())
+}
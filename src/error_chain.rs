@@ -0,0 +1,51 @@
+//! This module provides the [`ErrorChain`] used to print an error together with all of its sources.
+
+use std::{
+    error::Error,
+    fmt::{
+        self,
+        Display,
+    },
+};
+
+/// A wrapper around an error printing the error followed by every error of its [`source`](Error::source) chain. \
+/// \
+/// The errors are separated by `: caused by: `, so an error with two sources will be printed as
+/// `error: caused by: source: caused by: source of source`. This is used by the [`error_chain!`](crate::error_chain) and
+/// [`warn_chain!`](crate::warn_chain) macros.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// let error = std::fs::read("does/not/exist").unwrap_err();
+///
+/// // this will be printed as `No such file or directory (os error 2)`
+/// println!("{}", ErrorChain::new(&error));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorChain<'a> {
+    /// The error at the top of the chain.
+    error: &'a (dyn Error + 'a),
+}
+impl<'a> ErrorChain<'a> {
+    /// Wrap the given `error` to print it together with its sources.
+    pub fn new(error: &'a (dyn Error + 'a)) -> Self {
+        Self { error }
+    }
+}
+impl Display for ErrorChain<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+
+        let mut source = self.error.source();
+        while let Some(error) = source {
+            write!(f, ": caused by: {error}")?;
+            source = error.source();
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+//! This module provides the [`set_on_fatal`] function used to register a handler called by the [`fatal!`](crate::fatal) macro.
+
+use std::{
+    sync::{
+        atomic::{
+            AtomicI32,
+            AtomicU64,
+            Ordering,
+        },
+        Mutex,
+    },
+    time::Duration,
+};
+
+use crate::{
+    sink,
+    transaction,
+};
+
+/// A handler called by the [`fatal!`](crate::fatal) macro before exiting the application.
+pub type OnFatal = Box<dyn FnMut() + Send>;
+
+/// The handler called by the [`fatal!`](crate::fatal) macro before exiting the application.
+static ON_FATAL: Mutex<Option<OnFatal>> = Mutex::new(None);
+
+/// The longest time in milliseconds the [`fatal!`](crate::fatal) macro waits for the logger to be flushed before exiting.
+static FATAL_FLUSH_TIMEOUT: AtomicU64 = AtomicU64::new(1000);
+
+/// The exit code the [`fatal!`](crate::fatal) macro exits the application with, unless another one is given to the macro.
+static FATAL_EXIT_CODE: AtomicI32 = AtomicI32::new(1);
+
+/// Register a `handler` which will be called by the [`fatal!`](crate::fatal) macro after the fatal log has been printed and
+/// right before the application exits. \
+/// \
+/// Since the handler is an [`FnMut`], it can mutate its captured state. This can be used to count fatal errors or to send them
+/// over a captured channel, for example for telemetry. Registering a new handler will replace the previous one, while
+/// [`reset_on_fatal`] removes it.
+///
+/// # Re-entrancy
+///
+/// The handler is not locked while it runs, so it may call [`set_on_fatal`], [`reset_on_fatal`] or [`on_fatal_is_custom`]
+/// without deadlocking. While running, it is not registered: [`on_fatal_is_custom`] returns `false`, and a
+/// [`fatal!`](crate::fatal) sent by the handler itself does not call it again but exits right away. A handler registered while
+/// it runs replaces it; otherwise it is registered again afterwards.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::mpsc;
+///
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+///
+/// let (sender, receiver) = mpsc::channel();
+/// let mut fatal_count = 0;
+/// set_on_fatal(move || {
+///     fatal_count += 1;
+///     sender.send(fatal_count).ok();
+/// });
+/// # }
+/// ```
+pub fn set_on_fatal(handler: impl FnMut() + Send + 'static) {
+    *ON_FATAL
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Box::new(handler));
+}
+
+/// Remove the handler registered using [`set_on_fatal`], restoring the default of not calling any handler. \
+/// \
+/// Tests registering a handler can call this afterwards, so the handler does not leak into other tests.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// set_on_fatal(|| eprintln!("Shutting down"));
+/// assert!(on_fatal_is_custom());
+///
+/// reset_on_fatal();
+/// assert!(!on_fatal_is_custom());
+/// # }
+/// ```
+pub fn reset_on_fatal() {
+    *ON_FATAL
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+}
+
+/// Get whether a handler has been registered using [`set_on_fatal`].
+pub fn on_fatal_is_custom() -> bool {
+    ON_FATAL
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .is_some()
+}
+
+/// Set the longest time the [`fatal!`](crate::fatal) macro waits for the logger and every [`Sink`](crate::Sink) to be flushed
+/// before exiting the application. \
+/// \
+/// Flushing makes sure the fatal log reaches every destination, even one buffering its logs. If flushing takes longer than this,
+/// for example because a remote log collector is not responding, the application exits anyway. By default, the timeout is one
+/// second.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_fatal_flush_timeout(Duration::from_secs(5));
+/// # }
+/// ```
+pub fn set_fatal_flush_timeout(timeout: Duration) {
+    FATAL_FLUSH_TIMEOUT.store(
+        u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX),
+        Ordering::Relaxed,
+    );
+}
+
+/// Get the longest time the [`fatal!`](crate::fatal) macro waits for the logger to be flushed before exiting.
+pub fn fatal_flush_timeout() -> Duration {
+    Duration::from_millis(FATAL_FLUSH_TIMEOUT.load(Ordering::Relaxed))
+}
+
+/// Set the exit code the [`fatal!`](crate::fatal) macro exits the application with. \
+/// \
+/// Command line tools often use different exit codes for different classes of failures, so scripts calling them can react
+/// accordingly. A single fatal log can still use another code by passing it to the macro: `fatal!(code = 2; "Main"; "...")`. By
+/// default, the exit code is `1`.
+///
+/// The handler registered using [`set_on_fatal`] runs before the application exits. If it ends the process itself, for example
+/// by calling [`std::process::exit`], its own exit code is used and this one is ignored. Without the `fatal-exit` feature, the
+/// macro panics instead of exiting, so the exit code is ignored as well.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_fatal_exit_code(70);
+///
+/// // `fatal!` would now exit the process with the code 70
+/// # }
+/// ```
+pub fn set_fatal_exit_code(code: i32) {
+    FATAL_EXIT_CODE.store(code, Ordering::Relaxed);
+}
+
+/// Get the exit code the [`fatal!`](crate::fatal) macro exits the application with, unless another one is given to the macro.
+pub fn fatal_exit_code() -> i32 {
+    FATAL_EXIT_CODE.load(Ordering::Relaxed)
+}
+
+/// Flush the logger and every sink, waiting at most the time set using [`set_fatal_flush_timeout`].
+fn flush() {
+    /// Flush the logger and every sink.
+    fn flush_all() {
+        log::logger().flush();
+        sink::flush();
+    }
+
+    // threads are not available on wasm targets
+    #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+    flush_all();
+
+    #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+    {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            flush_all();
+            sender.send(()).ok();
+        });
+        receiver.recv_timeout(fatal_flush_timeout()).ok();
+    }
+}
+
+/// # DO NOT TOUCH THIS FUNCTION
+///
+/// End the application after a fatal log has been sent. This is used by the [`fatal!`](crate::fatal) macro. \
+/// With the `fatal-exit` feature, the process exits with the given error `code`. Otherwise, the current thread panics.
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub fn INTERNAL__fatal_exit(code: i32) -> ! {
+    #[cfg(feature = "fatal-exit")]
+    std::process::exit(code);
+
+    // the exit code is ignored without the `fatal-exit` feature
+    #[cfg(not(feature = "fatal-exit"))]
+    {
+        let _ = code;
+        panic!("a fatal error has been logged");
+    }
+}
+
+/// # DO NOT TOUCH THIS FUNCTION
+///
+/// Call the handler registered using [`set_on_fatal`] and flush the logger.
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub fn INTERNAL__on_fatal() {
+    // the lines of a transaction would otherwise get lost when exiting
+    transaction::commit();
+
+    // the handler is taken out of the lock while it runs, so it can call any function of this module without deadlocking
+    let handler = ON_FATAL
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .take();
+    if let Some(handler) = handler {
+        let mut guard = HandlerGuard(Some(handler));
+        if let Some(handler) = guard.0.as_mut() {
+            handler();
+        }
+    }
+
+    flush();
+}
+
+/// Puts the handler taken out of [`ON_FATAL`] back once dropped, even if it panicked, unless another handler has been
+/// registered while it was running.
+struct HandlerGuard(Option<OnFatal>);
+impl Drop for HandlerGuard {
+    fn drop(&mut self) {
+        let mut on_fatal = ON_FATAL
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if on_fatal.is_none() {
+            *on_fatal = self.0.take();
+        }
+    }
+}
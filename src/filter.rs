@@ -0,0 +1,127 @@
+//! This module provides the functions used to decide whether a log should be printed by the goolog logger.
+
+use std::{
+    fmt::{
+        self,
+        Display,
+    },
+    str::FromStr,
+};
+
+use log::{
+    Level,
+    LevelFilter,
+    Metadata,
+};
+
+use crate::{
+    mute,
+    target_level,
+};
+
+/// Check whether a log with the given `metadata` passes all of the goolog loggers own filters. \
+/// This does not include the log level, which is checked by the [`Logger`](fern::Dispatch) itself.
+pub(crate) fn filter(metadata: &Metadata) -> bool {
+    !mute::is_muted(metadata.target()) && target_level::allows(metadata)
+}
+
+/// Check whether a log with the given `level` and `target` would be printed by the active logger. \
+/// \
+/// Unlike [`log::log_enabled!`], this does not only check the global log level but also all the filters of the goolog logger,
+/// like muted targets. This can be used to skip expensive work needed for a log that would not be printed anyway.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// use goolog::log::Level;
+/// # fn main() {
+/// init_logger(None, None, None);
+///
+/// if would_log(Level::Trace, "Main") {
+///     // only do this expensive work when it will be printed
+///     trace!("Main"; "The current state is {}", "...");
+/// }
+/// # }
+/// ```
+pub fn would_log(level: Level, target: &str) -> bool {
+    let metadata = Metadata::builder().level(level).target(target).build();
+
+    level <= log::max_level() && filter(&metadata) && log::logger().enabled(&metadata)
+}
+
+/// An error returned by [`init_logger_str`](crate::init_logger_str) if the given log level is unknown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LevelParseError {
+    /// The unknown log level.
+    level: String,
+}
+impl Display for LevelParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown log level `{}`, expected one of `off`, `error`, `warn`, `info`, `debug`, `trace` or a number from 0 to 4",
+            self.level
+        )
+    }
+}
+impl std::error::Error for LevelParseError {}
+
+/// Get the level filter matching the given `verbosity`, like the number of times a `-v` flag has been passed. \
+/// \
+/// The verbosity is mapped to the levels from the least to the most verbose one:
+///
+/// | Verbosity | Level |
+/// |-|-|
+/// | 0 | [`Error`](LevelFilter::Error) |
+/// | 1 | [`Warn`](LevelFilter::Warn) |
+/// | 2 | [`Info`](LevelFilter::Info) |
+/// | 3 | [`Debug`](LevelFilter::Debug) |
+/// | 4 or more | [`Trace`](LevelFilter::Trace) |
+///
+/// The same mapping is used for numeric levels passed to [`init_logger_str`](crate::init_logger_str), like `"3"` for debug.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// use goolog::log::LevelFilter;
+/// # fn main() {
+/// // the number of `-v` flags, as counted by an argument parser
+/// let verbose = 3;
+/// assert_eq!(level_from_verbosity(verbose), LevelFilter::Debug);
+///
+/// init_logger(Some(level_from_verbosity(verbose)), None, None);
+/// # }
+/// ```
+pub fn level_from_verbosity(verbosity: u8) -> LevelFilter {
+    match verbosity {
+        0 => LevelFilter::Error,
+        1 => LevelFilter::Warn,
+        2 => LevelFilter::Info,
+        3 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
+/// Raise the given `base` level by `steps` levels, up to [`LevelFilter::Trace`].
+pub(crate) fn raise_level(base: LevelFilter, steps: u8) -> LevelFilter {
+    LevelFilter::iter()
+        .nth(base as usize + steps as usize)
+        .unwrap_or(LevelFilter::Trace)
+}
+
+/// Parse the given `level`, ignoring its case and any surrounding whitespace. \
+/// A number from 0 to 4 is mapped to a level just like by [`level_from_verbosity`].
+pub(crate) fn parse_level(level: &str) -> Result<LevelFilter, LevelParseError> {
+    let trimmed = level.trim();
+    match trimmed.parse::<u8>() {
+        Ok(verbosity @ 0..=4) => Ok(level_from_verbosity(verbosity)),
+        Ok(_) => Err(LevelParseError {
+            level: level.to_string(),
+        }),
+        Err(_) => LevelFilter::from_str(trimmed).map_err(|_| LevelParseError {
+            level: level.to_string(),
+        }),
+    }
+}
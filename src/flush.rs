@@ -0,0 +1,96 @@
+//! This module provides the [`set_flush_policy`] function used to decide when the sinks get flushed.
+
+use std::{
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        RwLock,
+    },
+    time::Duration,
+};
+
+use log::Level;
+
+use crate::delta;
+
+/// The policy deciding when the sinks get flushed.
+static FLUSH_POLICY: RwLock<FlushPolicy> = RwLock::new(FlushPolicy::Never);
+/// The time the sinks have been flushed at the last time because of [`FlushPolicy::OnInterval`], as returned by
+/// [`delta::now`]. \
+/// [`u64::MAX`] means the sinks have not been flushed because of it yet.
+static LAST_FLUSH: AtomicU64 = AtomicU64::new(u64::MAX);
+
+/// This enum describes when the sinks get flushed after a log has been sent to them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FlushPolicy {
+    /// Flush the sinks after every log. \
+    /// Nothing stays in a buffer, so no log gets lost if the application crashes, but every log costs a flush, which may be a
+    /// system call or a network round trip.
+    Always,
+    /// Never flush the sinks because of a log. This is the default. \
+    /// The sinks only get flushed by a [`LoggerGuard`](crate::LoggerGuard), the [`fatal!`](crate::fatal) macro, or once their own
+    /// buffers are full. This is the fastest policy, but the logs still buffered get lost if the application crashes.
+    #[default]
+    Never,
+    /// Flush the sinks after every log at the given level or a more severe one. \
+    /// With [`Level::Error`], errors reach their destination right away while every other log gets batched, so the logs leading
+    /// up to a failure only get lost if the application crashes before the error has been sent.
+    OnLevel(Level),
+    /// Flush the sinks after a log if the given time has passed since they have been flushed the last time. \
+    /// This limits both the cost of flushing and the time a log may stay in a buffer. Since the time is only checked while a log is
+    /// being sent, the logs sent last stay in the buffers until the next log arrives.
+    OnInterval(Duration),
+}
+
+/// Set the `policy` deciding when the sinks get flushed after a log has been sent to them. \
+/// \
+/// Flushing trades performance for durability: the more often the sinks get flushed, the fewer logs get lost if the application
+/// crashes, but the more every log costs. See the variants of [`FlushPolicy`] for the trade-off of each policy. The console and
+/// the log file are not affected, since they never keep the logs in a buffer.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// use goolog::log::Level;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// add_sink(WriterSink::new(std::io::BufWriter::new(std::io::sink())));
+///
+/// // errors reach the writer right away, while every other log gets batched
+/// set_flush_policy(FlushPolicy::OnLevel(Level::Error));
+/// # }
+/// ```
+pub fn set_flush_policy(policy: FlushPolicy) {
+    *FLUSH_POLICY
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = policy;
+}
+
+/// Get the policy deciding when the sinks get flushed after a log has been sent to them.
+pub fn flush_policy() -> FlushPolicy {
+    *FLUSH_POLICY
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Check whether the sinks need to be flushed after a log at the given `level` has been sent to them.
+pub(crate) fn should_flush(level: Level) -> bool {
+    match flush_policy() {
+        FlushPolicy::Always => true,
+        FlushPolicy::Never => false,
+        FlushPolicy::OnLevel(flush_level) => level <= flush_level,
+        FlushPolicy::OnInterval(interval) => {
+            let now = delta::now();
+            let last_flush = LAST_FLUSH.load(Ordering::Relaxed);
+            // only one of the threads seeing the interval pass flushes the sinks
+            (last_flush == u64::MAX
+                || now.saturating_sub(last_flush) >= interval.as_micros() as u64)
+                && LAST_FLUSH
+                    .compare_exchange(last_flush, now, Ordering::Relaxed, Ordering::Relaxed)
+                    .is_ok()
+        }
+    }
+}
@@ -0,0 +1,1117 @@
+//! This module provides the functions used to generate the log lines printed by the goolog logger.
+
+use std::{
+    borrow::Cow,
+    fmt::Arguments,
+    rc::Rc,
+    sync::{
+        atomic::{
+            AtomicBool,
+            AtomicU32,
+            AtomicU8,
+            AtomicUsize,
+            Ordering,
+        },
+        OnceLock,
+        RwLock,
+    },
+};
+
+use fern::colors::{
+    Color,
+    ColoredLevelConfig,
+};
+use log::{
+    Level,
+    Record,
+};
+
+#[cfg(feature = "timestamp")]
+use crate::timestamp;
+use crate::{
+    columns::{
+        self,
+        Column,
+    },
+    context,
+    delta,
+    entry::{
+        self,
+        Entry,
+    },
+    formatter::{
+        self,
+        FormatCtx,
+        Formatter,
+    },
+    indent,
+    multiline::{
+        self,
+        MultilineMode,
+    },
+    template::{
+        self,
+        LogParts,
+    },
+    version::{
+        self,
+        VersionPlacement,
+    },
+};
+
+/// The part of a log line that gets colored based on the level of the log. \
+/// This is stored as the discriminant of a [`ColorScope`] so no lock is needed to read it.
+static COLOR_SCOPE: AtomicU8 = AtomicU8::new(ColorScope::LevelOnly as u8);
+
+/// The maximum length of a message in bytes. \
+/// [`usize::MAX`] means that messages will never be truncated.
+static MAX_MESSAGE_LEN: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// The preset describing the default layout of a log line. \
+/// This is stored as the discriminant of a [`LayoutPreset`] so no lock is needed to read it.
+static LAYOUT_PRESET: AtomicU8 = AtomicU8::new(LayoutPreset::Full as u8);
+
+/// The length of caller names for every level, indexed by the level minus one. \
+/// [`u32::MAX`] means the length given while initiating the goolog logger will be used.
+static TARGET_LENGTHS: [AtomicU32; 5] = [
+    AtomicU32::new(u32::MAX),
+    AtomicU32::new(u32::MAX),
+    AtomicU32::new(u32::MAX),
+    AtomicU32::new(u32::MAX),
+    AtomicU32::new(u32::MAX),
+];
+
+/// The length of caller names given while initiating the goolog logger.
+pub(crate) static MAX_NAME_LENGTH: AtomicU32 = AtomicU32::new(16);
+
+/// Whether log lines get colored at all.
+static COLORS_ENABLED: AtomicBool = AtomicBool::new(true);
+/// Whether the logs sent by the [`fatal!`](crate::fatal) macro get a red background on the console.
+static FATAL_HIGHLIGHT: AtomicBool = AtomicBool::new(false);
+/// Whether the id of the process is printed in its own column.
+static SHOW_PID: AtomicBool = AtomicBool::new(false);
+/// Whether ANSI escape codes get removed from every message.
+static STRIP_INCOMING_ANSI: AtomicBool = AtomicBool::new(false);
+/// The whitespace trimmed from every message. \
+/// This is stored as the discriminant of a [`TrimMode`] so no lock is needed to read it.
+static TRIM_MESSAGE: AtomicU8 = AtomicU8::new(TrimMode::None as u8);
+/// The id of this process. \
+/// This gets read once while initiating the goolog logger, since it does not change.
+pub(crate) static PID: OnceLock<String> = OnceLock::new();
+
+/// The function applied to every message before it gets printed.
+static MESSAGE_TRANSFORM: RwLock<Option<MessageTransform>> = RwLock::new(None);
+
+/// A function applied to every message before it gets printed.
+pub type MessageTransform = &'static (dyn Fn(&str) -> Cow<str> + Sync);
+
+/// The color of the target column. \
+/// [`None`] means the target column will not be colored.
+static TARGET_COLOR: RwLock<Option<Color>> = RwLock::new(None);
+/// Whether the target column gets a color picked from [`TARGET_PALETTE`] based on the target.
+static AUTO_TARGET_COLORS: AtomicBool = AtomicBool::new(false);
+/// The number of colors of [`TARGET_PALETTE`] used to color the target column automatically.
+static TARGET_PALETTE_SIZE: AtomicUsize = AtomicUsize::new(TARGET_PALETTE.len());
+
+/// The colors the target column can be colored with automatically. \
+/// The colors are ordered so that the first few ones are the easiest to tell apart.
+const TARGET_PALETTE: [Color; 12] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::Green,
+    Color::Blue,
+    Color::Yellow,
+    Color::Red,
+    Color::BrightCyan,
+    Color::BrightMagenta,
+    Color::BrightGreen,
+    Color::BrightBlue,
+    Color::BrightYellow,
+    Color::BrightRed,
+];
+
+/// The separator printed between the message and the columns in front of it. \
+/// [`None`] means the separator of the layout preset will be used.
+static TARGET_MESSAGE_SEPARATOR: RwLock<Option<&'static str>> = RwLock::new(None);
+
+/// The icons printed in front of the level, indexed by the level minus one. \
+/// [`None`] means no icons will be printed.
+static LEVEL_ICONS: RwLock<Option<[&'static str; 5]>> = RwLock::new(None);
+/// The width the level column of the [`LayoutPreset::Full`] layout gets padded to.
+static LEVEL_WIDTH: AtomicUsize = AtomicUsize::new(LEVEL_NAME_WIDTH);
+/// The width of the longest level name: `ERROR` and `FATAL`.
+const LEVEL_NAME_WIDTH: usize = 5;
+
+/// This enum describes which part of a log line gets colored based on its level.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ColorScope {
+    /// Only the level will be colored. This is the default.
+    #[default]
+    LevelOnly,
+    /// The level and the message will be colored.
+    Message,
+    /// The whole line will be colored.
+    WholeLine,
+}
+
+/// This enum describes a preset for the default layout of a log line. \
+/// \
+/// A template set using [`set_format`](crate::set_format) takes precedence over the layout of the preset. The level will still be
+/// printed as described by the preset.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum LayoutPreset {
+    /// Every column is separated by ` | `: `29.05.2023 | 14:34:33 | Main             | INFO  | Hello World!`. The level is padded
+    /// to five characters, unless another width has been set using `set_level_width`. This is the default.
+    #[default]
+    Full,
+    /// The columns are separated by a single space and the level is printed as its first character: `I Main             Hello
+    /// World!`. The level comes first, followed by the target and the message. The timestamp and the process id are not printed,
+    /// unless a custom timestamp format has been set using `set_timestamp_format`, which can be used to add a short time like
+    /// `%H:%M` in front of the line. This is meant for narrow displays like serial consoles.
+    Compact,
+}
+
+/// This enum describes which whitespace gets trimmed from every message.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum TrimMode {
+    /// Messages will be printed exactly as they have been sent. This is the default.
+    #[default]
+    None,
+    /// Whitespace at the end of a message, like the newline of a line read from a subprocess, will be removed.
+    TrailingWhitespace,
+    /// Whitespace at the start and at the end of a message will be removed.
+    Both,
+}
+
+/// Set the preset describing the default layout of a log line. \
+/// \
+/// See [`LayoutPreset`] for what each preset prints. By default, the [`LayoutPreset::Full`] layout is used.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_layout_preset(LayoutPreset::Compact);
+///
+/// // this will be printed as `I Main             Hello World!`
+/// info!("Main"; "Hello World!");
+/// # }
+/// ```
+pub fn set_layout_preset(layout_preset: LayoutPreset) {
+    LAYOUT_PRESET.store(layout_preset as u8, Ordering::Relaxed);
+}
+
+/// Get the preset describing the default layout of a log line.
+pub fn layout_preset() -> LayoutPreset {
+    match LAYOUT_PRESET.load(Ordering::Relaxed) {
+        1 => LayoutPreset::Compact,
+        _ => LayoutPreset::Full,
+    }
+}
+
+/// Set whether log lines get colored at all. \
+/// \
+/// Disabling colors will remove every ANSI escape code from the log lines, including the styling of the timestamp. This takes
+/// precedence over any other color setting, like [`set_color_scope`], [`set_target_color`] or
+/// [`set_auto_target_colors`]. By default, colors are enabled.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_colors_enabled(false);
+///
+/// // this will be printed without any colors
+/// info!("Main"; "Hello World!");
+/// # }
+/// ```
+pub fn set_colors_enabled(colors_enabled: bool) {
+    COLORS_ENABLED.store(colors_enabled, Ordering::Relaxed);
+}
+
+/// Get whether log lines get colored at all.
+pub fn colors_enabled() -> bool {
+    COLORS_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Set whether the whole line of every log sent by the [`fatal!`](crate::fatal) macro gets a red background on the console. \
+/// \
+/// This makes fatal errors impossible to miss in a terminal. The background is reset at the end of the line, so it never bleeds
+/// into the following lines. It is only applied to the console: the log file and every sink receive the line without it. Just
+/// like any other color, it is not applied if colors have been disabled using [`set_colors_enabled`]. By default, fatal lines
+/// are not highlighted.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_fatal_highlight(true);
+///
+/// // `fatal!` would print its line with a red background before exiting the process
+/// # }
+/// ```
+pub fn set_fatal_highlight(fatal_highlight: bool) {
+    FATAL_HIGHLIGHT.store(fatal_highlight, Ordering::Relaxed);
+}
+
+/// Get whether the whole line of every log sent by the [`fatal!`](crate::fatal) macro gets a red background on the console.
+pub fn fatal_highlight() -> bool {
+    FATAL_HIGHLIGHT.load(Ordering::Relaxed)
+}
+
+/// Set which part of a log line gets colored based on its level. \
+/// \
+/// By default, only the level is colored. See [`ColorScope`] for all possible options.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_color_scope(ColorScope::WholeLine);
+///
+/// // this whole line will be printed in red
+/// error!("Main"; "Something went terribly wrong!");
+/// # }
+/// ```
+pub fn set_color_scope(color_scope: ColorScope) {
+    COLOR_SCOPE.store(color_scope as u8, Ordering::Relaxed);
+}
+
+/// Get the part of a log line that gets colored based on its level.
+pub fn color_scope() -> ColorScope {
+    match COLOR_SCOPE.load(Ordering::Relaxed) {
+        1 => ColorScope::Message,
+        2 => ColorScope::WholeLine,
+        _ => ColorScope::LevelOnly,
+    }
+}
+
+/// Set the maximum length of a message in bytes. \
+/// \
+/// Any message longer than this will be truncated and marked with `…(truncated N bytes)`, where `N` is the number of bytes
+/// removed. This protects the log from pathological messages like huge serialized payloads. The message will never be cut in the
+/// middle of a character, so it may end up slightly shorter than the limit. By default, messages are never truncated.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_max_message_len(Some(16));
+///
+/// // this will be printed as `A very long mess…(truncated 3 bytes)`
+/// info!("Main"; "A very long message");
+/// # }
+/// ```
+pub fn set_max_message_len(max_message_len: Option<usize>) {
+    MAX_MESSAGE_LEN.store(max_message_len.unwrap_or(usize::MAX), Ordering::Relaxed);
+}
+
+/// Get the maximum length of a message in bytes.
+pub fn max_message_len() -> Option<usize> {
+    match MAX_MESSAGE_LEN.load(Ordering::Relaxed) {
+        usize::MAX => None,
+        max_message_len => Some(max_message_len),
+    }
+}
+
+/// Set whether the id of the process is printed in its own column right after the timestamp. \
+/// \
+/// This helps to tell the logs of multiple processes sharing the same output apart. By default, the process id is not printed.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_show_pid(true);
+///
+/// // this will be printed as `29.05.2023 | 14:34:33 | 4242 | Main             | INFO  | Hello World!`
+/// info!("Main"; "Hello World!");
+/// # }
+/// ```
+pub fn set_show_pid(show_pid: bool) {
+    SHOW_PID.store(show_pid, Ordering::Relaxed);
+}
+
+/// Get whether the id of the process is printed in its own column.
+pub fn show_pid() -> bool {
+    SHOW_PID.load(Ordering::Relaxed)
+}
+
+/// Set the length of caller names for logs at the given `level`, overriding the `max_name_length` given while initiating the
+/// goolog logger. \
+/// \
+/// This can be used to print the full caller name of errors while keeping the target column of other logs narrow. Just like the
+/// `max_name_length` parameter, a length of `0` removes the limit. Passing [`None`] will restore the length given while
+/// initiating the goolog logger, which is used for every level by default.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// use goolog::log::Level;
+/// # fn main() {
+/// init_logger(None, Some(8), None);
+/// set_target_length_for(Level::Error, Some(0));
+///
+/// // this will be printed as `29.05.2023 | 14:34:33 | MySuperA | INFO  | Hello World!`
+/// info!("MySuperAwesomeMCManageClient"; "Hello World!");
+/// // this will be printed as `29.05.2023 | 14:34:33 | MySuperAwesomeMCManageClient | ERROR | Hello World!`
+/// error!("MySuperAwesomeMCManageClient"; "Hello World!");
+/// # }
+/// ```
+pub fn set_target_length_for(level: Level, length: Option<u32>) {
+    TARGET_LENGTHS[level as usize - 1].store(length.unwrap_or(u32::MAX), Ordering::Relaxed);
+}
+
+/// Get the length of caller names for logs at the given `level`, if it has been overridden using [`set_target_length_for`].
+pub fn target_length_for(level: Level) -> Option<u32> {
+    match TARGET_LENGTHS[level as usize - 1].load(Ordering::Relaxed) {
+        u32::MAX => None,
+        length => Some(length),
+    }
+}
+
+/// Set the `color` of the target column. \
+/// \
+/// This can be used to visually separate the target from the message. Passing [`None`] will restore the default of not coloring
+/// the target column. When the whole line gets colored using [`ColorScope::WholeLine`], the target column will share the color of
+/// the line instead.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_target_color(Some(Color::BrightBlack));
+///
+/// // the target column will be printed in grey
+/// info!("Main"; "Hello World!");
+/// # }
+/// ```
+pub fn set_target_color(color: Option<Color>) {
+    *TARGET_COLOR
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = color;
+}
+
+/// Get the color of the target column.
+pub fn target_color() -> Option<Color> {
+    *TARGET_COLOR
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Set whether the target column gets a color picked automatically based on the target. \
+/// \
+/// Every target will be hashed to pick one of the colors of the palette, so the lines of the same target always share the same
+/// color, even across restarts. This makes it easy to visually group the lines of many different targets. When enabled, this
+/// takes precedence over [`set_target_color`]. By default, the target column does not get colored automatically.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_auto_target_colors(true);
+///
+/// // the target columns of these lines will be printed in different colors
+/// info!("Server"; "Hello World!");
+/// info!("Database"; "Hello World!");
+/// # }
+/// ```
+pub fn set_auto_target_colors(auto_target_colors: bool) {
+    AUTO_TARGET_COLORS.store(auto_target_colors, Ordering::Relaxed);
+}
+
+/// Get whether the target column gets a color picked automatically based on the target.
+pub fn auto_target_colors() -> bool {
+    AUTO_TARGET_COLORS.load(Ordering::Relaxed)
+}
+
+/// Set the number of colors used by [`set_auto_target_colors`]. \
+/// \
+/// The `size` will be clamped between 1 and the 12 colors of the palette, which are used by default. A smaller palette can be
+/// useful if some of the colors are hard to read on the background of the terminal. Changing the size will change the color of
+/// most targets.
+pub fn set_target_palette_size(size: usize) {
+    TARGET_PALETTE_SIZE.store(size.clamp(1, TARGET_PALETTE.len()), Ordering::Relaxed);
+}
+
+/// Get the number of colors used by [`set_auto_target_colors`].
+pub fn target_palette_size() -> usize {
+    TARGET_PALETTE_SIZE.load(Ordering::Relaxed)
+}
+
+/// Pick the color of the given `target` from the palette used by [`set_auto_target_colors`].
+pub(crate) fn auto_target_color(target: &str) -> Color {
+    let hash = stable_hash(FNV_OFFSET_BASIS, target.as_bytes());
+
+    TARGET_PALETTE[(hash % target_palette_size() as u64) as usize]
+}
+
+/// The initial value of [`stable_hash`].
+pub(crate) const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// Continue the given FNV-1a `hash` with the given `bytes`. \
+/// Unlike the hasher of the standard library, this hash is stable across releases and platforms.
+pub(crate) fn stable_hash(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Set the `separator` printed between the message and the columns in front of it, like the target or the level. \
+/// \
+/// This can be used to make the message easier to split off, for example using a tab. The other columns will still be separated
+/// as described by the [`LayoutPreset`]. A template set using [`set_format`](crate::set_format) takes precedence over this
+/// separator. Passing [`None`] will restore the separator of the layout preset, which is ` | ` by default.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_target_message_separator(Some("\t"));
+///
+/// // this will be printed as `29.05.2023 | 14:34:33 | Main             | INFO \tHello World!`
+/// info!("Main"; "Hello World!");
+/// # }
+/// ```
+pub fn set_target_message_separator(separator: Option<&'static str>) {
+    *TARGET_MESSAGE_SEPARATOR
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = separator;
+}
+
+/// Get the separator printed between the message and the columns in front of it.
+pub fn target_message_separator() -> Option<&'static str> {
+    *TARGET_MESSAGE_SEPARATOR
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Set the `icons` printed in front of the level of every log. \
+/// \
+/// The icons are given in the order `[error, warn, info, debug, trace]`; logs sent by the [`fatal!`](crate::fatal) macro use the
+/// error icon. Icons narrower than the widest one will be padded, so the level column stays aligned. Emoji like `✅` are counted as
+/// two columns wide. Passing [`None`] will restore the default of not printing any icons, which is best kept for logs read by
+/// other programs.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_level_icons(Some(["❌", "⚠️", "ℹ️", "🔧", "🔍"]));
+///
+/// // the level of this line will be printed as `ℹ️ INFO `
+/// info!("Main"; "Hello World!");
+/// # }
+/// ```
+pub fn set_level_icons(icons: Option<[&'static str; 5]>) {
+    *LEVEL_ICONS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = icons;
+}
+
+/// Get the icons printed in front of the level of every log.
+pub fn level_icons() -> Option<[&'static str; 5]> {
+    *LEVEL_ICONS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Set the `width` the level column gets padded to. \
+/// \
+/// A wider column can be used to align the messages with the ones of another program, while the default width of 5 is as tight
+/// as possible. The `width` will never be smaller than the longest level name, so no level gets cut. Just like the level icons,
+/// this only affects the [`LayoutPreset::Full`] layout.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_level_width(7);
+///
+/// // this will be printed as `29.05.2023 | 14:34:33 | Main             | INFO    | Hello World!`
+/// info!("Main"; "Hello World!");
+/// # }
+/// ```
+pub fn set_level_width(width: u8) {
+    LEVEL_WIDTH.store((width as usize).max(LEVEL_NAME_WIDTH), Ordering::Relaxed);
+}
+
+/// Get the width the level column gets padded to.
+pub fn level_width() -> usize {
+    LEVEL_WIDTH.load(Ordering::Relaxed)
+}
+
+/// Get the number of columns the given `text` takes up in a terminal. \
+/// \
+/// This is an approximation good enough for the level icons: emoji take up two columns, while variation selectors, zero width
+/// joiners and combining marks take up none. Every other character takes up a single column.
+pub(crate) fn display_width(text: &str) -> usize {
+    let mut chars = text.chars().peekable();
+    let mut width = 0;
+    while let Some(char) = chars.next() {
+        width += match char {
+            '\u{200D}' | '\u{FE00}'..='\u{FE0F}' | '\u{0300}'..='\u{036F}' => 0,
+            // the miscellaneous symbols and dingbats like `✅` and `❌` are mostly displayed as emoji too
+            '\u{2600}'..='\u{27BF}' | '\u{2B00}'..='\u{2BFF}' | '\u{1F000}'.. => 2,
+            // a symbol followed by the emoji variation selector gets displayed as an emoji
+            _ if chars.peek() == Some(&'\u{FE0F}') => 2,
+            _ => 1,
+        };
+    }
+    width
+}
+
+/// Set a `transform` which will be applied to every message after it has been formatted and before it gets printed. \
+/// \
+/// This can be used to post-process every message, for example to redact tokens or passwords which accidentally ended up in a
+/// log. The transform will also be applied to the messages sent to any [`Sink`](crate::Sink). Setting a new transform will replace
+/// the previous one.
+///
+/// # Performance
+///
+/// The transform runs on every log. It should therefore be fast and return [`Cow::Borrowed`] if it did not change the message.
+///
+/// # Example
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_message_transform(&|message| match message.find("password=") {
+///     Some(index) => Cow::Owned(format!("{}password=<redacted>", &message[..index])),
+///     None => Cow::Borrowed(message),
+/// });
+///
+/// // this will be printed as `Logging in with password=<redacted>`
+/// info!("Main"; "Logging in with password=hunter2");
+/// # }
+/// ```
+pub fn set_message_transform(transform: MessageTransform) {
+    *MESSAGE_TRANSFORM
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(transform);
+}
+
+/// Set whether ANSI escape codes get removed from every message. \
+/// \
+/// Messages containing text which has not been written by the program itself, like the captured output of a subprocess, may
+/// contain escape codes changing the color of the rest of the line or even clearing the terminal. Enabling this removes any
+/// such code from the message before the transform set using [`set_message_transform`] gets applied. The colors added by the
+/// goolog logger itself are not affected. By default, messages are printed as they are.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_strip_incoming_ansi(true);
+///
+/// // this will be printed as `cargo: Compiling goolog`
+/// info!("Main"; "cargo: \x1b[1m\x1b[32mCompiling\x1b[0m goolog");
+/// # }
+/// ```
+pub fn set_strip_incoming_ansi(strip_incoming_ansi: bool) {
+    STRIP_INCOMING_ANSI.store(strip_incoming_ansi, Ordering::Relaxed);
+}
+
+/// Get whether ANSI escape codes get removed from every message.
+pub fn strip_incoming_ansi() -> bool {
+    STRIP_INCOMING_ANSI.load(Ordering::Relaxed)
+}
+
+/// Set which whitespace gets trimmed from every message. \
+/// \
+/// Messages fed from line-oriented sources, like `writeln!`-based writers or the output of a subprocess, often end with a newline,
+/// which shows up as a blank line after the log line. Trimming removes such whitespace after any ANSI escape code has been removed
+/// by [`set_strip_incoming_ansi`], but before the transform set using [`set_message_transform`] gets applied. Whitespace in the
+/// middle of a message is never touched. By default, messages are printed exactly as they have been sent.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_trim_message(TrimMode::TrailingWhitespace);
+///
+/// // this will be printed as `Compiling goolog` without an empty line after it
+/// info!("Main"; "Compiling goolog\n");
+/// # }
+/// ```
+pub fn set_trim_message(trim_mode: TrimMode) {
+    TRIM_MESSAGE.store(trim_mode as u8, Ordering::Relaxed);
+}
+
+/// Get which whitespace gets trimmed from every message.
+pub fn trim_message() -> TrimMode {
+    match TRIM_MESSAGE.load(Ordering::Relaxed) {
+        1 => TrimMode::TrailingWhitespace,
+        2 => TrimMode::Both,
+        _ => TrimMode::None,
+    }
+}
+
+/// Apply the transform set using [`set_message_transform`] to the given `message`. \
+/// If enabled by [`set_strip_incoming_ansi`], every ANSI escape code will be removed from the message beforehand, followed by
+/// the whitespace selected by [`set_trim_message`].
+pub(crate) fn transform_message(message: String) -> String {
+    let message = if strip_incoming_ansi() && message.contains('\x1b') {
+        strip_ansi(&message)
+    } else {
+        message
+    };
+    let message = match trim_message() {
+        TrimMode::None => message,
+        TrimMode::TrailingWhitespace => match message.trim_end().len() {
+            len if len == message.len() => message,
+            len => {
+                let mut message = message;
+                message.truncate(len);
+                message
+            }
+        },
+        TrimMode::Both => match message.trim() {
+            trimmed if trimmed.len() == message.len() => message,
+            trimmed => trimmed.to_string(),
+        },
+    };
+    let transform = *MESSAGE_TRANSFORM
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match transform.map(|transform| transform(&message)) {
+        Some(Cow::Owned(transformed)) => transformed,
+        _ => message,
+    }
+}
+
+/// Truncate the given `message` to be at most `max_message_len` bytes long without splitting any character.
+fn truncate_message(message: &mut String, max_message_len: usize) {
+    if message.len() <= max_message_len {
+        return;
+    }
+
+    let mut end = max_message_len;
+    while !message.is_char_boundary(end) {
+        end -= 1;
+    }
+    let truncated = message.len() - end;
+    message.truncate(end);
+    message.push_str(&format!("…(truncated {truncated} bytes)"));
+}
+
+/// Remove all ANSI escape codes from the given `text`. \
+/// \
+/// Besides the color codes, this also removes any other control sequence, like the ones moving the cursor, and operating system
+/// commands, like the ones setting the title of the terminal.
+pub(crate) fn strip_ansi(text: &str) -> String {
+    let mut stripped = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(char) = chars.next() {
+        if char != '\x1b' {
+            stripped.push(char);
+            continue;
+        }
+
+        match chars.next() {
+            // a control sequence ends with its first character between `@` and `~`
+            Some('[') => {
+                chars.by_ref().find(|char| ('@'..='~').contains(char));
+            }
+            // an operating system command ends with a bell or a string terminator
+            Some(']') => {
+                while let Some(char) = chars.next() {
+                    if char == '\x07' || (char == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                        break;
+                    }
+                }
+            }
+            // intermediate characters are followed by a single final character
+            Some(' '..='/') => {
+                chars.by_ref().find(|char| ('0'..='~').contains(char));
+            }
+            // every other escape sequence consists of a single character
+            _ => {}
+        }
+    }
+
+    stripped
+}
+
+/// Wrap the given `text` in the ANSI escape codes of the given `color`.
+fn paint(color: Color, text: &str) -> String {
+    if !colors_enabled() {
+        return text.to_string();
+    }
+
+    format!("\x1b[{}m{text}\x1b[0m", color.to_fg_str())
+}
+
+/// Remove the marker the [`fatal!`](crate::fatal) macro adds to the `message` of its logs. \
+/// The returned boolean indicates whether the marker has been found.
+pub(crate) fn strip_fatal_marker(level: Level, message: String) -> (bool, String) {
+    if let Level::Error = level {
+        if let Some(val) = message.strip_prefix("$goolog:fatal=") {
+            return (true, val.into());
+        }
+    }
+
+    (false, message)
+}
+
+/// Edit the name to be `max_name_length` long.
+pub(crate) fn to_fixed_size(max_name_length: u32, name: &str) -> String {
+    if max_name_length == 0 {
+        return name.to_string();
+    }
+
+    let mut new_name = vec![];
+    let mut name_iter = name.chars();
+    for _ in 0..max_name_length {
+        if let Some(name_char) = name_iter.next() {
+            new_name.push(name_char.to_string());
+        } else {
+            new_name.push(' '.to_string());
+        }
+    }
+
+    new_name.concat()
+}
+
+/// Generate the log line using the [`Formatter`](crate::Formatter) set by [`set_formatter`](crate::set_formatter).
+pub(crate) fn generate_log(
+    max_name_length: u32,
+    record: &Record,
+    colors: ColoredLevelConfig,
+    message: &Arguments,
+) -> String {
+    // the log has usually been captured already, so its arguments do not need to be formatted again
+    let entry = entry::current().unwrap_or_else(|| {
+        let (fatal, message) = strip_fatal_marker(record.level(), message.to_string());
+        Rc::new(Entry {
+            message: transform_message(message),
+            fatal,
+            #[cfg(all(
+                feature = "timestamp",
+                not(all(feature = "wasm", target_arch = "wasm32"))
+            ))]
+            time: chrono::Local::now(),
+        })
+    });
+
+    formatter::format(
+        record,
+        &FormatCtx {
+            max_name_length,
+            colors,
+            fatal: entry.fatal,
+            message: &entry.message,
+            #[cfg(feature = "timestamp")]
+            timestamp_format: None,
+        },
+        None,
+    )
+}
+
+/// Give the given console `log` line of the given `record` a red background if it has been sent by the [`fatal!`](crate::fatal)
+/// macro and enabled by [`set_fatal_highlight`]. \
+/// Every reset sequence within the line re-applies the background, so the colors of the columns do not end it early.
+#[cfg_attr(all(feature = "wasm", target_arch = "wasm32"), allow(dead_code))]
+pub(crate) fn highlight_fatal(record: &Record, message: &Arguments, log: String) -> String {
+    if !fatal_highlight() || !colors_enabled() {
+        return log;
+    }
+    let fatal = entry::current().map_or_else(
+        || strip_fatal_marker(record.level(), message.to_string()).0,
+        |entry| entry.fatal,
+    );
+    if !fatal {
+        return log;
+    }
+
+    format!(
+        "\x1b[41m{}\x1b[0m",
+        log.replace("\x1b[0m", "\x1b[0m\x1b[41m")
+    )
+}
+
+/// Generate the log line for a `record` received by a [`Sink`](crate::Sink), without any ANSI escape codes. \
+/// `fatal` indicates whether the record has been sent by the [`fatal!`](crate::fatal) macro. If no `formatter` is given, the one
+/// set by [`set_formatter`](crate::set_formatter) will be used.
+pub(crate) fn generate_plain_log(
+    record: &Record,
+    fatal: bool,
+    formatter: Option<&dyn Formatter>,
+) -> String {
+    strip_ansi(&formatter::format(
+        record,
+        &sink_ctx(fatal, &record.args().to_string()),
+        formatter,
+    ))
+}
+
+/// Generate the log for a `record` received by a [`Sink`](crate::Sink) sending binary data, using
+/// [`Formatter::format_bytes`] of the given `formatter`. \
+/// `fatal` indicates whether the record has been sent by the [`fatal!`](crate::fatal) macro.
+#[cfg_attr(not(feature = "cbor"), allow(dead_code))]
+pub(crate) fn generate_bytes(record: &Record, fatal: bool, formatter: &dyn Formatter) -> Vec<u8> {
+    formatter.format_bytes(record, &sink_ctx(fatal, &record.args().to_string()))
+}
+
+/// Create the context passed to the formatter of a [`Sink`](crate::Sink) for a log with the given `message`.
+fn sink_ctx(fatal: bool, message: &str) -> FormatCtx<'_> {
+    FormatCtx {
+        max_name_length: MAX_NAME_LENGTH.load(Ordering::Relaxed),
+        colors: ColoredLevelConfig::new(),
+        fatal,
+        message,
+        #[cfg(feature = "timestamp")]
+        timestamp_format: None,
+    }
+}
+
+/// Generate a header naming the columns of the default layout, aligned with the log lines generated using the current settings. \
+/// Returns [`None`] if the header has not been enabled using [`set_column_header`](crate::set_column_header) or the log lines
+/// do not use the default layout.
+pub(crate) fn header_line() -> Option<String> {
+    if !columns::column_header()
+        || layout_preset() == LayoutPreset::Compact
+        || template::format().is_some()
+        || formatter::is_set()
+    {
+        return None;
+    }
+
+    #[cfg(feature = "timestamp")]
+    let timestamp = if timestamp::show_timestamp() {
+        timestamp::header()
+    } else {
+        String::new()
+    };
+    #[cfg(not(feature = "timestamp"))]
+    let timestamp = String::new();
+    let version = version::version_for(VersionPlacement::Line)
+        .map(|version| to_fixed_size(version.chars().count() as u32, "VERSION"))
+        .unwrap_or_default();
+    let pid = if show_pid() {
+        let pid = PID.get_or_init(|| std::process::id().to_string());
+        to_fixed_size(pid.len() as u32, "PID")
+    } else {
+        String::new()
+    };
+    let delta = if delta::show_delta() {
+        to_fixed_size(8, "DELTA")
+    } else {
+        String::new()
+    };
+    let target = to_fixed_size(MAX_NAME_LENGTH.load(Ordering::Relaxed), "TARGET");
+    let mut level_width = level_width();
+    if let Some(icons) = level_icons() {
+        level_width += icons
+            .iter()
+            .map(|icon| display_width(icon))
+            .max()
+            .unwrap_or_default()
+            + 1;
+    }
+    let level = to_fixed_size(level_width as u32, "LEVEL");
+
+    let mut columns = vec![];
+    for column in columns::column_order() {
+        match column {
+            Column::Timestamp if timestamp.is_empty() => {}
+            Column::Timestamp => columns.push(timestamp.as_str()),
+            Column::Version if version.is_empty() => {}
+            Column::Version => columns.push(&version),
+            Column::Pid if pid.is_empty() => {}
+            Column::Pid => columns.push(&pid),
+            Column::Delta if delta.is_empty() => {}
+            Column::Delta => columns.push(&delta),
+            Column::Target => columns.push(&target),
+            Column::Level => columns.push(&level),
+            // the message is always the last column
+            Column::Message => {}
+        }
+    }
+    if columns.is_empty() {
+        return Some("MESSAGE".to_string());
+    }
+
+    Some(format!(
+        "{}{}MESSAGE",
+        columns.join(" | "),
+        target_message_separator().unwrap_or(" | ")
+    ))
+}
+
+/// Generate the log line using the default layout of the goolog logger.
+pub(crate) fn default_log(record: &Record, ctx: &FormatCtx) -> String {
+    let color_scope = color_scope();
+
+    let level_name = if ctx.fatal {
+        "FATAL".to_string()
+    } else {
+        record.level().to_string()
+    };
+
+    let mut message = ctx.message.to_string();
+    if let Some(max_message_len) = max_message_len() {
+        truncate_message(&mut message, max_message_len);
+    }
+    let multiline = multiline::multiline();
+    if let MultilineMode::EscapeNewlines = multiline {
+        message = multiline::escape_newlines(&message);
+    }
+    message = indent::indent_message(message, multiline);
+
+    let level_color = ctx.colors.get_color(&record.level());
+    let max_name_length = target_length_for(record.level()).unwrap_or(ctx.max_name_length);
+    let mut name = to_fixed_size(max_name_length, record.target());
+    let target_color = if auto_target_colors() {
+        Some(auto_target_color(record.target()))
+    } else {
+        target_color()
+    };
+    if let (Some(target_color), ColorScope::LevelOnly | ColorScope::Message) =
+        (target_color, color_scope)
+    {
+        name = paint(target_color, &name);
+    }
+    let layout_preset = layout_preset();
+    let log_level = match layout_preset {
+        LayoutPreset::Full => format!("{level_name:0$}", level_width()),
+        LayoutPreset::Compact => level_name.chars().take(1).collect(),
+    };
+    let mut log_level = match color_scope {
+        ColorScope::WholeLine => log_level,
+        _ => paint(level_color, &log_level),
+    };
+    if let Some(icons) = level_icons() {
+        let icon = icons[record.level() as usize - 1];
+        let width = icons
+            .iter()
+            .map(|icon| display_width(icon))
+            .max()
+            .unwrap_or_default();
+        let padding = " ".repeat(width - display_width(icon));
+        log_level = format!("{icon}{padding} {log_level}");
+    }
+    if let ColorScope::Message = color_scope {
+        message = paint(level_color, &message);
+    }
+    if let Some((trace_id, span_id)) = context::trace_ids() {
+        message = format!("trace_id={trace_id} span_id={span_id} | {message}");
+    }
+
+    #[cfg(feature = "timestamp")]
+    let timestamp = if timestamp::show_timestamp() {
+        // the reset sequences of the styled timestamp would end the color of the line
+        timestamp::timestamp(
+            colors_enabled() && color_scope != ColorScope::WholeLine,
+            ctx.timestamp_format,
+        )
+    } else {
+        String::new()
+    };
+    #[cfg(not(feature = "timestamp"))]
+    let timestamp = String::new();
+
+    let pid = if show_pid() {
+        PID.get_or_init(|| std::process::id().to_string()).as_str()
+    } else {
+        ""
+    };
+
+    let delta = delta::delta()
+        .map(|delta| format!("{:8}", format!("+{}ms", delta.as_millis())))
+        .unwrap_or_default();
+
+    let version = version::version_for(VersionPlacement::Line).unwrap_or_default();
+
+    let log = template::render(&LogParts {
+        timestamp: &timestamp,
+        version,
+        pid,
+        delta: &delta,
+        target: &name,
+        level: &log_level,
+        message: &message,
+    })
+    .unwrap_or_else(|| {
+        let (columns, separator) = if let LayoutPreset::Compact = layout_preset {
+            let mut columns = vec![];
+            #[cfg(feature = "timestamp")]
+            if timestamp::show_timestamp()
+                && (ctx.timestamp_format.is_some() || timestamp::timestamp_format().is_some())
+            {
+                columns.push(timestamp.as_str());
+            }
+            columns.extend([log_level.as_str(), &name]);
+
+            (columns.join(" "), " ")
+        } else {
+            let mut columns = vec![];
+            for column in columns::column_order() {
+                match column {
+                    Column::Timestamp if timestamp.is_empty() => {}
+                    Column::Timestamp => columns.push(timestamp.as_str()),
+                    Column::Version if version.is_empty() => {}
+                    Column::Version => columns.push(version),
+                    Column::Pid if pid.is_empty() => {}
+                    Column::Pid => columns.push(pid),
+                    Column::Delta if delta.is_empty() => {}
+                    Column::Delta => columns.push(&delta),
+                    Column::Target => columns.push(&name),
+                    Column::Level => columns.push(&log_level),
+                    // the message is always the last column
+                    Column::Message => {}
+                }
+            }
+
+            (columns.join(" | "), " | ")
+        };
+
+        if columns.is_empty() {
+            return message.clone();
+        }
+        let separator = target_message_separator().unwrap_or(separator);
+        format!("{columns}{separator}{message}")
+    });
+    let log = match multiline {
+        MultilineMode::IndentContinuation => multiline::indent_continuation(&log, &message),
+        _ => log,
+    };
+
+    match color_scope {
+        ColorScope::WholeLine => paint(level_color, &log),
+        _ => log,
+    }
+}
@@ -0,0 +1,182 @@
+//! This module provides the [`Formatter`] trait used to replace the whole logic generating the log lines.
+
+use std::{
+    fmt::{
+        self,
+        Write,
+    },
+    sync::RwLock,
+};
+
+use fern::colors::{
+    Color,
+    ColoredLevelConfig,
+};
+use log::{
+    Level,
+    Record,
+};
+
+use crate::format::default_log;
+
+/// The formatter used to generate the log lines. \
+/// [`None`] means the [`DefaultFormatter`] will be used.
+static FORMATTER: RwLock<Option<Box<dyn Formatter>>> = RwLock::new(None);
+
+/// The context of a log line passed to a [`Formatter`]. \
+/// It gives access to the configuration of the goolog logger and the already processed message.
+#[derive(Clone, Copy)]
+pub struct FormatCtx<'a> {
+    /// The length of caller names given while initiating the goolog logger.
+    pub(crate) max_name_length: u32,
+    /// The colors used for every level.
+    pub(crate) colors: ColoredLevelConfig,
+    /// Whether the log has been sent by the [`fatal!`](crate::fatal) macro.
+    pub(crate) fatal: bool,
+    /// The message of the log.
+    pub(crate) message: &'a str,
+    /// The chrono format of the timestamp and its width, overriding the one set using
+    /// [`set_timestamp_format`](crate::set_timestamp_format).
+    #[cfg(feature = "timestamp")]
+    pub(crate) timestamp_format: Option<(&'a str, usize)>,
+}
+impl FormatCtx<'_> {
+    /// Get the length of caller names given while initiating the goolog logger. \
+    /// A length of `0` means the caller names should not be limited.
+    pub fn max_name_length(&self) -> u32 {
+        self.max_name_length
+    }
+
+    /// Get the color used for logs at the given `level`.
+    pub fn level_color(&self, level: Level) -> Color {
+        self.colors.get_color(&level)
+    }
+
+    /// Get whether the log has been sent by the [`fatal!`](crate::fatal) macro.
+    pub fn fatal(&self) -> bool {
+        self.fatal
+    }
+
+    /// Get the message of the log. \
+    /// Unlike the arguments of the record, the message has already been processed by the transform set using
+    /// [`set_message_transform`](crate::set_message_transform) and does not contain the marker added by the
+    /// [`fatal!`](crate::fatal) macro.
+    pub fn message(&self) -> &str {
+        self.message
+    }
+}
+
+/// A formatter generating the log lines printed by the goolog logger. \
+/// \
+/// Formatters only decide how a log line looks. Where it gets printed to and which logs get printed at all is still decided by
+/// the goolog logger. Formatters can be set using the [`set_formatter`] function.
+pub trait Formatter: Send + Sync {
+    /// Write the log line for the given `record` to `out`. \
+    /// The `ctx` gives access to the configuration of the goolog logger and the already processed message.
+    ///
+    /// # Errors
+    ///
+    /// This function should only return an error if writing to `out` failed. Anything written before the error will still be
+    /// printed.
+    fn format(&self, out: &mut dyn Write, record: &Record, ctx: &FormatCtx) -> fmt::Result;
+
+    /// Generate the log for the given `record` as bytes. \
+    /// \
+    /// Sinks sending binary data, like a [`CborSink`](crate::CborSink) given this formatter, call this function instead of
+    /// [`format`](Self::format), so binary formats like MessagePack do not have to go through a UTF-8 string. The console, the log
+    /// file and every sink sending text lines always call [`format`](Self::format), so a formatter used by them needs to implement
+    /// the text path. By default, this returns the UTF-8 bytes of the line written by [`format`](Self::format).
+    fn format_bytes(&self, record: &Record, ctx: &FormatCtx) -> Vec<u8> {
+        let mut log = String::new();
+        // anything written before an error will still be used
+        let _ = self.format(&mut log, record, ctx);
+
+        log.into_bytes()
+    }
+}
+
+/// The formatter generating the default log lines of the goolog logger. \
+/// \
+/// This formatter respects every setting changing the default layout, like [`set_format`](crate::set_format) or
+/// [`set_color_scope`](crate::set_color_scope).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultFormatter;
+impl Formatter for DefaultFormatter {
+    fn format(&self, out: &mut dyn Write, record: &Record, ctx: &FormatCtx) -> fmt::Result {
+        out.write_str(&default_log(record, ctx))
+    }
+}
+
+/// Set the `formatter` used to generate the log lines, replacing the whole logic of the goolog logger. \
+/// \
+/// Passing [`None`] will restore the [`DefaultFormatter`]. Since the formatter replaces the default layout, any setting changing
+/// the default layout, like [`set_format`](crate::set_format), will have no effect unless the new formatter calls the
+/// [`DefaultFormatter`] itself.
+///
+/// # Deadlocks
+///
+/// Calling this function from within [`Formatter::format`] will deadlock.
+///
+/// # Example
+///
+/// ```
+/// use std::fmt::{
+///     self,
+///     Write,
+/// };
+///
+/// use goolog::*;
+/// use goolog::log::Record;
+///
+/// struct ArrowFormatter;
+/// impl Formatter for ArrowFormatter {
+///     fn format(&self, out: &mut dyn Write, record: &Record, ctx: &FormatCtx) -> fmt::Result {
+///         write!(out, "{} -> {}", record.target(), ctx.message())
+///     }
+/// }
+/// # fn main() {
+///
+/// init_logger(None, None, None);
+/// set_formatter(Some(Box::new(ArrowFormatter)));
+///
+/// // this will be printed as `Main -> Hello World!`
+/// info!("Main"; "Hello World!");
+/// # }
+/// ```
+pub fn set_formatter(formatter: Option<Box<dyn Formatter>>) {
+    *FORMATTER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = formatter;
+}
+
+/// Check whether a custom formatter has been set using [`set_formatter`].
+pub(crate) fn is_set() -> bool {
+    FORMATTER
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .is_some()
+}
+
+/// Generate the log line for the given `record` using the given `formatter`, or the one set by [`set_formatter`] if it is
+/// [`None`].
+pub(crate) fn format(
+    record: &Record,
+    ctx: &FormatCtx,
+    formatter: Option<&dyn Formatter>,
+) -> String {
+    let mut log = String::new();
+    // anything written before an error will still be printed
+    let _ = match formatter {
+        Some(formatter) => formatter.format(&mut log, record, ctx),
+        None => match FORMATTER
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .as_deref()
+        {
+            Some(formatter) => formatter.format(&mut log, record, ctx),
+            None => DefaultFormatter.format(&mut log, record, ctx),
+        },
+    };
+
+    log
+}
@@ -0,0 +1,89 @@
+//! This module provides the [`LoggerGuard`] and the [`install_exit_flush`] function used to flush the goolog logger once `main`
+//! returns.
+
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+use std::{
+    ffi::c_int,
+    panic,
+    sync::Once,
+};
+
+use crate::sink;
+
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+extern "C" {
+    /// Register a function called once the process exits normally.
+    fn atexit(callback: extern "C" fn()) -> c_int;
+}
+
+/// A guard returned by [`init_logger_with_guard`](crate::init_logger_with_guard). \
+/// \
+/// Dropping this guard flushes the goolog logger and every registered [`Sink`](crate::Sink). Keep it alive until the end of
+/// `main`, so logs still buffered when the program exits normally do not get lost. Dropping it earlier flushes the logger at that
+/// point; logs sent afterwards will then not be flushed by it.
+#[must_use = "the logger will be flushed immediately if the guard is not held"]
+pub struct LoggerGuard {
+    /// Prevents this guard from being created outside of this crate.
+    _private: (),
+}
+impl LoggerGuard {
+    /// Create a new guard.
+    pub(crate) fn new() -> Self {
+        Self { _private: () }
+    }
+}
+impl Drop for LoggerGuard {
+    fn drop(&mut self) {
+        flush();
+    }
+}
+
+/// Flush the goolog logger once the process exits normally, without having to hold a [`LoggerGuard`]. \
+/// \
+/// The flush gets registered using the `atexit` function of the C library, so it runs when `main` returns and when
+/// [`std::process::exit`] gets called, flushing the logger and every registered [`Sink`](crate::Sink). Calling this function
+/// more than once registers the flush only once. Libraries should leave calling this function up to the application.
+///
+/// # Limitations
+///
+/// The flush does not run if the process gets aborted, like by [`std::process::abort`] or a panic while compiled with
+/// `panic = "abort"`, or gets killed by a signal like `SIGKILL`, or `SIGTERM` without a handler exiting the process. Logs buffered
+/// in these cases still get lost. On `wasm32` targets, where there is no process to exit, this function does nothing.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// install_exit_flush();
+///
+/// info!("Main"; "This will be flushed once main returns.");
+/// # }
+/// ```
+pub fn install_exit_flush() {
+    #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+    {
+        /// Makes sure the flush only gets registered once.
+        static INSTALLED: Once = Once::new();
+
+        /// Flush the goolog logger while the process is exiting.
+        extern "C" fn flush_on_exit() {
+            // a panic must not unwind into the C library
+            panic::catch_unwind(flush).ok();
+        }
+
+        INSTALLED.call_once(|| {
+            // SAFETY: the callback is a valid function for the whole lifetime of the process and never unwinds
+            unsafe {
+                atexit(flush_on_exit);
+            }
+        });
+    }
+}
+
+/// Flush the goolog logger and every registered sink.
+fn flush() {
+    log::logger().flush();
+    sink::flush();
+}
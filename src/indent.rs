@@ -0,0 +1,102 @@
+//! This module provides the [`indent`] function used to indent the messages of nested operations.
+
+use std::{
+    cell::Cell,
+    marker::PhantomData,
+    sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+};
+
+use crate::multiline::MultilineMode;
+
+/// The number of spaces every level of indentation adds in front of a message.
+static INDENT_WIDTH: AtomicUsize = AtomicUsize::new(2);
+
+thread_local! {
+    /// The number of [`IndentGuard`]s currently alive on this thread.
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Indent the message of every log sent on this thread until the returned [`IndentGuard`] gets dropped. \
+/// \
+/// Every guard alive on the current thread adds [`indent_width`] spaces in front of the message, so nested operations like the
+/// steps of a build become visible in the log. The indentation only applies to the default layout and the templates set using
+/// [`set_format`](crate::set_format). A custom [`Formatter`](crate::Formatter) can read it using [`indent_depth`].
+///
+/// # Multiline messages
+///
+/// When using [`MultilineMode::IndentContinuation`], every line of a message will be indented. Otherwise, only its first line
+/// will be indented, so [`MultilineMode::Raw`] continuation lines still start at the beginning of the line.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+///
+/// info!("Build"; "Building the project...");
+/// {
+///     let _guard = indent();
+///
+///     // this will be printed as `  Compiling goolog`
+///     info!("Build"; "Compiling goolog");
+/// }
+/// # }
+/// ```
+pub fn indent() -> IndentGuard {
+    DEPTH.with(|depth| depth.set(depth.get() + 1));
+
+    IndentGuard {
+        _not_send: PhantomData,
+    }
+}
+
+/// Get the number of levels of indentation of the messages sent on this thread.
+pub fn indent_depth() -> usize {
+    DEPTH.with(Cell::get)
+}
+
+/// Set the number of spaces every level of indentation adds in front of a message. \
+/// By default, every level adds two spaces.
+pub fn set_indent_width(indent_width: usize) {
+    INDENT_WIDTH.store(indent_width, Ordering::Relaxed);
+}
+
+/// Get the number of spaces every level of indentation adds in front of a message.
+pub fn indent_width() -> usize {
+    INDENT_WIDTH.load(Ordering::Relaxed)
+}
+
+/// A guard returned by [`indent`]. \
+/// The messages of this thread will stay indented until this guard gets dropped.
+#[must_use = "the indentation will be removed immediately if the guard is not held"]
+pub struct IndentGuard {
+    /// The indentation belongs to the thread which created this guard, so it must not be dropped on another one.
+    _not_send: PhantomData<*const ()>,
+}
+impl Drop for IndentGuard {
+    fn drop(&mut self) {
+        DEPTH.with(|depth| depth.set(depth.get().saturating_sub(1)));
+    }
+}
+
+/// Indent the given `message` by the current depth of this thread, as described by [`indent`].
+pub(crate) fn indent_message(message: String, multiline: MultilineMode) -> String {
+    let indentation = " ".repeat(indent_depth() * indent_width());
+    if indentation.is_empty() {
+        return message;
+    }
+
+    match multiline {
+        MultilineMode::IndentContinuation => {
+            format!(
+                "{indentation}{}",
+                message.replace('\n', &format!("\n{indentation}"))
+            )
+        }
+        _ => format!("{indentation}{message}"),
+    }
+}
@@ -0,0 +1,30 @@
+//! This module provides the [`Lazy`] wrapper used by the [`lazy!`](crate::lazy) macro to defer building an expensive message.
+
+use std::fmt::{
+    self,
+    Display,
+};
+
+/// A message argument which only gets built once the log is actually printed, as created by the [`lazy!`](crate::lazy) macro. \
+/// \
+/// The wrapped closure gets called when the argument is formatted. Since the goolog logger formats the message of a log only
+/// once, after the log passed the log level and every filter like [`mute_target`](crate::mute_target) or
+/// [`set_sampling`](crate::set_sampling), the closure is not called at all for a log which will not be printed, and called
+/// exactly once for every other log. The console, the log file and every sink then reuse the already formatted message.
+#[derive(Clone, Copy)]
+pub struct Lazy<F>(F);
+impl<F> Lazy<F> {
+    /// Wrap the given `closure`, which will be called every time this argument is formatted.
+    pub fn new(closure: F) -> Self {
+        Self(closure)
+    }
+}
+impl<F, T> Display for Lazy<F>
+where
+    F: Fn() -> T,
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)().fmt(f)
+    }
+}
@@ -6,83 +6,436 @@
 
 #[cfg(not(feature = "wasm"))]
 use std::path::PathBuf;
-use std::sync::OnceLock;
-
-use fern::colors::{
-    Color,
-    ColoredLevelConfig,
+use std::{
+    fmt::{
+        self,
+        Display,
+    },
+    sync::{
+        atomic::{
+            AtomicBool,
+            AtomicUsize,
+            Ordering,
+        },
+        Mutex,
+        OnceLock,
+    },
 };
+
+// The colors which can be used to customize the goolog logger.
+pub use fern::colors::Color;
+use fern::colors::ColoredLevelConfig;
 // A required export needed by this libraries macros.
 pub use log;
 use log::{
-    Level,
     LevelFilter,
+    SetLoggerError,
 };
 
+mod adaptive;
+#[cfg(feature = "anyhow")]
+mod anyhow_error;
+mod audit;
+mod backtrace;
+mod bootstrap;
+mod capture;
+mod columns;
+mod config;
+mod context;
+mod delta;
+mod dropped;
+mod duration;
+mod entry;
+mod error_chain;
+mod fatal;
+mod filter;
+mod flush;
+mod format;
+mod formatter;
+mod guard;
+mod indent;
+mod lazy;
+mod location;
+mod logged;
 pub mod macros;
+mod multiline;
+mod mute;
+mod reentrancy;
+mod ring_buffer;
+#[cfg(not(feature = "wasm"))]
+mod rotation;
+mod routing;
+mod sampling;
+mod sink;
+mod span;
+mod suppressed;
+mod target_level;
+mod targets;
+mod template;
+mod testing;
 mod tests;
+#[cfg(feature = "timestamp")]
+mod timestamp;
+mod transaction;
+mod version;
+mod visitor;
+
+pub use adaptive::{
+    adaptive_debug,
+    set_adaptive_debug,
+};
+#[cfg(feature = "anyhow")]
+pub use anyhow_error::INTERNAL__log_anyhow;
+pub use audit::{
+    set_audit_writer,
+    INTERNAL__audit,
+};
+pub use backtrace::INTERNAL__backtrace;
+pub use bootstrap::{
+    pre_init,
+    set_uninitialized_behavior,
+    uninitialized_behavior,
+    Uninit,
+    PRE_INIT_BUFFER_CAP,
+};
+pub use capture::capture;
+pub use columns::{
+    column_header,
+    column_order,
+    set_column_header,
+    set_column_order,
+    Column,
+    ColumnOrderError,
+};
+pub use config::{
+    effective_config,
+    Config,
+};
+pub use context::{
+    clear_trace_ids,
+    set_trace_ids,
+};
+pub use delta::{
+    set_show_delta,
+    show_delta,
+};
+pub use dropped::{
+    dropped_count,
+    set_on_drop,
+    OnDrop,
+};
+pub use duration::{
+    human_duration,
+    HumanDuration,
+};
+pub use error_chain::ErrorChain;
+pub use fatal::{
+    fatal_exit_code,
+    fatal_flush_timeout,
+    on_fatal_is_custom,
+    reset_on_fatal,
+    set_fatal_exit_code,
+    set_fatal_flush_timeout,
+    set_on_fatal,
+    INTERNAL__fatal_exit,
+    INTERNAL__on_fatal,
+    OnFatal,
+};
+pub use filter::{
+    level_from_verbosity,
+    would_log,
+    LevelParseError,
+};
+pub use flush::{
+    flush_policy,
+    set_flush_policy,
+    FlushPolicy,
+};
+use format::generate_log;
+pub use format::{
+    auto_target_colors,
+    color_scope,
+    colors_enabled,
+    fatal_highlight,
+    layout_preset,
+    level_icons,
+    level_width,
+    max_message_len,
+    set_auto_target_colors,
+    set_color_scope,
+    set_colors_enabled,
+    set_fatal_highlight,
+    set_layout_preset,
+    set_level_icons,
+    set_level_width,
+    set_max_message_len,
+    set_message_transform,
+    set_show_pid,
+    set_strip_incoming_ansi,
+    set_target_color,
+    set_target_length_for,
+    set_target_message_separator,
+    set_target_palette_size,
+    set_trim_message,
+    show_pid,
+    strip_incoming_ansi,
+    target_color,
+    target_length_for,
+    target_message_separator,
+    target_palette_size,
+    trim_message,
+    ColorScope,
+    LayoutPreset,
+    MessageTransform,
+    TrimMode,
+};
+pub use formatter::{
+    set_formatter,
+    DefaultFormatter,
+    FormatCtx,
+    Formatter,
+};
+pub use guard::{
+    install_exit_flush,
+    LoggerGuard,
+};
+pub use indent::{
+    indent,
+    indent_depth,
+    indent_width,
+    set_indent_width,
+    IndentGuard,
+};
+pub use lazy::Lazy;
+pub use location::INTERNAL__log_at;
+pub use logged::Logged;
+pub use multiline::{
+    multiline,
+    set_multiline,
+    MultilineMode,
+};
+pub use mute::{
+    mute_target,
+    MuteGuard,
+};
+pub use ring_buffer::{
+    clear_ring_buffer,
+    dump_ring_buffer,
+    ring_buffer_size,
+    set_ring_buffer_size,
+};
+#[cfg(not(feature = "wasm"))]
+pub use rotation::{
+    file_header,
+    max_open_files,
+    max_total_size,
+    reopen_files,
+    rotation_policy,
+    set_file_header,
+    set_file_path_resolver,
+    set_max_open_files,
+    set_max_total_size,
+    set_rotation_policy,
+    FilePathResolver,
+    RotationPolicy,
+};
+pub use routing::{
+    clear_route,
+    route_target,
+    target_route,
+    RouteMode,
+};
+pub use sampling::{
+    sampling,
+    sampling_seed,
+    set_sampling,
+    set_sampling_seed,
+};
+#[cfg(all(feature = "android", target_os = "android"))]
+pub use sink::AndroidSink;
+#[cfg(feature = "cbor")]
+pub use sink::CborSink;
+#[cfg(all(unix, feature = "mmap"))]
+pub use sink::MmapSink;
+#[cfg(unix)]
+pub use sink::UnixSocketSink;
+#[cfg(any(unix, feature = "net"))]
+pub use sink::STREAM_BUFFER_CAP;
+pub use sink::{
+    add_sink,
+    sinks,
+    Sink,
+    SinkInfo,
+    WriterSink,
+};
+#[cfg(feature = "net")]
+pub use sink::{
+    TcpSink,
+    UdpSink,
+};
+pub use span::{
+    current_span,
+    INTERNAL__span,
+    Span,
+};
+pub use suppressed::{
+    count_suppressed,
+    set_count_suppressed,
+    suppressed_counts,
+    INTERNAL__suppressed,
+};
+pub use target_level::{
+    clear_target_level,
+    set_target_level,
+    target_level,
+    target_levels,
+};
+pub use targets::{
+    set_new_target_hook,
+    NewTargetHook,
+};
+pub use template::{
+    format,
+    set_format,
+    INTERNAL__validate_template,
+    Placeholder,
+    Template,
+    TemplateError,
+};
+pub use testing::{
+    init_for_test,
+    Capture,
+    TEST_TARGET_LENGTH,
+};
+#[cfg(feature = "timestamp")]
+pub use timestamp::{
+    set_show_timestamp,
+    set_timestamp_counter,
+    set_timestamp_format,
+    show_timestamp,
+    timestamp_counter,
+    timestamp_format,
+    TimestampFormatError,
+    TimestampFormatter,
+};
+pub use transaction::transaction;
+pub use version::{
+    set_show_version,
+    set_version_placement,
+    show_version,
+    version_placement,
+    VersionPlacement,
+};
+pub use visitor::{
+    visit_record,
+    RecordVisitor,
+};
 
 /// The caller name for fatal logs send by this logger.
 const GOOLOG_CALLER: &str = "Logger";
 
+/// The length of caller names used if neither the `max_name_length` parameter nor the [`MAX_NAME_LENGTH_VAR`] environment
+/// variable is set.
+const DEFAULT_MAX_NAME_LENGTH: u32 = 16;
+/// The environment variable which can be used to set the length of caller names.
+const MAX_NAME_LENGTH_VAR: &str = "GOOLOG_TARGET_LENGTH";
+
 /// # DO NOT TOUCH THIS STATIC
 pub static INTERNAL__LOGGER_ACTIVE: OnceLock<()> = OnceLock::new();
+/// # DO NOT TOUCH THIS STATIC
+///
+/// The number of checkpoints logged using the [`checkpoint!`] macro so far.
+#[doc(hidden)]
+pub static INTERNAL__CHECKPOINTS: AtomicUsize = AtomicUsize::new(0);
+/// Whether initiating the goolog logger fails if an environment variable read by it has an invalid value.
+static STRICT_ENV: AtomicBool = AtomicBool::new(false);
 
-/// Generate the log line
-#[cfg(feature = "timestamp")]
-macro_rules! generate_log {
-    (
-        $max_name_length: ident,
-        $record: ident,
-        $colors: ident,
-        $message: ident
-    ) => {{
-        let mut message = $message.to_string();
-        let mut log_level = $colors.color($record.level()).to_string();
-        #[cfg(not(feature = "std_fatal"))]
-        if let Level::Error = $record.level() {
-            if let Some(val) = message.strip_prefix("$goolog:fatal=") {
-                log_level = log_level.replace("ERROR", "FATAL");
-                message = val.into();
-            }
-        }
+/// The configuration the goolog logger has been initiated with. \
+/// This also serializes initiating the logger, so racing threads cannot both set it up.
+static INIT_CONFIG: Mutex<Option<InitConfig>> = Mutex::new(None);
 
-        format!(
-            "{} | {} | {:14.14} | {}",
-            chrono::Local::now()
-                .format("\x1b[2m\x1b[1m%d.%m.%Y\x1b[0m | \x1b[2m\x1b[1m%H:%M:%S\x1b[0m"),
-            to_fixed_size($max_name_length, $record.target()),
-            log_level,
-            message
-        )
-    }};
-}
-/// Generate the log line
-#[cfg(not(feature = "timestamp"))]
-macro_rules! generate_log {
-    (
-        $max_name_length: ident,
-        $record: ident,
-        $colors: ident,
-        $message: ident
-    ) => {{
-        let mut message = $message.to_string();
-        let mut log_level = $colors.color($record.level()).to_string();
-        #[cfg(not(feature = "std_fatal"))]
-        if let Level::Error = $record.level() {
-            if let Some(val) = message.strip_prefix("$goolog:fatal=") {
-                log_level = log_level.replace("ERROR", "FATAL");
-                message = val.into();
-            }
+/// The configuration given while initiating the goolog logger.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct InitConfig {
+    /// The log level of the console.
+    log_level: LevelFilter,
+    /// The length of caller names.
+    max_name_length: u32,
+    /// The path of the log file.
+    #[cfg(not(feature = "wasm"))]
+    log_file: Option<PathBuf>,
+    /// Whether every log gets printed to stderr instead of stdout.
+    to_stderr: bool,
+}
+
+/// An error returned by [`try_init_logger`] if the goolog logger could not be initiated.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum InitError {
+    /// The goolog logger has already been initiated using a different configuration.
+    ConfigMismatch,
+    /// Another logger has already been set as the global logger.
+    LoggerAlreadySet(SetLoggerError),
+    /// An environment variable has an invalid value while [`set_strict_env`] is enabled.
+    InvalidEnvVar {
+        /// The name of the environment variable.
+        name: &'static str,
+        /// The invalid value.
+        value: String,
+    },
+}
+impl Display for InitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConfigMismatch => write!(
+                f,
+                "the goolog logger has already been initiated using a different configuration"
+            ),
+            Self::LoggerAlreadySet(error) => write!(f, "{error}"),
+            Self::InvalidEnvVar { name, value } => write!(
+                f,
+                "invalid value `{value}` of the `{name}` environment variable"
+            ),
         }
+    }
+}
+impl std::error::Error for InitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ConfigMismatch | Self::InvalidEnvVar { .. } => None,
+            Self::LoggerAlreadySet(error) => Some(error),
+        }
+    }
+}
 
-        format!(
-            "{} | {:14.14} | {}",
-            to_fixed_size($max_name_length, $record.target()),
-            log_level,
-            message
-        )
-    }};
+/// Set whether initiating the goolog logger fails if an environment variable read by it has an invalid value. \
+/// \
+/// By default, invalid values are ignored with a warning, just like most crates handle their environment variables. In strict
+/// mode, [`try_init_logger`] returns an [`InitError::InvalidEnvVar`] instead and the other functions initiating the logger send
+/// a fatal log, which makes a typo in the configuration of a deployment fail right at startup. This has to be set before the
+/// logger gets initiated. The only environment variable read by the goolog logger is `GOOLOG_TARGET_LENGTH`, which is only
+/// read if no `max_name_length` has been given.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// set_strict_env(true);
+///
+/// if let Err(error) = try_init_logger(None, None, None) {
+///     eprintln!("Invalid logging configuration: {error}");
+///     std::process::exit(2);
+/// }
+/// # }
+/// ```
+pub fn set_strict_env(strict_env: bool) {
+    STRICT_ENV.store(strict_env, Ordering::Relaxed);
+}
+
+/// Get whether initiating the goolog logger fails if an environment variable read by it has an invalid value.
+pub fn strict_env() -> bool {
+    STRICT_ENV.load(Ordering::Relaxed)
 }
 
 /// Initiate the custom [`Logger`](fern::Dispatch). \
@@ -94,32 +447,247 @@ macro_rules! generate_log {
 /// This function will panic if:
 /// - A global logger has already been set to a previous logger.
 /// - The given log file could not be opened.
+/// - An environment variable has an invalid value while [`set_strict_env`] is enabled.
+///
+/// This includes the goolog logger itself, even if it has been initiated by another thread racing this call. Use
+/// [`try_init_logger`] if multiple threads may initiate the logger.
 pub fn init_logger(
     log_level: Option<LevelFilter>,
     max_name_length: Option<u32>,
     #[cfg(not(feature = "wasm"))] log_file: Option<PathBuf>,
 ) {
-    /// Edit the name to be `max_name_length` long.
-    fn to_fixed_size(max_name_length: u32, name: &str) -> String {
-        if max_name_length == 0 {
-            return name.to_string();
-        }
+    init_or_exit(
+        log_level,
+        max_name_length,
+        #[cfg(not(feature = "wasm"))]
+        log_file,
+        false,
+    );
+}
 
-        let mut new_name = vec![];
-        let mut name_iter = name.chars();
-        for _ in 0..max_name_length {
-            if let Some(name_char) = name_iter.next() {
-                new_name.push(name_char.to_string());
-            } else {
-                new_name.push(' '.to_string());
-            }
-        }
+/// Initiate the custom [`Logger`](fern::Dispatch) unless it has already been initiated using the same configuration. \
+/// \
+/// Unlike [`init_logger`], this function can safely be called by many threads at once, like by the plugins of a program which
+/// cannot control the order they get loaded in. Initiating the logger is serialized, so exactly one call sets it up. Every
+/// other call, no matter whether it raced the first one or came long after it, succeeds as long as it passes the same
+/// configuration. A log level or caller name length of [`None`] is treated just like the default it stands for.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// - The goolog logger has already been initiated using a different configuration.
+/// - Another logger has already been set as the global logger.
+/// - An environment variable has an invalid value while [`set_strict_env`] is enabled.
+///
+/// # Panics
+///
+/// This function will panic if the given log file could not be opened.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// let plugins: Vec<_> = (0..4)
+///     .map(|_| std::thread::spawn(|| try_init_logger(None, None, None)))
+///     .collect();
+/// for plugin in plugins {
+///     plugin.join().unwrap().unwrap_or_else(|error| panic!("{error}"));
+/// }
+///
+/// info!("Main"; "Initiated the goolog logger exactly once.");
+/// # }
+/// ```
+pub fn try_init_logger(
+    log_level: Option<LevelFilter>,
+    max_name_length: Option<u32>,
+    #[cfg(not(feature = "wasm"))] log_file: Option<PathBuf>,
+) -> Result<(), InitError> {
+    init(
+        log_level,
+        max_name_length,
+        #[cfg(not(feature = "wasm"))]
+        log_file,
+        false,
+    )
+    .map(|_| ())
+}
 
-        new_name.concat()
+/// Initiate the custom [`Logger`](fern::Dispatch) using a log level given as a string, like the value of a `--log-level` flag.
+/// \
+/// The `log_level` may be any of `off`, `error`, `warn`, `info`, `debug` or `trace`, ignoring its case, or a number from 0 to 4
+/// mapped to a level as described by [`level_from_verbosity`]. Apart from that, this works just like [`init_logger`].
+///
+/// # Errors
+///
+/// This function will return an error if the given log level is unknown. The logger will not be initiated in that case.
+///
+/// # Panics
+///
+/// This function will panic if:
+/// - A global logger has already been set to a previous logger.
+/// - The given log file could not be opened.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// let log_level = "DEBUG";
+/// init_logger_str(log_level, None, None).unwrap_or_else(|error| panic!("{error}"));
+///
+/// debug!("Main"; "Initialized the goolog logger.");
+/// # }
+/// ```
+pub fn init_logger_str(
+    log_level: &str,
+    max_name_length: Option<u32>,
+    #[cfg(not(feature = "wasm"))] log_file: Option<PathBuf>,
+) -> Result<(), LevelParseError> {
+    init_logger(
+        Some(filter::parse_level(log_level)?),
+        max_name_length,
+        #[cfg(not(feature = "wasm"))]
+        log_file,
+    );
+
+    Ok(())
+}
+
+/// Initiate the custom [`Logger`](fern::Dispatch) using a log level raised by the given `verbosity`, like the number of times a
+/// `-v` flag has been passed. \
+/// \
+/// Every step of verbosity raises the log level by one level above the `base` level, which defaults to `info` just like for
+/// [`init_logger`]. Starting at `info`, `-v` therefore enables debug logs and `-vv` trace logs. The level is clamped at trace, so
+/// any further `-v` flag does not change anything. Pass a `base` of [`LevelFilter::Warn`] to keep the output of a quiet CLI tool
+/// free of info logs unless `-v` is given. Apart from that, this works just like [`init_logger`].
+///
+/// # Panics
+///
+/// This function will panic if:
+/// - A global logger has already been set to a previous logger.
+/// - The given log file could not be opened.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// use goolog::log::LevelFilter;
+/// # fn main() {
+/// // the number of `-v` flags, as counted by an argument parser like clap
+/// let verbose = 1;
+/// init_logger_from_verbosity(verbose, Some(LevelFilter::Warn), None, None);
+///
+/// info!("Main"; "This will be printed, since `-v` has been passed once.");
+/// # }
+/// ```
+pub fn init_logger_from_verbosity(
+    verbosity: u8,
+    base: Option<LevelFilter>,
+    max_name_length: Option<u32>,
+    #[cfg(not(feature = "wasm"))] log_file: Option<PathBuf>,
+) {
+    init_logger(
+        Some(filter::raise_level(
+            base.unwrap_or(LevelFilter::Info),
+            verbosity,
+        )),
+        max_name_length,
+        #[cfg(not(feature = "wasm"))]
+        log_file,
+    );
+}
+
+/// Initiate the custom [`Logger`](fern::Dispatch) printing every log to stderr instead of stdout. \
+/// \
+/// This works just like [`init_logger`], but keeps stdout free for the actual output of a program, like the JSON results of a
+/// CLI tool whose output gets piped into another program.
+///
+/// # Panics
+///
+/// This function will panic if:
+/// - A global logger has already been set to a previous logger.
+/// - The given log file could not be opened.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger_stderr(None, None, None);
+///
+/// // this will be printed to stderr
+/// info!("Main"; "Hello World!");
+/// println!("{{\"result\": 42}}");
+/// # }
+/// ```
+#[cfg(not(feature = "wasm"))]
+pub fn init_logger_stderr(
+    log_level: Option<LevelFilter>,
+    max_name_length: Option<u32>,
+    log_file: Option<PathBuf>,
+) {
+    init_or_exit(log_level, max_name_length, log_file, true);
+}
+
+/// Initiate the custom [`Logger`](fern::Dispatch) just like [`init`], sending a fatal log if the goolog logger has already been
+/// initiated or another logger has already been set.
+fn init_or_exit(
+    log_level: Option<LevelFilter>,
+    max_name_length: Option<u32>,
+    #[cfg(not(feature = "wasm"))] log_file: Option<PathBuf>,
+    to_stderr: bool,
+) {
+    match init(
+        log_level,
+        max_name_length,
+        #[cfg(not(feature = "wasm"))]
+        log_file,
+        to_stderr,
+    ) {
+        Ok(true) => {}
+        Ok(false) => {
+            fatal!("Failed to initiate the goolog logger. Error: the goolog logger has already been initiated")
+        }
+        Err(error) => fatal!("Failed to initiate the goolog logger. Error: {error}"),
     }
+}
 
-    let max_name_length = max_name_length.unwrap_or(16);
+/// Initiate the custom [`Logger`](fern::Dispatch), printing every log to stderr if `to_stderr` is true and to stdout otherwise.
+/// \
+/// This returns false if the goolog logger has already been initiated using the same configuration.
+fn init(
+    log_level: Option<LevelFilter>,
+    max_name_length: Option<u32>,
+    #[cfg(not(feature = "wasm"))] log_file: Option<PathBuf>,
+    to_stderr: bool,
+) -> Result<bool, InitError> {
+    let (max_name_length, invalid_max_name_length) =
+        resolve_max_name_length(max_name_length, std::env::var(MAX_NAME_LENGTH_VAR).ok());
+    if let (Some(value), true) = (&invalid_max_name_length, strict_env()) {
+        return Err(InitError::InvalidEnvVar {
+            name: MAX_NAME_LENGTH_VAR,
+            value: value.clone(),
+        });
+    }
     let log_level = log_level.unwrap_or(LevelFilter::Info);
+    let config = InitConfig {
+        log_level,
+        max_name_length,
+        #[cfg(not(feature = "wasm"))]
+        log_file: log_file.clone(),
+        to_stderr,
+    };
+
+    let mut init_config = INIT_CONFIG
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match init_config.as_ref() {
+        Some(init_config) if *init_config == config => return Ok(false),
+        Some(_) => return Err(InitError::ConfigMismatch),
+        None => {}
+    }
+    format::MAX_NAME_LENGTH.store(max_name_length, Ordering::Relaxed);
 
     #[cfg(not(feature = "wasm"))]
     if let Some(mut logs_dir) = log_file.clone() {
@@ -133,52 +701,197 @@ pub fn init_logger(
         });
     }
 
-    let colors = ColoredLevelConfig::new()
-        .debug(Color::Blue)
-        .error(Color::Red)
-        .info(Color::Green)
-        .trace(Color::White)
-        .warn(Color::Yellow);
+    let colors = level_colors();
+
+    // the destinations only get registered once the logger has been set
+    let mut builtin_sinks = vec![];
+    #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+    builtin_sinks.push(("browser console".to_string(), log_level));
+    #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+    builtin_sinks.push((
+        if to_stderr { "stderr" } else { "stdout" }.to_string(),
+        log_level,
+    ));
+    #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+    let console =
+        fern::Output::call(|record| sink::console::log(record.level(), record.args().to_string()));
+    #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+    let console = transaction::console(to_stderr);
 
+    // debug logs of targets set using `set_adaptive_debug` and the logs of targets set using `set_target_level` may be printed
+    // above the log level
+    let print_level = adaptive::print_level(log_level).max(target_level::print_level(log_level));
     #[allow(unused_mut)] // when we use the wasm feature this does not need to be mut
-    let mut logger = fern::Dispatch::new().chain(
-        fern::Dispatch::new()
-            .format(move |_out, message, record| {
-                let log = generate_log!(max_name_length, record, colors, message);
-
-                #[cfg(feature = "wasm")]
-                web_sys::console::log_1(&log.into());
-
-                #[cfg(not(feature = "wasm"))]
-                _out.finish(format_args!("{log}"));
-            })
-            .level(log_level)
-            .chain(std::io::stdout()),
-    );
+    let mut logger = builtin_dispatch(log_level, max_name_length, colors, console);
 
     #[cfg(not(feature = "wasm"))]
     if let Some(log_file) = log_file {
+        builtin_sinks.push((
+            format!("log file `{}`", log_file.display()),
+            LevelFilter::Info,
+        ));
         logger = logger.chain(
             fern::Dispatch::new()
+                .filter(|metadata| routing::to_builtin(metadata.target()))
                 .format(move |out, message, record| {
-                    let log = generate_log!(max_name_length, record, colors, message);
+                    let log = generate_log(max_name_length, record, colors, message);
 
                     out.finish(format_args!("{log}"))
                 })
                 .level(log::LevelFilter::Info)
-                .chain(fern::log_file(&log_file).unwrap_or_else(|error| {
+                .chain(rotation::output(&log_file).unwrap_or_else(|error| {
                     fatal!("Failed to open the log file `{log_file:#?}`. Error: {error}")
                 })),
         );
     }
 
-    logger
-        .apply()
-        .unwrap_or_else(|error| fatal!("Failed to initiate the goolog logger. Error: {error}"));
+    try_apply(logger, print_level).map_err(InitError::LoggerAlreadySet)?;
+    adaptive::init(log_level);
+    target_level::init(log_level);
+    for (name, level) in builtin_sinks {
+        sink::add_builtin_sink(name, level);
+    }
+    *init_config = Some(config);
+    drop(init_config);
+
+    #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+    if let Some(header) = format::header_line() {
+        match to_stderr {
+            true => eprintln!("{header}"),
+            false => println!("{header}"),
+        }
+    }
+
+    if let Some(value) = invalid_max_name_length {
+        warn!(
+            "Ignoring the invalid value `{value}` of the `{MAX_NAME_LENGTH_VAR}` environment variable."
+        );
+    }
+
+    Ok(true)
+}
+
+/// Initiate the custom [`Logger`](fern::Dispatch) and return a [`LoggerGuard`] flushing it once dropped. \
+/// \
+/// This works just like [`init_logger`], but makes sure logs still buffered when `main` returns normally do not get lost. Keep
+/// the guard alive until the end of `main`.
+///
+/// # Panics
+///
+/// This function will panic if:
+/// - A global logger has already been set to a previous logger.
+/// - The given log file could not be opened.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+///
+/// fn main() {
+///     let _guard = init_logger_with_guard(None, None, None);
+///
+///     info!("Main"; "This will be flushed once main returns.");
+/// }
+/// ```
+pub fn init_logger_with_guard(
+    log_level: Option<LevelFilter>,
+    max_name_length: Option<u32>,
+    #[cfg(not(feature = "wasm"))] log_file: Option<PathBuf>,
+) -> LoggerGuard {
+    init_logger(
+        log_level,
+        max_name_length,
+        #[cfg(not(feature = "wasm"))]
+        log_file,
+    );
+
+    LoggerGuard::new()
+}
+
+/// Get the length of caller names and, if it could not be parsed, the value of the [`MAX_NAME_LENGTH_VAR`] environment variable.
+/// \
+/// An `explicit` length takes precedence over the value of the environment variable, which takes precedence over the default.
+fn resolve_max_name_length(explicit: Option<u32>, env: Option<String>) -> (u32, Option<String>) {
+    match (explicit, env) {
+        (Some(max_name_length), _) => (max_name_length, None),
+        (None, Some(value)) => match value.trim().parse() {
+            Ok(max_name_length) => (max_name_length, None),
+            Err(_) => (DEFAULT_MAX_NAME_LENGTH, Some(value)),
+        },
+        (None, None) => (DEFAULT_MAX_NAME_LENGTH, None),
+    }
+}
+
+/// Get the colors used for every level.
+fn level_colors() -> ColoredLevelConfig {
+    ColoredLevelConfig::new()
+        .debug(Color::Blue)
+        .error(Color::Red)
+        .info(Color::Green)
+        .trace(Color::White)
+        .warn(Color::Yellow)
+}
+
+/// Create the [`Dispatch`](fern::Dispatch) of the goolog logger with the given `log_level`, rendering every log passing its
+/// filters with the given `max_name_length` and `colors` and sending it to the given `console` and every registered sink. \
+/// The log file is not part of it, since it has its own level.
+fn builtin_dispatch(
+    log_level: LevelFilter,
+    max_name_length: u32,
+    colors: ColoredLevelConfig,
+    console: fern::Output,
+) -> fern::Dispatch {
+    fern::Dispatch::new()
+        .filter(move |metadata| {
+            filter::filter(metadata)
+                && adaptive::allows(
+                    metadata,
+                    target_level::level_for(metadata.target(), log_level),
+                )
+        })
+        .chain(
+            fern::Dispatch::new()
+                .filter(|metadata| routing::to_builtin(metadata.target()))
+                .format(move |out, message, record| {
+                    let log = generate_log(max_name_length, record, colors, message);
+                    #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+                    let log = format::highlight_fatal(record, message, log);
+                    capture::record(&log);
+                    ring_buffer::record(&log);
+
+                    out.finish(format_args!("{log}"));
+                })
+                .chain(console),
+        )
+        .chain(sinks_dispatch())
+}
+
+/// Create the [`Dispatch`](fern::Dispatch) sending every log passing the filters of the goolog logger to the registered sinks.
+fn sinks_dispatch() -> fern::Dispatch {
+    fern::Dispatch::new().chain(fern::Output::call(|record| {
+        targets::track(record.target());
+        sink::log(record);
+    }))
+}
+
+/// Set the given `logger` as the global logger with the given `max_level`, returning an error if another logger has already been
+/// set. \
+/// The levels of the destinations are not used for the maximum level, since the filters of the goolog logger may change at any
+/// time and decide on their own which logs get printed.
+fn try_apply(logger: fern::Dispatch, max_level: LevelFilter) -> Result<(), SetLoggerError> {
+    format::PID.get_or_init(|| std::process::id().to_string());
+
+    let (_, logger) = logger.into_log();
+    bootstrap::set_logger(
+        Box::new(reentrancy::ReentrancyGuard::new(logger)),
+        max_level,
+    )?;
 
     if INTERNAL__LOGGER_ACTIVE.set(()).is_err() {
         fatal!(
             "The `INTERNAL__LOGGER_ACTIVE` static should only be used by the goolog logger or its macros."
         )
     }
+
+    Ok(())
 }
@@ -0,0 +1,33 @@
+//! This module provides the function used by the [`log_at!`](crate::log_at) macro to send a log with an explicit source location.
+
+use std::{
+    fmt::Arguments,
+    panic::Location,
+};
+
+use log::{
+    Level,
+    Record,
+};
+
+/// # DO NOT TOUCH THIS FUNCTION
+///
+/// Send a log attributed to the given `location`. This is used by the [`log_at!`](crate::log_at) macro.
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub fn INTERNAL__log_at(location: &Location, level: Level, target: &str, arguments: Arguments) {
+    if level > log::max_level() {
+        crate::INTERNAL__suppressed(level);
+        return;
+    }
+
+    log::logger().log(
+        &Record::builder()
+            .level(level)
+            .target(target)
+            .args(arguments)
+            .file(Some(location.file()))
+            .line(Some(location.line()))
+            .build(),
+    );
+}
@@ -0,0 +1,33 @@
+//! This module provides the [`Logged`] trait used to bind a caller name to a type.
+
+/// A type sending its logs under its own caller name. \
+/// \
+/// This is an alternative to the `GOOLOG_CALLER` constant for modules containing many types which should each log under their
+/// own name. The name is read by the [`log_for!`](crate::log_for) macro. Both can be used side by side: the other macros keep
+/// falling back to the `GOOLOG_CALLER` constant of the current module, while the [`log_for!`](crate::log_for) macro always uses
+/// the name of the given type.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+///
+/// struct Database;
+/// impl Logged for Database {
+///     const TARGET: &'static str = "Database";
+/// }
+/// impl Database {
+///     fn connect(&self) {
+///         log_for!(Self; info; "Connected to the database");
+///     }
+/// }
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// Database.connect();
+/// # }
+/// ```
+pub trait Logged {
+    /// The name under which the logs of this type should be sent.
+    const TARGET: &'static str;
+}
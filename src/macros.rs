@@ -8,6 +8,34 @@
 //! - [`fatal!`](crate::fatal)
 //! - [`trace!`](crate::trace)
 //! - [`debug!`](crate::debug)
+//! - [`info_if!`](crate::info_if)
+//! - [`warn_if!`](crate::warn_if)
+//! - [`error_if!`](crate::error_if)
+//! - [`trace_if!`](crate::trace_if)
+//! - [`debug_if!`](crate::debug_if)
+//! - [`leveled!`](crate::leveled)
+//! - [`log_for!`](crate::log_for)
+//! - [`log_at!`](crate::log_at)
+//! - [`dbg_tap!`](crate::dbg_tap)
+//! - [`debug_var!`](crate::debug_var)
+//! - [`error_chain!`](crate::error_chain)
+//! - [`warn_chain!`](crate::warn_chain)
+//! - [`log_anyhow!`](crate::log_anyhow) (requires the `anyhow` feature)
+//! - [`log_backtrace!`](crate::log_backtrace)
+//! - [`deprecated!`](crate::deprecated)
+//! - [`checkpoint!`](crate::checkpoint)
+//! - [`lazy!`](crate::lazy)
+//! - [`span!`](crate::span)
+//! - [`audit!`](crate::audit)
+//! - [`assert_logged!`](crate::assert_logged)
+//! - [`goolog_format!`](crate::goolog_format)
+//!
+//! # Performance
+//!
+//! The log macros check the level of a log against the cached maximum level of the active logger before the arguments of its
+//! message get evaluated. A log at a disabled level therefore only costs a single atomic load, plus a second one checking whether
+//! it should be counted for [`suppressed_counts`](crate::suppressed_counts). See the `disabled` benchmark for a comparison with
+//! letting the logger check the level itself.
 
 /// This macro logs a message at the info level. \
 /// Infos indicate important information that should be logged under normal conditions such as services starting.
@@ -29,7 +57,11 @@
 /// info!("Main"; "Started in {:?} secs", secs);
 ///
 /// // This is what this macro will expand to:
-/// goolog::log::info!(target: &"Main", "Started in {:?} secs", secs);
+/// if goolog::log::Level::Info <= goolog::log::max_level() {
+///     goolog::log::info!(target: &"Main", "Started in {:?} secs", secs);
+/// } else {
+///     goolog::INTERNAL__suppressed(goolog::log::Level::Info);
+/// }
 /// # }
 /// ```
 ///
@@ -49,7 +81,11 @@
 /// // This is what this macro will expand to:
 /// goolog::info!(GOOLOG_CALLER; "Started in {:?} secs", secs);;
 /// // this will then further expand to:
-/// goolog::log::info!(target: &GOOLOG_CALLER, "Started in {:?} secs", secs);
+/// if goolog::log::Level::Info <= goolog::log::max_level() {
+///     goolog::log::info!(target: &GOOLOG_CALLER, "Started in {:?} secs", secs);
+/// } else {
+///     goolog::INTERNAL__suppressed(goolog::log::Level::Info);
+/// }
 ///
 /// // but you can still specify a caller name which will result in the standard behavior
 /// info!("OtherCaller"; "Started in {:?} secs", secs);
@@ -58,7 +94,11 @@
 #[macro_export]
 macro_rules! info {
     ($caller: expr; $( $argument: tt ) *) => {
-        $crate::log::info!(target: &$caller, $( $argument ) *);
+        if $crate::log::Level::Info <= $crate::log::max_level() {
+            $crate::log::info!(target: &$caller, $( $argument ) *);
+        } else {
+            $crate::INTERNAL__suppressed($crate::log::Level::Info);
+        }
     };
     ($( $argument: tt ) *) => {
         info!(GOOLOG_CALLER; $( $argument ) *)
@@ -83,7 +123,11 @@ macro_rules! info {
 /// warn!("Main"; "Accept the EULA to use this MCServer. Error: {}", erro);
 ///
 /// // This is what this macro will expand to:
-/// goolog::log::warn!(target: &"Main", "Accept the EULA to use this MCServer. Error: {}", erro);
+/// if goolog::log::Level::Warn <= goolog::log::max_level() {
+///     goolog::log::warn!(target: &"Main", "Accept the EULA to use this MCServer. Error: {}", erro);
+/// } else {
+///     goolog::INTERNAL__suppressed(goolog::log::Level::Warn);
+/// }
 /// # }
 /// ```
 ///
@@ -102,7 +146,11 @@ macro_rules! info {
 /// // This is what this macro will expand to:
 /// goolog::warn!(GOOLOG_CALLER; "Accept the EULA to use this MCServer. Error: {}", erro);
 /// // this will then further expand to:
-/// goolog::log::warn!(target: &GOOLOG_CALLER, "Accept the EULA to use this MCServer. Error: {}", erro);
+/// if goolog::log::Level::Warn <= goolog::log::max_level() {
+///     goolog::log::warn!(target: &GOOLOG_CALLER, "Accept the EULA to use this MCServer. Error: {}", erro);
+/// } else {
+///     goolog::INTERNAL__suppressed(goolog::log::Level::Warn);
+/// }
 ///
 /// // but you can still specify a caller name which will result in the standard behavior
 /// warn!("OtherCaller"; "Accept the EULA to use this MCServer. Error: {}", erro);
@@ -111,7 +159,11 @@ macro_rules! info {
 #[macro_export]
 macro_rules! warn {
     ($caller: expr; $( $argument: tt ) *) => {
-        $crate::log::warn!(target: &$caller, $( $argument ) *);
+        if $crate::log::Level::Warn <= $crate::log::max_level() {
+            $crate::log::warn!(target: &$caller, $( $argument ) *);
+        } else {
+            $crate::INTERNAL__suppressed($crate::log::Level::Warn);
+        }
     };
     ($( $argument: tt ) *) => {
         warn!(GOOLOG_CALLER; $( $argument ) *)
@@ -141,7 +193,11 @@ macro_rules! warn {
 /// error!("Main"; "An error occurred while waiting on the Minecraft server to finish. Error: {}", erro);
 ///
 /// // This is what this macro will expand to:
-/// goolog::log::error!(target: &"Main", "An error occurred while waiting on the Minecraft server to finish. Error: {}", erro);
+/// if goolog::log::Level::Error <= goolog::log::max_level() {
+///     goolog::log::error!(target: &"Main", "An error occurred while waiting on the Minecraft server to finish. Error: {}", erro);
+/// } else {
+///     goolog::INTERNAL__suppressed(goolog::log::Level::Error);
+/// }
 /// # }
 /// ```
 ///
@@ -160,7 +216,11 @@ macro_rules! warn {
 /// // This is what this macro will expand to:
 /// goolog::error!(GOOLOG_CALLER; "An error occurred while waiting on the Minecraft server to finish. Error: {}", erro);
 /// // this will then further expand to:
-/// goolog::log::error!(target: &GOOLOG_CALLER, "An error occurred while waiting on the Minecraft server to finish. Error: {}", erro);
+/// if goolog::log::Level::Error <= goolog::log::max_level() {
+///     goolog::log::error!(target: &GOOLOG_CALLER, "An error occurred while waiting on the Minecraft server to finish. Error: {}", erro);
+/// } else {
+///     goolog::INTERNAL__suppressed(goolog::log::Level::Error);
+/// }
 ///
 /// // but you can still specify a caller name which will result in the standard behavior
 /// error!("OtherCaller"; "An error occurred while waiting on the Minecraft server to finish. Error: {}", erro);
@@ -169,14 +229,29 @@ macro_rules! warn {
 #[macro_export]
 macro_rules! error {
     ($caller: expr; $( $argument: tt ) *) => {
-        $crate::log::error!(target: &$caller, $( $argument ) *);
+        if $crate::log::Level::Error <= $crate::log::max_level() {
+            $crate::log::error!(target: &$caller, $( $argument ) *);
+        } else {
+            $crate::INTERNAL__suppressed($crate::log::Level::Error);
+        }
     };
     ($( $argument: tt ) *) => {
         error!(GOOLOG_CALLER; $( $argument ) *)
     }
 }
-/// This macro logs a message at the error level and exits the application with the error code 1. \
-/// Fatal errors indicate a problem that is not recoverable.
+/// This macro logs a message at the error level and exits the application with the error code 1 by default. \
+/// Fatal errors indicate a problem that is not recoverable. \
+/// \
+/// Exiting the application requires the `fatal-exit` feature, which is enabled by default. Without it, this macro panics instead,
+/// leaving it up to the application how to handle the failure. Library crates should therefore depend on goolog with
+/// `default-features = false`. \
+/// \
+/// Before exiting, the handler registered using [`set_on_fatal`](crate::set_on_fatal) will be called and the logger and every
+/// [`Sink`](crate::Sink) will be flushed, waiting at most the time set using
+/// [`set_fatal_flush_timeout`](crate::set_fatal_flush_timeout). \
+/// \
+/// The error code can be changed for every fatal log using [`set_fatal_exit_code`](crate::set_fatal_exit_code), or for a
+/// single one by putting `code = ` followed by the code in front of the other parameters: `fatal!(code = 2; "Main"; "...")`.
 ///
 /// # Parameters
 ///
@@ -206,7 +281,8 @@ macro_rules! error {
 ///         "An error occurred while waiting on the Minecraft server to finish. Error: {}", erro
 ///     );
 /// }
-/// std::process::exit(1);
+/// goolog::INTERNAL__on_fatal();
+/// goolog::INTERNAL__fatal_exit(goolog::fatal_exit_code());
 /// # }
 /// ```
 ///
@@ -237,7 +313,8 @@ macro_rules! error {
 ///         "An error occurred while waiting on the Minecraft server to finish. Error: {}", erro
 ///     );
 /// }
-/// std::process::exit(1);
+/// goolog::INTERNAL__on_fatal();
+/// goolog::INTERNAL__fatal_exit(goolog::fatal_exit_code());
 ///
 /// // but you can still specify a caller name which will result in the standard behavior
 /// fatal!("OtherCaller"; "An error occurred while waiting on the Minecraft server to finish. Error: {}", erro);
@@ -245,7 +322,7 @@ macro_rules! error {
 /// ```
 #[macro_export]
 macro_rules! fatal {
-    ($caller: expr; $( $argument: tt ) *) => {
+    (code = $code: expr; $caller: expr; $( $argument: tt ) *) => {
         {
             // we assume the user followed our warning and that the goolog logger is active
             if $crate::INTERNAL__LOGGER_ACTIVE.get().is_some() {
@@ -260,9 +337,16 @@ macro_rules! fatal {
                     $( $argument ) *
                 );
             }
-            std::process::exit(1);
+            $crate::INTERNAL__on_fatal();
+            $crate::INTERNAL__fatal_exit($code)
         }
     };
+    (code = $code: expr; $( $argument: tt ) *) => {
+        $crate::fatal!(code = $code; GOOLOG_CALLER; $( $argument ) *)
+    };
+    ($caller: expr; $( $argument: tt ) *) => {
+        $crate::fatal!(code = $crate::fatal_exit_code(); $caller; $( $argument ) *)
+    };
     ($( $argument: tt ) *) => {
         fatal!(GOOLOG_CALLER; $( $argument ) *)
     }
@@ -285,7 +369,11 @@ macro_rules! fatal {
 /// trace!("Main"; "Initiated goolog logger without a log file set.");
 ///
 /// // This is what this macro will expand to:
-/// goolog::log::trace!(target: &"Main", "Initiated goolog logger without a log file set.");
+/// if goolog::log::Level::Trace <= goolog::log::max_level() {
+///     goolog::log::trace!(target: &"Main", "Initiated goolog logger without a log file set.");
+/// } else {
+///     goolog::INTERNAL__suppressed(goolog::log::Level::Trace);
+/// }
 /// # }
 /// ```
 ///
@@ -303,7 +391,11 @@ macro_rules! fatal {
 /// // This is what the code above will expand to:
 /// goolog::trace!(GOOLOG_CALLER; "Initiated goolog logger without a log file set.");
 /// // this will then further expand to:
-/// goolog::log::trace!(target: &GOOLOG_CALLER, "Initiated goolog logger without a log file set.");
+/// if goolog::log::Level::Trace <= goolog::log::max_level() {
+///     goolog::log::trace!(target: &GOOLOG_CALLER, "Initiated goolog logger without a log file set.");
+/// } else {
+///     goolog::INTERNAL__suppressed(goolog::log::Level::Trace);
+/// }
 ///
 /// // but you can still specify a caller name which will result in the standard behavior
 /// trace!("OtherCaller"; "A different trace message.");
@@ -312,7 +404,11 @@ macro_rules! fatal {
 #[macro_export]
 macro_rules! trace {
     ($caller: expr; $( $argument: tt ) *) => {
-        $crate::log::trace!(target: &$caller, $( $argument ) *);
+        if $crate::log::Level::Trace <= $crate::log::max_level() {
+            $crate::log::trace!(target: &$caller, $( $argument ) *);
+        } else {
+            $crate::INTERNAL__suppressed($crate::log::Level::Trace);
+        }
     };
     ($( $argument: tt ) *) => {
         trace!(GOOLOG_CALLER; $( $argument ) *)
@@ -339,7 +435,11 @@ macro_rules! trace {
 ///
 /// // This is what this macro will expand to:
 /// #[cfg(debug_assertions)]
-/// goolog::log::debug!(target: &"Main", "Initiated logger.");
+/// if goolog::log::Level::Debug <= goolog::log::max_level() {
+///     goolog::log::debug!(target: &"Main", "Initiated logger.");
+/// } else {
+///     goolog::INTERNAL__suppressed(goolog::log::Level::Debug);
+/// }
 /// # }
 /// ```
 ///
@@ -357,7 +457,11 @@ macro_rules! trace {
 /// // This is what this macro will expand to:
 /// goolog::debug!(GOOLOG_CALLER; "Initiated logger.");
 /// // this will then further expand to:
-/// goolog::log::debug!(target: &GOOLOG_CALLER, "Initiated logger.");
+/// if goolog::log::Level::Debug <= goolog::log::max_level() {
+///     goolog::log::debug!(target: &GOOLOG_CALLER, "Initiated logger.");
+/// } else {
+///     goolog::INTERNAL__suppressed(goolog::log::Level::Debug);
+/// }
 ///
 /// // but you can still specify a caller name which will result in the standard behavior
 /// debug!("OtherCaller"; "Initiated logger.");
@@ -367,9 +471,909 @@ macro_rules! trace {
 macro_rules! debug {
     ($caller: expr; $( $argument: tt ) *) => {
         #[cfg(debug_assertions)]
-        $crate::log::debug!(target: &$caller, $( $argument ) *);
+        if $crate::log::Level::Debug <= $crate::log::max_level() {
+            $crate::log::debug!(target: &$caller, $( $argument ) *);
+        } else {
+            $crate::INTERNAL__suppressed($crate::log::Level::Debug);
+        }
     };
     ($( $argument: tt ) *) => {
         debug!(GOOLOG_CALLER; $( $argument ) *)
     }
 }
+/// This macro logs a message at the info level, but only if the given condition is true. \
+/// The arguments of the message will not be evaluated if the condition is false.
+///
+/// # Parameters
+///
+/// 1. This is the `condition` which needs to be true for this log to be sent.
+/// 2. This is the `name` under which this log should be sent. It can be omitted in the same way as for the [`info!`](crate::info)
+///    macro.
+/// 3. The following arguments represent the `message` to be sent. It can be used in the same way as the [`format!`] macro.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// let verbose = true;
+/// info_if!(verbose; "Main"; "Started the server.");
+///
+/// // This is what this macro will expand to:
+/// if verbose {
+///     goolog::info!("Main"; "Started the server.");
+/// }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! info_if {
+    ($condition: expr; $caller: expr; $( $argument: tt ) *) => {
+        if $condition {
+            $crate::info!($caller; $( $argument ) *);
+        }
+    };
+    ($condition: expr; $( $argument: tt ) *) => {
+        $crate::info_if!($condition; GOOLOG_CALLER; $( $argument ) *)
+    }
+}
+/// This macro logs a message at the warn level, but only if the given condition is true. \
+/// The arguments of the message will not be evaluated if the condition is false.
+///
+/// # Parameters
+///
+/// 1. This is the `condition` which needs to be true for this log to be sent.
+/// 2. This is the `name` under which this log should be sent. It can be omitted in the same way as for the [`warn!`](crate::warn)
+///    macro.
+/// 3. The following arguments represent the `message` to be sent. It can be used in the same way as the [`format!`] macro.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// let verbose = true;
+/// warn_if!(verbose; "Main"; "The config file is missing a value.");
+///
+/// // This is what this macro will expand to:
+/// if verbose {
+///     goolog::warn!("Main"; "The config file is missing a value.");
+/// }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! warn_if {
+    ($condition: expr; $caller: expr; $( $argument: tt ) *) => {
+        if $condition {
+            $crate::warn!($caller; $( $argument ) *);
+        }
+    };
+    ($condition: expr; $( $argument: tt ) *) => {
+        $crate::warn_if!($condition; GOOLOG_CALLER; $( $argument ) *)
+    }
+}
+/// This macro logs a message at the error level, but only if the given condition is true. \
+/// The arguments of the message will not be evaluated if the condition is false.
+///
+/// # Parameters
+///
+/// 1. This is the `condition` which needs to be true for this log to be sent.
+/// 2. This is the `name` under which this log should be sent. It can be omitted in the same way as for the [`error!`](crate::error)
+///    macro.
+/// 3. The following arguments represent the `message` to be sent. It can be used in the same way as the [`format!`] macro.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// let verbose = true;
+/// error_if!(verbose; "Main"; "Failed to read the config file.");
+///
+/// // This is what this macro will expand to:
+/// if verbose {
+///     goolog::error!("Main"; "Failed to read the config file.");
+/// }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! error_if {
+    ($condition: expr; $caller: expr; $( $argument: tt ) *) => {
+        if $condition {
+            $crate::error!($caller; $( $argument ) *);
+        }
+    };
+    ($condition: expr; $( $argument: tt ) *) => {
+        $crate::error_if!($condition; GOOLOG_CALLER; $( $argument ) *)
+    }
+}
+/// This macro logs a message at the trace level, but only if the given condition is true. \
+/// The arguments of the message will not be evaluated if the condition is false.
+///
+/// # Parameters
+///
+/// 1. This is the `condition` which needs to be true for this log to be sent.
+/// 2. This is the `name` under which this log should be sent. It can be omitted in the same way as for the [`trace!`](crate::trace)
+///    macro.
+/// 3. The following arguments represent the `message` to be sent. It can be used in the same way as the [`format!`] macro.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// let verbose = true;
+/// trace_if!(verbose; "Main"; "Reading the config file...");
+///
+/// // This is what this macro will expand to:
+/// if verbose {
+///     goolog::trace!("Main"; "Reading the config file...");
+/// }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! trace_if {
+    ($condition: expr; $caller: expr; $( $argument: tt ) *) => {
+        if $condition {
+            $crate::trace!($caller; $( $argument ) *);
+        }
+    };
+    ($condition: expr; $( $argument: tt ) *) => {
+        $crate::trace_if!($condition; GOOLOG_CALLER; $( $argument ) *)
+    }
+}
+/// This macro logs a message at the debug level, but only if the given condition is true. \
+/// The arguments of the message will not be evaluated if the condition is false.
+///
+/// # Parameters
+///
+/// 1. This is the `condition` which needs to be true for this log to be sent.
+/// 2. This is the `name` under which this log should be sent. It can be omitted in the same way as for the [`debug!`](crate::debug)
+///    macro.
+/// 3. The following arguments represent the `message` to be sent. It can be used in the same way as the [`format!`] macro.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// let verbose = true;
+/// debug_if!(verbose; "Main"; "Read the config file.");
+///
+/// // This is what this macro will expand to:
+/// if verbose {
+///     goolog::debug!("Main"; "Read the config file.");
+/// }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! debug_if {
+    ($condition: expr; $caller: expr; $( $argument: tt ) *) => {
+        if $condition {
+            $crate::debug!($caller; $( $argument ) *);
+        }
+    };
+    ($condition: expr; $( $argument: tt ) *) => {
+        $crate::debug_if!($condition; GOOLOG_CALLER; $( $argument ) *)
+    }
+}
+/// This macro logs a message at a level computed at runtime. \
+/// \
+/// This avoids matching over every level at each call site, for example when the level depends on the status code of an HTTP
+/// response. The level expression will be evaluated exactly once, before anything else. Just like for the other macros, the
+/// arguments of the message will only be evaluated if a log at the computed level would be printed, and a log suppressed by the
+/// log level gets counted for [`suppressed_counts`](crate::suppressed_counts). Debug logs will only be sent during debug mode,
+/// just like the ones sent using the [`debug!`](crate::debug) macro.
+///
+/// # Parameters
+///
+/// 1. This is the `level` of the log. It can be any expression evaluating to a [`Level`](log::Level).
+/// 2. This is the `name` under which this log should be sent. It can be omitted in the same way as for the [`info!`](crate::info)
+///    macro.
+/// 3. The following arguments represent the `message` to be sent. It can be used in the same way as the [`format!`] macro.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// use goolog::log::Level;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// let status = 404;
+/// let level = match status {
+///     500.. => Level::Error,
+///     400.. => Level::Warn,
+///     _ => Level::Info,
+/// };
+/// leveled!(level; "Server"; "GET /index.html returned {status}");
+///
+/// // This is what this macro will expand to:
+/// let computed_level = level;
+/// if cfg!(debug_assertions) || computed_level != goolog::log::Level::Debug {
+///     if computed_level <= goolog::log::max_level() {
+///         goolog::log::log!(target: &"Server", computed_level, "GET /index.html returned {status}");
+///     } else {
+///         goolog::INTERNAL__suppressed(computed_level);
+///     }
+/// }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! leveled {
+    ($level: expr; $caller: expr; $( $argument: tt ) *) => {{
+        let level: $crate::log::Level = $level;
+        // just like for the `debug!` macro, debug logs are only sent during debug mode
+        if cfg!(debug_assertions) || level != $crate::log::Level::Debug {
+            if level <= $crate::log::max_level() {
+                $crate::log::log!(target: &$caller, level, $( $argument ) *);
+            } else {
+                $crate::INTERNAL__suppressed(level);
+            }
+        }
+    }};
+    ($level: expr; $( $argument: tt ) *) => {
+        $crate::leveled!($level; GOOLOG_CALLER; $( $argument ) *)
+    }
+}
+/// This macro logs a message under the name of the given type. \
+/// \
+/// The name is read from the [`TARGET`](crate::Logged::TARGET) of the [`Logged`](crate::Logged) trait implemented by the type.
+/// Inside of an `impl` block, `Self` can be passed as the type. Unlike the other macros, this one never falls back to the
+/// `GOOLOG_CALLER` constant of the current module.
+///
+/// # Parameters
+///
+/// 1. This is the `type` implementing the [`Logged`](crate::Logged) trait.
+/// 2. This is the `level` of the log: `info`, `warn`, `error`, `fatal`, `trace` or `debug`.
+/// 3. The following arguments represent the `message` to be sent. It can be used in the same way as the [`format!`] macro.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+///
+/// struct Server;
+/// impl Logged for Server {
+///     const TARGET: &'static str = "Server";
+/// }
+/// impl Server {
+///     fn start(&self, port: u16) {
+///         log_for!(Self; info; "Listening on port {port}");
+///
+///         // This is what this macro will expand to:
+///         goolog::info!(<Self as goolog::Logged>::TARGET; "Listening on port {port}");
+///     }
+/// }
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// Server.start(8080);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! log_for {
+    ($type: ty; $level: ident; $( $argument: tt ) *) => {
+        $crate::$level!(<$type as $crate::Logged>::TARGET; $( $argument ) *)
+    };
+}
+/// This macro logs a message at the given level, attributed to the given source location. \
+/// \
+/// The location will be passed to the logger as the file and line of the log, just like the other macros pass the location they
+/// have been called at. This lets a thin logging helper attribute its logs to its caller instead of itself: mark the helper with
+/// `#[track_caller]` and pass [`Location::caller`](std::panic::Location::caller) to this macro.
+///
+/// # Parameters
+///
+/// 1. This is the `location` the log should be attributed to.
+/// 2. This is the `level` of the log.
+/// 3. This is the `name` under which this log should be sent. It can be omitted in the same way as for the [`info!`](crate::info)
+///    macro.
+/// 4. The following arguments represent the `message` to be sent. It can be used in the same way as the [`format!`] macro.
+///
+/// # Example
+///
+/// ```
+/// use std::panic::Location;
+///
+/// use goolog::*;
+/// use goolog::log::Level;
+///
+/// #[track_caller]
+/// fn log_request(path: &str) {
+///     // this log will be attributed to the caller of this function
+///     log_at!(Location::caller(); Level::Info; "Server"; "Handling a request to {path}");
+///
+///     // This is what this macro will expand to:
+///     goolog::INTERNAL__log_at(Location::caller(), Level::Info, &"Server", format_args!("Handling a request to {path}"));
+/// }
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// log_request("/index.html");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! log_at {
+    ($location: expr; $level: expr; $caller: expr; $( $argument: tt ) *) => {
+        $crate::INTERNAL__log_at($location, $level, &$caller, format_args!($( $argument ) *))
+    };
+    ($location: expr; $level: expr; $( $argument: tt ) *) => {
+        $crate::log_at!($location; $level; GOOLOG_CALLER; $( $argument ) *)
+    }
+}
+/// This macro logs the [`Debug`](std::fmt::Debug) representation of a value at the debug level and returns the value. \
+/// \
+/// This works like the [`dbg!`] macro, but the value will be sent to the goolog logger instead of being printed to stderr. The
+/// log can therefore be suppressed using the log level, and will only be sent during debug mode, just like any log sent using
+/// the [`debug!`](crate::debug) macro. The value needs to implement [`Debug`](std::fmt::Debug).
+///
+/// # Parameters
+///
+/// 1. This is the `name` under which this log should be sent. It can be omitted in the same way as for the
+///    [`debug!`](crate::debug) macro.
+/// 2. This is the `value` to be logged and returned.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// let port = dbg_tap!("Main"; 8000 + 80);
+///
+/// // This is what this macro will expand to:
+/// let port = match 8000 + 80 {
+///     value => {
+///         goolog::debug!("Main"; "[{}:{}] {} = {:?}", file!(), line!(), "8000 + 80", &value);
+///         value
+///     }
+/// };
+/// # }
+/// ```
+#[macro_export]
+macro_rules! dbg_tap {
+    ($caller: expr; $value: expr) => {
+        match $value {
+            value => {
+                $crate::debug!($caller; "[{}:{}] {} = {:?}", file!(), line!(), stringify!($value), &value);
+                value
+            }
+        }
+    };
+    ($value: expr) => {
+        $crate::dbg_tap!(GOOLOG_CALLER; $value)
+    }
+}
+/// This macro logs the names and [`Debug`](std::fmt::Debug) representations of the given variables at the debug level. \
+/// \
+/// Just like any log sent using the [`debug!`](crate::debug) macro, the log will only be sent during debug mode. Multiple
+/// variables will be separated by commas, like `x = 1, y = 2`.
+///
+/// # Parameters
+///
+/// 1. This is the `name` under which this log should be sent. It can be omitted in the same way as for the
+///    [`debug!`](crate::debug) macro.
+/// 2. The following arguments are the `variables` to be logged, separated by commas.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// let (x, y) = (1, "two");
+/// debug_var!("Main"; x, y);
+///
+/// // This is what this macro will expand to:
+/// goolog::debug!("Main"; "{}", [format!("{} = {:?}", "x", &x), format!("{} = {:?}", "y", &y)].join(", "));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! debug_var {
+    ($caller: expr; $( $variable: expr ),+ $(,)?) => {
+        $crate::debug!(
+            $caller;
+            "{}",
+            [$( format!("{} = {:?}", stringify!($variable), &$variable) ),+].join(", ")
+        )
+    };
+    ($( $variable: expr ),+ $(,)?) => {
+        $crate::debug_var!(GOOLOG_CALLER; $( $variable ),+)
+    }
+}
+/// This macro logs an error together with every error of its [`source`](std::error::Error::source) chain at the error level. \
+/// The errors will be separated by `: caused by: ` as described by [`ErrorChain`](crate::ErrorChain).
+///
+/// # Parameters
+///
+/// 1. This is the `name` under which this log should be sent. It can be omitted in the same way as for the
+///    [`error!`](crate::error) macro.
+/// 2. This is the `error` to be sent. It needs to implement [`Error`](std::error::Error).
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// let erro = std::fs::read("config.toml").unwrap_err();
+/// error_chain!("Main"; erro);
+///
+/// // This is what this macro will expand to:
+/// goolog::error!("Main"; "{}", goolog::ErrorChain::new(&erro));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! error_chain {
+    ($caller: expr; $error: expr) => {
+        $crate::error!($caller; "{}", $crate::ErrorChain::new(&$error))
+    };
+    ($error: expr) => {
+        $crate::error_chain!(GOOLOG_CALLER; $error)
+    }
+}
+/// This macro logs an error together with every error of its [`source`](std::error::Error::source) chain at the warn level. \
+/// The errors will be separated by `: caused by: ` as described by [`ErrorChain`](crate::ErrorChain).
+///
+/// # Parameters
+///
+/// 1. This is the `name` under which this log should be sent. It can be omitted in the same way as for the
+///    [`warn!`](crate::warn) macro.
+/// 2. This is the `error` to be sent. It needs to implement [`Error`](std::error::Error).
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// let erro = std::fs::read("config.toml").unwrap_err();
+/// warn_chain!("Main"; erro);
+///
+/// // This is what this macro will expand to:
+/// goolog::warn!("Main"; "{}", goolog::ErrorChain::new(&erro));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! warn_chain {
+    ($caller: expr; $error: expr) => {
+        $crate::warn!($caller; "{}", $crate::ErrorChain::new(&$error))
+    };
+    ($error: expr) => {
+        $crate::warn_chain!(GOOLOG_CALLER; $error)
+    }
+}
+/// This macro logs an [`anyhow::Error`] together with its context chain at the given level. \
+/// \
+/// The error will be printed using its alternate [`Display`](std::fmt::Display) representation (`{:#}`), which separates every
+/// context by `: `. If a backtrace has been captured, for example because `RUST_BACKTRACE=1` is set, it will be sent as a second
+/// log at the trace level. This macro is only available if the `anyhow` feature is enabled.
+///
+/// # Parameters
+///
+/// 1. This is the `level` of the log.
+/// 2. This is the `name` under which this log should be sent. It can be omitted in the same way as for the [`error!`](crate::error)
+///    macro.
+/// 3. This is the `error` to be sent. It needs to be a reference to an [`anyhow::Error`].
+///
+/// # Example
+///
+/// ```
+/// use anyhow::Context;
+/// use goolog::*;
+/// use goolog::log::Level;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// let erro = std::fs::read("config.toml")
+///     .context("Failed to read the config file")
+///     .unwrap_err();
+/// log_anyhow!(Level::Error; "Main"; &erro);
+///
+/// // This is what this macro will expand to:
+/// goolog::INTERNAL__log_anyhow(Level::Error, &"Main", &erro);
+/// # }
+/// ```
+#[cfg(feature = "anyhow")]
+#[macro_export]
+macro_rules! log_anyhow {
+    ($level: expr; $caller: expr; $error: expr) => {
+        $crate::INTERNAL__log_anyhow($level, &$caller, $error)
+    };
+    ($level: expr; $error: expr) => {
+        $crate::log_anyhow!($level; GOOLOG_CALLER; $error)
+    }
+}
+/// This macro logs a message together with the current backtrace at the given level. \
+/// \
+/// This answers the question of how a code path has been reached without having to panic. The backtrace will be appended to the
+/// message, starting on a new line.
+///
+/// # Cost
+///
+/// Capturing and resolving a backtrace is expensive, easily taking milliseconds. Just like for the other macros, neither the
+/// backtrace nor the message will be created if a log at the given level would not be printed, so a disabled call is cheap. The
+/// log is sent using the [`leveled!`](crate::leveled) macro, so it gets counted for [`suppressed_counts`](crate::suppressed_counts)
+/// if suppressed by the log level, and debug logs will only be sent during debug mode.
+///
+/// # Enabling backtraces
+///
+/// The backtrace is captured using [`Backtrace::capture`](std::backtrace::Backtrace::capture), which only captures anything if the
+/// `RUST_LIB_BACKTRACE` or `RUST_BACKTRACE` environment variable is set to a value other than `0`. Otherwise, the log will
+/// mention that backtraces are disabled instead.
+///
+/// # Parameters
+///
+/// 1. This is the `level` of the log. It can be any expression evaluating to a [`Level`](log::Level).
+/// 2. This is the `name` under which this log should be sent. It can be omitted in the same way as for the [`info!`](crate::info)
+///    macro.
+/// 3. The following arguments represent the `message` to be sent. It can be used in the same way as the [`format!`] macro.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// use goolog::log::Level;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// let id = 42;
+/// log_backtrace!(Level::Debug; "Cache"; "Evicted entry {id}");
+///
+/// // This is what this macro will expand to:
+/// goolog::leveled!(Level::Debug; "Cache"; "{}\n{}", format_args!("Evicted entry {id}"), goolog::INTERNAL__backtrace());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! log_backtrace {
+    ($level: expr; $caller: expr; $( $argument: tt ) *) => {
+        $crate::leveled!(
+            $level;
+            $caller;
+            "{}\n{}",
+            format_args!($( $argument ) *),
+            $crate::INTERNAL__backtrace()
+        )
+    };
+    ($level: expr; $( $argument: tt ) *) => {
+        $crate::log_backtrace!($level; GOOLOG_CALLER; $( $argument ) *)
+    }
+}
+/// This macro logs a deprecation warning, but only the first time its call site is reached. \
+/// \
+/// The message will be sent at the warn level and prefixed with `[deprecated] `, so deprecation warnings look the same across
+/// every crate using goolog. Every call site has its own flag: after the warning has been sent, reaching the call site again only
+/// costs a single atomic load. The call site only counts as hit once the warning would actually be printed, as checked by
+/// [`would_log`](crate::would_log), so reaching it before the goolog logger has been initiated, or while the warn level is
+/// filtered out or the target is muted, does not use up its warning. A warning suppressed by the log level gets counted for
+/// [`suppressed_counts`](crate::suppressed_counts) every time the call site is reached until it has been sent.
+///
+/// # Parameters
+///
+/// 1. This is the `name` under which this log should be sent. It can be omitted in the same way as for the [`warn!`](crate::warn)
+///    macro.
+/// 2. The following arguments represent the `message` to be sent. It can be used in the same way as the [`format!`] macro.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// fn connect_insecure() {
+///     // this will only be printed once, no matter how often this function gets called
+///     deprecated!("Client"; "`connect_insecure` will be removed, use `connect` instead");
+/// }
+/// connect_insecure();
+/// connect_insecure();
+///
+/// // This is what this macro will expand to:
+/// static WARNED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+/// if !WARNED.load(std::sync::atomic::Ordering::Relaxed) {
+///     if goolog::log::Level::Warn > goolog::log::max_level() {
+///         goolog::INTERNAL__suppressed(goolog::log::Level::Warn);
+///     } else if goolog::would_log(goolog::log::Level::Warn, &"Client")
+///         && !WARNED.swap(true, std::sync::atomic::Ordering::Relaxed)
+///     {
+///         goolog::log::warn!(
+///             target: &"Client",
+///             "[deprecated] {}",
+///             format_args!("`connect_insecure` will be removed, use `connect` instead")
+///         );
+///     }
+/// }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! deprecated {
+    ($caller: expr; $( $argument: tt ) *) => {{
+        static WARNED: ::std::sync::atomic::AtomicBool = ::std::sync::atomic::AtomicBool::new(false);
+        if !WARNED.load(::std::sync::atomic::Ordering::Relaxed) {
+            if $crate::log::Level::Warn > $crate::log::max_level() {
+                $crate::INTERNAL__suppressed($crate::log::Level::Warn);
+            } else if $crate::would_log($crate::log::Level::Warn, &$caller)
+                && !WARNED.swap(true, ::std::sync::atomic::Ordering::Relaxed)
+            {
+                $crate::log::warn!(target: &$caller, "[deprecated] {}", format_args!($( $argument ) *));
+            }
+        }
+    }};
+    ($( $argument: tt ) *) => {
+        $crate::deprecated!(GOOLOG_CALLER; $( $argument ) *)
+    }
+}
+/// This macro logs a numbered checkpoint at the trace level, like `checkpoint #3 at src/main.rs:42`. \
+/// \
+/// Sprinkling checkpoints over the code is a quick way to find out how far the execution gets, without having to come up with a
+/// unique message for every spot. The number is taken from a single counter shared across all threads, so it shows the order in
+/// which the checkpoints have been reached across the whole application. It is only raised for checkpoints passing the log
+/// level, but since targets get muted after the message has been built, muted checkpoints still take up a number. Just like the
+/// [`dbg!`] macro, checkpoints are meant for debugging and should be removed before committing.
+///
+/// # Parameters
+///
+/// 1. This is the `name` under which this log should be sent. It can be omitted in the same way as for the
+///    [`trace!`](crate::trace) macro.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// checkpoint!("Main");
+///
+/// // This is what this macro will expand to:
+/// goolog::trace!(
+///     "Main";
+///     "checkpoint #{} at {}:{}",
+///     goolog::INTERNAL__CHECKPOINTS.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1,
+///     file!(),
+///     line!()
+/// );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! checkpoint {
+    ($caller: expr) => {
+        $crate::trace!(
+            $caller;
+            "checkpoint #{} at {}:{}",
+            $crate::INTERNAL__CHECKPOINTS.fetch_add(1, ::std::sync::atomic::Ordering::Relaxed) + 1,
+            file!(),
+            line!()
+        )
+    };
+    () => {
+        $crate::checkpoint!(GOOLOG_CALLER)
+    };
+}
+/// This macro wraps a closure building an expensive message argument, so it only gets called if the log is actually printed. \
+/// \
+/// The level macros already skip formatting their arguments if the level of the log is disabled. A log at an enabled level may
+/// still be dropped by a filter, like a target muted using [`mute_target`](crate::mute_target) or sampled using
+/// [`set_sampling`](crate::set_sampling), after its arguments have been evaluated. Wrapping an expensive argument in this macro
+/// defers building it until the message gets formatted, which happens once, after every filter passed. The closure therefore
+/// gets called at most once for every log, no matter how many destinations print it. See [`Lazy`](crate::Lazy) for details.
+///
+/// # Parameters
+///
+/// 1. This is the `closure` building the argument. It takes no parameters and can return anything implementing
+///    [`Display`](std::fmt::Display).
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// let rows = vec![1, 2, 3];
+/// debug!("Database"; "Fetched rows: {}", lazy!(|| format!("{rows:?}")));
+///
+/// // This is what this macro will expand to:
+/// debug!("Database"; "Fetched rows: {}", goolog::Lazy::new(|| format!("{rows:?}")));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! lazy {
+    ($closure: expr) => {
+        $crate::Lazy::new($closure)
+    };
+}
+/// This macro enters a span of work, logging its entry at the info level and returning a [`Span`](crate::Span) which logs its
+/// exit together with its duration once dropped. \
+/// \
+/// Every span gets an id unique across the whole process, which is printed with its entry and its exit. While the span is
+/// alive, the messages of every log sent on the current thread are indented, so nested spans and the logs within them form a
+/// tree. The innermost span of a thread can be read using [`current_span`](crate::current_span). Spans are scoped to the thread
+/// which entered them; see [`Span`](crate::Span) for details. The goolog logger requires the standard library, so there is no
+/// `no_std` fallback: spans always rely on thread-local storage.
+///
+/// # Parameters
+///
+/// 1. This is the `name` under which the entry and exit of this span should be logged. It can be omitted in the same way as for
+///    the [`info!`](crate::info) macro.
+/// 2. This is the `name` of the span.
+/// 3. The following arguments are optional `fields` describing the span, given as `key = value` pairs separated by commas. The
+///    value of every field is rendered using its [`Display`](std::fmt::Display) implementation.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// {
+///     // this will be printed as `request (span #1, id=42) started`
+///     let _span = span!("Server"; "request", id = 42);
+///
+///     // this will be printed as `  Parsing the body...`
+///     info!("Server"; "Parsing the body...");
+///
+///     // once dropped, this will print `request (span #1) finished in 1.23ms`
+/// }
+///
+/// // This is what this macro will expand to:
+/// let _span = goolog::INTERNAL__span(&"Server", &"request", &[("id", &42)]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! span {
+    ($caller: expr; $name: expr $(, $key: ident = $value: expr )* $(,)?) => {
+        $crate::INTERNAL__span(&$caller, &$name, &[$( (stringify!($key), &$value) ),*])
+    };
+    ($name: expr $(, $key: ident = $value: expr )* $(,)?) => {
+        $crate::span!(GOOLOG_CALLER; $name $(, $key = $value )*)
+    };
+}
+/// This macro writes an audit event to the writer set using [`set_audit_writer`](crate::set_audit_writer). \
+/// \
+/// Audit events record security-relevant actions, like a user logging in, apart from the other logs. They are always written as
+/// a single line of JSON containing the time in milliseconds since the unix epoch, the target and every given field, with the
+/// value of every field rendered using its [`Display`](std::fmt::Display) implementation:
+/// `{"timestamp":1685370873000,"target":"Auth","user":"alice","action":"login"}`.
+///
+/// Audit events are never suppressed: they ignore the log level of the goolog logger, muted targets, sampling and any other
+/// filter, and they are written even if the logger has not been initiated.
+///
+/// # Parameters
+///
+/// 1. This is the `name` under which this event should be written. It can be omitted in the same way as for the
+///    [`info!`](crate::info) macro.
+/// 2. The following arguments are the `fields` of the event, given as `key = value` pairs separated by commas.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// let user = "alice";
+/// audit!("Auth"; user = user, action = "delete", record = 42);
+///
+/// // This is what this macro will expand to:
+/// goolog::INTERNAL__audit(&"Auth", &[("user", &user), ("action", &"delete"), ("record", &42)]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! audit {
+    ($caller: expr; $( $key: ident = $value: expr ),+ $(,)?) => {
+        $crate::INTERNAL__audit(&$caller, &[$( (stringify!($key), &$value) ),+])
+    };
+    ($( $key: ident = $value: expr ),+ $(,)?) => {
+        $crate::audit!(GOOLOG_CALLER; $( $key = $value ),+)
+    }
+}
+/// This macro asserts that a log line has been captured at the given level containing the given text. \
+/// \
+/// It scans the log lines captured by the logger initiated using [`init_for_test`](crate::init_for_test) and panics if none of
+/// them has been logged at the given level and contains the given text. The panic message lists every captured log line, so a
+/// failing test shows what has been logged instead. This macro therefore requires the logger of the test to be initiated using
+/// [`init_for_test`](crate::init_for_test); with any other logger nothing gets captured and the assertion always fails.
+///
+/// # Parameters
+///
+/// 1. This is the [`Capture`](crate::Capture) returned by [`init_for_test`](crate::init_for_test).
+/// 2. This is the [`Level`](crate::log::Level) the log needs to have been sent at.
+/// 3. This is the `text` the log line needs to contain, preceded by the keyword `contains`.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// use goolog::log::Level;
+/// # fn main() {
+/// let capture = init_for_test();
+///
+/// error!("Storage"; "Could not write the file: disk full");
+/// assert_logged!(capture, Level::Error, contains "disk full");
+///
+/// // This is what this macro will expand to:
+/// if !capture.logged(Level::Error, "disk full") {
+///     panic!(
+///         "no log line at the {} level containing {:?} has been captured, captured log lines:\n{}",
+///         Level::Error,
+///         "disk full",
+///         capture.lines().join("\n")
+///     );
+/// }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_logged {
+    ($capture: expr, $level: expr, contains $text: expr $(,)?) => {{
+        let capture: &$crate::Capture = &$capture;
+        let level: $crate::log::Level = $level;
+        let text: &str = &$text;
+        if !capture.logged(level, text) {
+            panic!(
+                "no log line at the {} level containing {:?} has been captured, captured log lines:\n{}",
+                level,
+                text,
+                capture.lines().join("\n")
+            );
+        }
+    }};
+}
+/// This macro creates a [`Template`](crate::Template) which can be used to customize the layout of the log lines using
+/// [`set_format`](crate::set_format). \
+/// Unlike [`Template::parse`](crate::Template::parse), the template gets checked at compile time. Any typo in a placeholder name
+/// will therefore cause a compile error.
+///
+/// # Parameters
+///
+/// 1. This is the `template` to be used. See [`Template`](crate::Template) for all possible placeholders.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// set_format(Some(goolog_format!("{timestamp} [{level}] {target}: {message}")));
+///
+/// // This is what this macro will expand to:
+/// set_format(Some({
+///     const _: () = goolog::INTERNAL__validate_template("{timestamp} [{level}] {target}: {message}");
+///     goolog::Template::parse("{timestamp} [{level}] {target}: {message}").unwrap()
+/// }));
+/// # }
+/// ```
+///
+/// A typo in a placeholder name will not compile:
+///
+/// ```compile_fail
+/// use goolog::*;
+///
+/// let template = goolog_format!("{timestamp} [{levle}] {target}: {message}");
+/// ```
+#[macro_export]
+macro_rules! goolog_format {
+    ($template: literal) => {{
+        const _: () = $crate::INTERNAL__validate_template($template);
+        match $crate::Template::parse($template) {
+            Ok(template) => template,
+            Err(_) => unreachable!("the template has already been checked at compile time"),
+        }
+    }};
+}
@@ -0,0 +1,75 @@
+//! This module provides the [`set_multiline`] function used to configure how messages spanning multiple lines are printed.
+
+use std::sync::atomic::{
+    AtomicU8,
+    Ordering,
+};
+
+use crate::format::{
+    display_width,
+    strip_ansi,
+};
+
+/// The way messages spanning multiple lines are printed. \
+/// This is stored as the discriminant of a [`MultilineMode`] so no lock is needed to read it.
+static MULTILINE: AtomicU8 = AtomicU8::new(MultilineMode::Raw as u8);
+
+/// This enum describes how messages spanning multiple lines are printed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum MultilineMode {
+    /// The message will be printed as is. Only its first line will be prefixed with the timestamp, name and level. This is the
+    /// default.
+    #[default]
+    Raw,
+    /// Every continuation line will be indented to start right below the first line of the message.
+    IndentContinuation,
+    /// Every line break will be escaped as `\n`, so every log takes up exactly one line.
+    EscapeNewlines,
+}
+
+/// Set how messages spanning multiple lines are printed. \
+/// \
+/// By default, messages are printed as is, which breaks the columns of the log. See [`MultilineMode`] for all possible options.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_multiline(MultilineMode::EscapeNewlines);
+///
+/// // this will be printed as `First line\nSecond line`
+/// info!("Main"; "First line\nSecond line");
+/// # }
+/// ```
+pub fn set_multiline(multiline: MultilineMode) {
+    MULTILINE.store(multiline as u8, Ordering::Relaxed);
+}
+
+/// Get how messages spanning multiple lines are printed.
+pub fn multiline() -> MultilineMode {
+    match MULTILINE.load(Ordering::Relaxed) {
+        1 => MultilineMode::IndentContinuation,
+        2 => MultilineMode::EscapeNewlines,
+        _ => MultilineMode::Raw,
+    }
+}
+
+/// Escape every line break of the given `message`.
+pub(crate) fn escape_newlines(message: &str) -> String {
+    message.replace('\r', "\\r").replace('\n', "\\n")
+}
+
+/// Indent every continuation line of the given `log` to start right below the first line of its `message`.
+pub(crate) fn indent_continuation(log: &str, message: &str) -> String {
+    let (Some(first_line), Some(first_message_line)) = (log.lines().next(), message.lines().next())
+    else {
+        return log.to_string();
+    };
+    let indent =
+        display_width(&strip_ansi(first_line)) - display_width(&strip_ansi(first_message_line));
+
+    log.replace('\n', &format!("\n{}", " ".repeat(indent)))
+}
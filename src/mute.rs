@@ -0,0 +1,100 @@
+//! This module provides the [`mute_target`] function used to temporarily silence a specific target.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        OnceLock,
+        RwLock,
+    },
+};
+
+/// The set of currently muted targets. \
+/// Every target is mapped to the number of [`MuteGuard`]s currently alive for it, so nested mutes of the same target work as
+/// expected.
+static MUTED_TARGETS: OnceLock<RwLock<HashMap<String, usize>>> = OnceLock::new();
+
+/// The number of currently muted targets. \
+/// This lets every log skip locking the set of muted targets while no target is muted.
+static MUTED_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Get the set of currently muted targets.
+fn muted_targets() -> &'static RwLock<HashMap<String, usize>> {
+    MUTED_TARGETS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Silence every log sent under the given `target` until the returned [`MuteGuard`] gets dropped. \
+/// \
+/// This is useful for wrapping a known-chatty call without reconfiguring the global log level.
+///
+/// # Thread-safety
+///
+/// The set of muted targets is global. Muting a target will therefore silence it on every thread, not just the one holding the
+/// guard. Muting the same target multiple times is allowed; it will stay muted until the last guard has been dropped.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # init_logger(None, None, None);
+///
+/// {
+///     let _guard = mute_target("Chatty");
+///
+///     // this will not be printed
+///     info!("Chatty"; "Lots of noise...");
+/// }
+///
+/// // this will be printed again
+/// info!("Chatty"; "Back to normal.");
+/// # }
+/// ```
+pub fn mute_target(target: &str) -> MuteGuard {
+    let mut muted_targets = muted_targets()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *muted_targets.entry(target.to_string()).or_insert(0) += 1;
+    MUTED_COUNT.store(muted_targets.len(), Ordering::Relaxed);
+
+    MuteGuard {
+        target: target.to_string(),
+    }
+}
+
+/// Check whether the given `target` is currently muted.
+pub(crate) fn is_muted(target: &str) -> bool {
+    if MUTED_COUNT.load(Ordering::Relaxed) == 0 {
+        return false;
+    }
+
+    muted_targets()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .contains_key(target)
+}
+
+/// A guard returned by [`mute_target`]. \
+/// The target will stay muted until this guard gets dropped.
+#[must_use = "the target will be unmuted immediately if the guard is not held"]
+pub struct MuteGuard {
+    /// The target muted by this guard.
+    target: String,
+}
+impl Drop for MuteGuard {
+    fn drop(&mut self) {
+        let mut muted_targets = muted_targets()
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(count) = muted_targets.get_mut(&self.target) {
+            *count -= 1;
+            if *count == 0 {
+                muted_targets.remove(&self.target);
+            }
+        }
+        MUTED_COUNT.store(muted_targets.len(), Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,118 @@
+//! This module provides the [`ReentrancyGuard`] used to handle logs sent while another log is being sent on the same thread.
+
+use std::{
+    cell::Cell,
+    fmt::Arguments,
+    panic::{
+        self,
+        AssertUnwindSafe,
+    },
+};
+
+use log::{
+    Log,
+    Metadata,
+    Record,
+};
+
+#[cfg(feature = "timestamp")]
+use crate::timestamp;
+use crate::{
+    adaptive,
+    delta,
+    entry,
+    routing,
+    sampling,
+    suppressed,
+    target_level,
+};
+
+/// The message printed in place of a message whose arguments panicked while being formatted.
+pub(crate) const FORMATTING_PANICKED: &str = "<formatting panicked>";
+
+thread_local! {
+    /// The number of logs currently being sent on this thread.
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A logger protecting the wrapped logger from logs sent while another log is being sent on the same thread. \
+/// \
+/// This happens when a sink or the [`Display`](std::fmt::Display) implementation of a message argument sends a log itself. Such
+/// a nested log will be printed to stderr without any formatting instead of re-entering the wrapped logger, which could otherwise
+/// deadlock on a held lock or recurse infinitely. It is only printed if it passes the filters of the wrapped logger, like the log
+/// level and muted targets, and its target has not been routed to sinks only. Any log nested even deeper will be dropped. \
+/// \
+/// The message of every log gets formatted once before it reaches the wrapped logger, and gets captured together with the time
+/// of the log into an [`Entry`](crate::entry::Entry) every destination renders its line from. If the [`Display`](std::fmt::Display) or
+/// [`Debug`](std::fmt::Debug) implementation of one of its arguments panics, the message will be replaced by
+/// [`FORMATTING_PANICKED`] instead of poisoning the locks of the wrapped logger. This relies on unwinding, so a panic will still
+/// abort the process if it has been compiled with `panic = "abort"`.
+pub(crate) struct ReentrancyGuard {
+    /// The wrapped logger.
+    logger: Box<dyn Log>,
+}
+impl ReentrancyGuard {
+    /// Protect the given `logger` from nested logs.
+    pub(crate) fn new(logger: Box<dyn Log>) -> Self {
+        Self { logger }
+    }
+}
+impl Log for ReentrancyGuard {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.logger.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        let depth = DEPTH.with(Cell::get);
+        DEPTH.with(|cell| cell.set(depth + 1));
+
+        match depth {
+            // a log which would not be printed anyway does not need to be formatted
+            0 if self.logger.enabled(record.metadata()) && sampling::sample(record.target()) => {
+                delta::track();
+                adaptive::track(record);
+
+                let message = format_message(record.args());
+                let _entry = entry::capture(record, message.clone());
+                #[cfg(feature = "timestamp")]
+                timestamp::track_counter();
+                self.logger.log(
+                    &Record::builder()
+                        .metadata(record.metadata().clone())
+                        .args(format_args!("{message}"))
+                        .module_path(record.module_path())
+                        .file(record.file())
+                        .line(record.line())
+                        .build(),
+                );
+            }
+            0 if adaptive::suppresses(record.metadata())
+                || target_level::suppresses(record.metadata()) =>
+            {
+                suppressed::INTERNAL__suppressed(record.level());
+            }
+            // a nested log must not leak a target the wrapped logger would have kept quiet
+            1 if self.logger.enabled(record.metadata()) && routing::to_builtin(record.target()) => {
+                eprintln!(
+                    "{} | {} | {}",
+                    record.target(),
+                    record.level(),
+                    record.args()
+                )
+            }
+            _ => {}
+        }
+
+        DEPTH.with(|cell| cell.set(depth));
+    }
+
+    fn flush(&self) {
+        self.logger.flush();
+    }
+}
+
+/// Format the given `arguments`, returning [`FORMATTING_PANICKED`] if one of them panics.
+fn format_message(arguments: &Arguments) -> String {
+    panic::catch_unwind(AssertUnwindSafe(|| arguments.to_string()))
+        .unwrap_or_else(|_| FORMATTING_PANICKED.to_string())
+}
@@ -0,0 +1,112 @@
+//! This module provides the [`set_ring_buffer_size`] function used to keep the most recent log lines in memory.
+
+use std::{
+    collections::VecDeque,
+    io::{
+        self,
+        Write,
+    },
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Mutex,
+    },
+};
+
+use crate::format::strip_ansi;
+
+/// The most recent log lines, from the oldest to the newest one.
+static RING_BUFFER: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+/// The maximum number of log lines kept in the [`RING_BUFFER`]. \
+/// `0` means no log lines will be kept.
+static RING_BUFFER_SIZE: AtomicUsize = AtomicUsize::new(0);
+
+/// Keep the most recent `size` log lines in memory, so they can be written somewhere else using [`dump_ring_buffer`]. \
+/// \
+/// Once the buffer is full, every new log line replaces the oldest one. The lines are kept without any ANSI escape codes. Only
+/// the logs passing the log level of the console are kept. Passing `0` will restore the default of not keeping any log lines and
+/// clear the buffer. Shrinking the buffer drops its oldest lines.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_ring_buffer_size(200);
+/// # }
+/// ```
+pub fn set_ring_buffer_size(size: usize) {
+    RING_BUFFER_SIZE.store(size, Ordering::Relaxed);
+
+    let mut ring_buffer = RING_BUFFER
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let excess = ring_buffer.len().saturating_sub(size);
+    ring_buffer.drain(..excess);
+}
+
+/// Get the maximum number of log lines kept in memory.
+pub fn ring_buffer_size() -> usize {
+    RING_BUFFER_SIZE.load(Ordering::Relaxed)
+}
+
+/// Write every log line kept in memory to the given `writer`, from the oldest to the newest one. \
+/// \
+/// This can be used to write the context leading up to an error to a crash file, for example from a panic hook. Dumping the
+/// buffer does not clear it; use [`clear_ring_buffer`] for that.
+///
+/// # Errors
+///
+/// This function will return an error if writing to the given `writer` failed.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_ring_buffer_size(200);
+///
+/// info!("Main"; "Hello World!");
+///
+/// let mut crash_report = vec![];
+/// dump_ring_buffer(&mut crash_report).unwrap();
+/// # }
+/// ```
+pub fn dump_ring_buffer(writer: &mut dyn Write) -> io::Result<()> {
+    let ring_buffer = RING_BUFFER
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    for line in ring_buffer.iter() {
+        writeln!(writer, "{line}")?;
+    }
+
+    writer.flush()
+}
+
+/// Remove every log line kept in memory.
+pub fn clear_ring_buffer() {
+    RING_BUFFER
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clear();
+}
+
+/// Keep the given log `line` in memory if the ring buffer is enabled.
+pub(crate) fn record(line: &str) {
+    let size = ring_buffer_size();
+    if size == 0 {
+        return;
+    }
+
+    let mut ring_buffer = RING_BUFFER
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if ring_buffer.len() >= size {
+        ring_buffer.pop_front();
+    }
+    ring_buffer.push_back(strip_ansi(line));
+}
@@ -0,0 +1,632 @@
+//! This module provides the [`RotationPolicy`] used to rotate the log file of the goolog logger.
+
+use std::{
+    collections::VecDeque,
+    fmt::Arguments,
+    fs::{
+        self,
+        File,
+        OpenOptions,
+    },
+    io::{
+        self,
+        Write,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        atomic::{
+            AtomicU64,
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+        Mutex,
+        RwLock,
+    },
+    time::{
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+use log::Record;
+
+use crate::{
+    format,
+    version::{
+        self,
+        VersionPlacement,
+    },
+};
+
+/// The policy deciding when the log file gets rotated. \
+/// [`None`] means the log file will never be rotated.
+static ROTATION_POLICY: RwLock<Option<RotationPolicy>> = RwLock::new(None);
+
+/// The maximum number of bytes all log files may take up in total. \
+/// [`u64::MAX`] means there is no limit.
+static MAX_TOTAL_SIZE: AtomicU64 = AtomicU64::new(u64::MAX);
+
+/// The line written at the top of every new log file. \
+/// [`None`] means no header will be written.
+static FILE_HEADER: RwLock<Option<String>> = RwLock::new(None);
+
+/// Every log file opened by the goolog logger, so they can be reopened using [`reopen_files`].
+static OPEN_FILES: Mutex<Vec<Arc<Mutex<RotatingFile>>>> = Mutex::new(Vec::new());
+
+/// The function deciding which file a log gets written to.
+static FILE_PATH_RESOLVER: RwLock<Option<FilePathResolver>> = RwLock::new(None);
+/// The files opened for the paths returned by the [`FilePathResolver`], with the least recently used one first.
+static RESOLVED_FILES: Mutex<VecDeque<(PathBuf, Arc<Mutex<RotatingFile>>)>> =
+    Mutex::new(VecDeque::new());
+/// The maximum number of files opened for the paths returned by the [`FilePathResolver`] at the same time.
+static MAX_OPEN_FILES: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_OPEN_FILES);
+
+/// The default maximum number of files opened for the paths returned by the [`FilePathResolver`] at the same time.
+const DEFAULT_MAX_OPEN_FILES: usize = 16;
+
+/// A function deciding which file a log gets written to.
+pub type FilePathResolver = &'static (dyn Fn(&Record) -> PathBuf + Sync);
+
+/// The number of seconds in a day.
+const SECONDS_PER_DAY: u64 = 60 * 60 * 24;
+
+/// This enum describes when the log file gets rotated. \
+/// \
+/// Rotating the log file renames it to `<name>.<date>.<index>.<extension>`, where `date` is the (UTC) day the rotated logs have
+/// been written on and `index` is the lowest number not used by a previous rotation of that day. A new, empty log file will then
+/// be created in its place.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RotationPolicy {
+    /// Rotate the log file before it would grow beyond the given size.
+    Size {
+        /// The maximum size of the log file in bytes.
+        max_bytes: u64,
+    },
+    /// Rotate the log file once a new (UTC) day has begun.
+    Daily,
+    /// Rotate the log file as soon as any of the given policies fires. \
+    /// For example, `Any(vec![Size { max_bytes: 100_000_000 }, Daily])` rotates the log file daily or at 100 MB, whichever comes
+    /// first.
+    Any(Vec<RotationPolicy>),
+}
+impl RotationPolicy {
+    /// Check whether this policy fires for a log file of `size` bytes opened on `opened_day`, if a `line` of the given length
+    /// gets written to it on `day`.
+    fn fires(&self, size: u64, line: u64, opened_day: u64, day: u64) -> bool {
+        match self {
+            // a single line longer than the limit still has to be written somewhere
+            Self::Size { max_bytes } => size > 0 && size + line > *max_bytes,
+            Self::Daily => day != opened_day,
+            Self::Any(policies) => policies
+                .iter()
+                .any(|policy| policy.fires(size, line, opened_day, day)),
+        }
+    }
+}
+
+/// Set the `policy` deciding when the log file gets rotated. \
+/// \
+/// Passing [`None`] will restore the default of never rotating the log file. The policy is checked before every line written to
+/// the log file, so it can be changed at any time.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// // rotate the log file daily or at 100 MB, whichever comes first
+/// set_rotation_policy(Some(RotationPolicy::Any(vec![
+///     RotationPolicy::Size {
+///         max_bytes: 100_000_000,
+///     },
+///     RotationPolicy::Daily,
+/// ])));
+/// # }
+/// ```
+pub fn set_rotation_policy(policy: Option<RotationPolicy>) {
+    *ROTATION_POLICY
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = policy;
+}
+
+/// Get the policy deciding when the log file gets rotated.
+pub fn rotation_policy() -> Option<RotationPolicy> {
+    ROTATION_POLICY
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// Set the maximum number of bytes the log file and all of its rotated files may take up in total. \
+/// \
+/// After every rotation, the oldest rotated files will be deleted until the total size is below this limit. The current log file
+/// will never be deleted. This prevents running out of disk space, no matter how often the log file gets rotated. Only files named
+/// exactly like a rotated file, like `app.2023-05-29.0.log` for `app.log`, count as rotated files; any other file in the
+/// directory, like `app.lock`, is never deleted. Passing [`None`] will restore the default of keeping every rotated file.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_rotation_policy(Some(RotationPolicy::Daily));
+/// // keep at most 1 GB of logs
+/// set_max_total_size(Some(1_000_000_000));
+/// # }
+/// ```
+pub fn set_max_total_size(max_total_size: Option<u64>) {
+    MAX_TOTAL_SIZE.store(max_total_size.unwrap_or(u64::MAX), Ordering::Relaxed);
+}
+
+/// Get the maximum number of bytes the log file and all of its rotated files may take up in total.
+pub fn max_total_size() -> Option<u64> {
+    match MAX_TOTAL_SIZE.load(Ordering::Relaxed) {
+        u64::MAX => None,
+        max_total_size => Some(max_total_size),
+    }
+}
+
+/// Set the `header` written as the first line of every new log file. \
+/// \
+/// The header gets written whenever a new log file is created, including after every rotation. It can be used to describe the
+/// columns or the version of the application for tools reading the raw log file. When appending to an existing log file, the
+/// header will only be written if the file is empty, so it never gets duplicated. Passing [`None`] will restore the default of
+/// not writing any header.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// set_file_header(Some("# my-app 1.0.0 | date | time | target | level | message".to_string()));
+/// init_logger(None, None, None);
+/// # }
+/// ```
+pub fn set_file_header(header: Option<String>) {
+    *FILE_HEADER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = header;
+}
+
+/// Get the header written as the first line of every new log file.
+pub fn file_header() -> Option<String> {
+    FILE_HEADER
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// Set a `resolver` deciding which file each log gets written to. \
+/// \
+/// Instead of writing every log to the log file given while initiating the goolog logger, the resolver gets called for every
+/// log which would be written to it and returns the path of the file to write the log to. This can be used to route the logs to
+/// one file per tenant or per date, for example based on the target of the log. Missing directories will be created. Returning
+/// the path of the log file given while initiating the logger writes the log to it as usual. The resolver is only used if the
+/// logger has been initiated with a log file, and it receives the already formatted log line as the arguments of the record.
+/// Every file gets rotated on its own according to the policy set by [`set_rotation_policy`]. Passing [`None`] will restore the
+/// default of writing every log to the log file.
+///
+/// # Open files
+///
+/// Files opened for a resolved path are kept open for the following logs. At most [`max_open_files`] of them will be open at the
+/// same time; once this limit has been reached, the least recently used file gets closed for every new one. A closed file is
+/// opened again, appending to it, as soon as another log gets written to it.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// set_file_path_resolver(Some(&|record| {
+///     match record.target().strip_prefix("tenant:") {
+///         Some(tenant) => format!("resolver_example_logs/{tenant}.log").into(),
+///         None => "resolver_example_logs/main.log".into(),
+///     }
+/// }));
+/// init_logger(None, None, Some("resolver_example_logs/main.log".into()));
+///
+/// // this will be written to `resolver_example_logs/acme.log`
+/// info!("tenant:acme"; "Created an invoice");
+/// # std::fs::remove_dir_all("resolver_example_logs").ok();
+/// # }
+/// ```
+pub fn set_file_path_resolver(resolver: Option<FilePathResolver>) {
+    *FILE_PATH_RESOLVER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = resolver;
+}
+
+/// Set the maximum number of files opened for the paths returned by the resolver set using [`set_file_path_resolver`] at the
+/// same time. \
+/// \
+/// The `max_open_files` will be at least 1. If more files are open, the least recently used ones get closed with the next log.
+/// By default, at most 16 files will be open at the same time.
+pub fn set_max_open_files(max_open_files: usize) {
+    MAX_OPEN_FILES.store(max_open_files.max(1), Ordering::Relaxed);
+}
+
+/// Get the maximum number of files opened for the paths returned by the resolver set using [`set_file_path_resolver`] at the
+/// same time.
+pub fn max_open_files() -> usize {
+    MAX_OPEN_FILES.load(Ordering::Relaxed)
+}
+
+/// Get whether a resolver has been set using [`set_file_path_resolver`].
+pub(crate) fn file_path_resolver_set() -> bool {
+    FILE_PATH_RESOLVER
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .is_some()
+}
+
+/// Get the file opened for the given resolved `path`, opening it if necessary and closing the least recently used files above the
+/// limit set by [`set_max_open_files`].
+fn resolved_file(path: &Path) -> io::Result<Arc<Mutex<RotatingFile>>> {
+    let mut files = RESOLVED_FILES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let file = match files.iter().position(|(file_path, _)| file_path == path) {
+        Some(index) => files.remove(index).map(|(_, file)| file),
+        None => None,
+    };
+    let file = match file {
+        Some(file) => file,
+        None => {
+            if let Some(directory) = path.parent() {
+                fs::create_dir_all(directory)?;
+            }
+            Arc::new(Mutex::new(RotatingFile::open(path)?))
+        }
+    };
+    files.push_back((path.to_path_buf(), file.clone()));
+    while files.len() > max_open_files() {
+        files.pop_front();
+    }
+
+    Ok(file)
+}
+
+/// Flush and reopen every log file at its configured path. \
+/// \
+/// This is meant to be called from the `SIGHUP` handler of a daemon whose log files get rotated by an external tool like
+/// `logrotate`: once the tool has moved a log file, calling this function makes the logger continue writing to a fresh file at the
+/// original path instead of the moved one. The goolog logger does not install any signal handler itself, so wiring this function
+/// to a signal is up to you. A header set using [`set_file_header`] will be written to every reopened file that is empty.
+///
+/// # Errors
+///
+/// Every log file will be reopened, even if reopening another one failed. The first error encountered will be returned, in which
+/// case the affected file keeps writing to its old location.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, Some("reopen_example_logs/main.log".into()));
+///
+/// // call this from the `SIGHUP` handler of your application
+/// reopen_files().unwrap_or_else(|error| error!("Main"; "Failed to reopen the log files. Error: {error}"));
+/// # std::fs::remove_dir_all("reopen_example_logs").ok();
+/// # }
+/// ```
+pub fn reopen_files() -> io::Result<()> {
+    log::logger().flush();
+
+    let mut files = OPEN_FILES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone();
+    files.extend(
+        RESOLVED_FILES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .map(|(_, file)| file.clone()),
+    );
+
+    let mut result = Ok(());
+    for file in files {
+        let reopened = file
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .reopen();
+        if result.is_ok() {
+            result = reopened;
+        }
+    }
+    result
+}
+
+/// Get the number of (UTC) days passed since the unix epoch.
+pub(crate) fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() / SECONDS_PER_DAY)
+        .unwrap_or_default()
+}
+
+/// Format the given number of `days` since the unix epoch as `YYYY-MM-DD`.
+pub(crate) fn to_date(days: u64) -> String {
+    // see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// A log file which gets rotated according to the policy set by [`set_rotation_policy`].
+pub(crate) struct RotatingFile {
+    /// The path of the log file.
+    path: PathBuf,
+    /// The currently opened log file.
+    file: File,
+    /// The size of the currently opened log file in bytes.
+    size: u64,
+    /// The day the currently opened log file has been opened on.
+    opened_day: u64,
+}
+impl RotatingFile {
+    /// Open the log file at the given `path`.
+    pub(crate) fn open(path: &Path) -> io::Result<Self> {
+        let file = Self::open_file(path)?;
+
+        let mut rotating_file = Self {
+            path: path.to_path_buf(),
+            size: file.metadata()?.len(),
+            file,
+            opened_day: today(),
+        };
+        if rotating_file.size == 0 {
+            rotating_file.write_header()?;
+        }
+        Ok(rotating_file)
+    }
+
+    /// Open the file at the given `path` in append mode.
+    fn open_file(path: &Path) -> io::Result<File> {
+        OpenOptions::new().create(true).append(true).open(path)
+    }
+
+    /// Flush the log file and open it again at its path, creating a new one if it has been moved.
+    fn reopen(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        let file = Self::open_file(&self.path)?;
+        self.size = file.metadata()?.len();
+        self.file = file;
+        if self.size == 0 {
+            self.opened_day = today();
+            self.write_header()?;
+        }
+        Ok(())
+    }
+
+    /// Write the header set by [`set_file_header`], the version set by [`set_show_version`](crate::set_show_version) and the
+    /// header naming the columns enabled by [`set_column_header`](crate::set_column_header) to the log file.
+    fn write_header(&mut self) -> io::Result<()> {
+        let mut header = String::new();
+        if let Some(file_header) = file_header() {
+            header.push_str(&format!("{file_header}\n"));
+        }
+        if let Some(version) = version::version_for(VersionPlacement::Header) {
+            header.push_str(&format!("# version {version}\n"));
+        }
+        if let Some(column_header) = format::header_line() {
+            header.push_str(&format!("{column_header}\n"));
+        }
+
+        self.file.write_all(header.as_bytes())?;
+        self.size += header.len() as u64;
+        Ok(())
+    }
+
+    /// Write the given `line` to the log file, rotating it first if necessary.
+    pub(crate) fn write_line(&mut self, line: &Arguments) -> io::Result<()> {
+        self.write_line_on(line, today())
+    }
+
+    /// Write the given `line` to the log file as if it was written on the given `day`, rotating the file first if necessary.
+    pub(crate) fn write_line_on(&mut self, line: &Arguments, day: u64) -> io::Result<()> {
+        let line = format!("{line}\n");
+
+        let policy = ROTATION_POLICY
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+        if policy
+            .is_some_and(|policy| policy.fires(self.size, line.len() as u64, self.opened_day, day))
+        {
+            self.rotate(day)?;
+        }
+
+        self.file.write_all(line.as_bytes())?;
+        self.size += line.len() as u64;
+        Ok(())
+    }
+
+    /// Rename the current log file and open a new one on the given `day`.
+    fn rotate(&mut self, day: u64) -> io::Result<()> {
+        self.file.flush()?;
+
+        let stem = self
+            .path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let extension = self
+            .path
+            .extension()
+            .map(|extension| format!(".{}", extension.to_string_lossy()))
+            .unwrap_or_default();
+        let date = to_date(self.opened_day);
+        let rotated_path = (0..)
+            .map(|index| {
+                self.path
+                    .with_file_name(format!("{stem}.{date}.{index}{extension}"))
+            })
+            .find(|path| !path.exists())
+            .unwrap_or_else(|| self.path.with_extension("rotated"));
+        fs::rename(&self.path, rotated_path)?;
+
+        self.file = Self::open_file(&self.path)?;
+        self.size = 0;
+        self.opened_day = day;
+        self.write_header()?;
+
+        // the line triggering the rotation must still be written, so pruning never fails the rotation
+        if let Some(max_total_size) = max_total_size() {
+            self.prune(max_total_size);
+        }
+        Ok(())
+    }
+
+    /// Delete the oldest rotated files until all log files take up at most `max_total_size` bytes. \
+    /// This is best-effort: a file which cannot be read or removed, for example because another process deleted it in the
+    /// meantime, gets reported to stderr and skipped.
+    fn prune(&self, max_total_size: u64) {
+        match self.rotated_files() {
+            Ok(rotated_files) => self.remove_oldest(rotated_files, max_total_size),
+            // logging this error would end up in this very file again
+            Err(error) => eprintln!("Failed to list the rotated log files. Error: {error}"),
+        }
+    }
+
+    /// Get the modification time, path and size of every file rotated from this log file, skipping the ones which cannot be read.
+    fn rotated_files(&self) -> io::Result<Vec<(SystemTime, PathBuf, u64)>> {
+        let directory = match self.path.parent() {
+            Some(directory) if !directory.as_os_str().is_empty() => directory,
+            _ => Path::new("."),
+        };
+        let Some(stem) = self.path.file_stem() else {
+            return Ok(vec![]);
+        };
+        let stem = stem.to_string_lossy();
+        let extension = self
+            .path
+            .extension()
+            .map(|extension| format!(".{}", extension.to_string_lossy()))
+            .unwrap_or_default();
+
+        let mut rotated_files = vec![];
+        for entry in fs::read_dir(directory)? {
+            let file = entry.and_then(|entry| {
+                let metadata = entry.metadata()?;
+                match metadata.is_file()
+                    && is_rotated(&entry.file_name().to_string_lossy(), &stem, &extension)
+                {
+                    true => Ok(Some((metadata.modified()?, entry.path(), metadata.len()))),
+                    false => Ok(None),
+                }
+            });
+            match file {
+                Ok(Some(file)) => rotated_files.push(file),
+                Ok(None) => {}
+                Err(error) => eprintln!("Failed to read a rotated log file. Error: {error}"),
+            }
+        }
+        Ok(rotated_files)
+    }
+
+    /// Delete the oldest of the given `rotated_files` until all log files take up at most `max_total_size` bytes, skipping the
+    /// ones which cannot be removed.
+    pub(crate) fn remove_oldest(
+        &self,
+        mut rotated_files: Vec<(SystemTime, PathBuf, u64)>,
+        max_total_size: u64,
+    ) {
+        // the oldest files come first; files modified at the same time are sorted by the date in their name
+        rotated_files.sort();
+
+        let mut total_size = self.size + rotated_files.iter().map(|(_, _, size)| size).sum::<u64>();
+        for (_, path, size) in rotated_files {
+            if total_size <= max_total_size {
+                break;
+            }
+            match fs::remove_file(&path) {
+                Ok(()) => total_size -= size,
+                Err(error) => eprintln!(
+                    "Failed to remove the rotated log file `{}`. Error: {error}",
+                    path.display()
+                ),
+            }
+        }
+    }
+}
+
+/// Check whether the given `file_name` is the name of a file rotated by [`RotatingFile::rotate`] from a log file with the given
+/// `stem` and `extension`: either `{stem}.{YYYY-MM-DD}.{n}{extension}` or the fallback `{stem}.rotated`. \
+/// Any other file sharing the stem, like `app.lock` next to `app.log`, does not belong to the log and must never be pruned.
+fn is_rotated(file_name: &str, stem: &str, extension: &str) -> bool {
+    let Some(rest) = file_name
+        .strip_prefix(stem)
+        .and_then(|rest| rest.strip_prefix('.'))
+    else {
+        return false;
+    };
+    if rest == "rotated" {
+        return true;
+    }
+    let Some(rest) = rest.strip_suffix(extension) else {
+        return false;
+    };
+    let Some((date, index)) = rest.split_once('.') else {
+        return false;
+    };
+
+    date.len() == 10
+        && date.char_indices().all(|(position, char)| match position {
+            4 | 7 => char == '-',
+            _ => char.is_ascii_digit(),
+        })
+        && !index.is_empty()
+        && index.chars().all(|char| char.is_ascii_digit())
+}
+
+/// Create the [`Output`](fern::Output) writing every log line to the rotating log file at the given `path`.
+pub(crate) fn output(path: &Path) -> io::Result<fern::Output> {
+    let file = Arc::new(Mutex::new(RotatingFile::open(path)?));
+    OPEN_FILES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(file.clone());
+
+    let path = path.to_path_buf();
+    Ok(fern::Output::call(move |record| {
+        let resolved_path = FILE_PATH_RESOLVER
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .map(|resolver| resolver(record))
+            .filter(|resolved_path| *resolved_path != path);
+        let file = match resolved_path.map(|resolved_path| resolved_file(&resolved_path)) {
+            Some(Ok(resolved_file)) => resolved_file,
+            Some(Err(error)) => {
+                // logging this error would end up in a log file again
+                eprintln!("Failed to open the resolved log file. Error: {error}");
+                return;
+            }
+            None => file.clone(),
+        };
+
+        let mut file = file.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Err(error) = file.write_line(record.args()) {
+            // logging this error would end up in this very file again
+            eprintln!("Failed to write to the log file. Error: {error}");
+        }
+    }))
+}
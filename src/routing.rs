@@ -0,0 +1,137 @@
+//! This module provides the [`route_target`] function used to send the logs of a target to specific sinks.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        OnceLock,
+        RwLock,
+    },
+};
+
+/// The names of the sinks the logs of a target get routed to and whether they get sent anywhere else.
+type Route = (Vec<String>, RouteMode);
+
+/// The routed targets.
+static ROUTES: OnceLock<RwLock<HashMap<String, Route>>> = OnceLock::new();
+
+/// Get the routed targets.
+fn routes() -> &'static RwLock<HashMap<String, Route>> {
+    ROUTES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// This enum describes whether the logs of a target routed using [`route_target`] still get sent to the other destinations.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RouteMode {
+    /// The logs of the target only get sent to the sinks they have been routed to. This is the default.
+    #[default]
+    Only,
+    /// The logs of the target get sent to the sinks they have been routed to in addition to the console, the log file and every
+    /// sink no target has been routed to.
+    Also,
+}
+
+/// Route the logs sent under the given `target` to the sink with the given `sink_name`. \
+/// \
+/// The name of a sink is the one returned by [`Sink::name`](crate::Sink::name). Routing more targets to the same sink, or the same
+/// target to more sinks, adds to the existing routes, while the `mode` of a target is always the one given last. Every sink a
+/// target has been routed to becomes dedicated to its routed targets and stops receiving the logs of every other target. With
+/// [`RouteMode::Only`], the logs of the target are not printed to the console, not written to the log file and not sent to any
+/// other sink. For example, the logs of an `Audit` target can be sent to a dedicated sink without showing up on the console.
+///
+/// # Precedence
+///
+/// Routing only decides where a log gets sent once it would be printed at all. A log still needs to pass the log level of the
+/// goolog logger and every other filter, like muted targets, and it is only sent to a sink if it is at or above the
+/// [level](crate::Sink::level) of that sink. The log file keeps its own level of `info`.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// use goolog::log::Record;
+///
+/// struct AuditSink;
+/// impl Sink for AuditSink {
+///     fn log(&self, record: &Record, _fatal: bool) {
+///         // write the log to the audit trail
+///     }
+///
+///     fn name(&self) -> String {
+///         "audit".to_string()
+///     }
+/// }
+/// # fn main() {
+///
+/// init_logger(None, None, None);
+/// add_sink(AuditSink);
+/// route_target("Audit", "audit", RouteMode::Only);
+///
+/// // this will only be sent to the audit sink
+/// info!("Audit"; "alice logged in");
+/// // while this will only be printed to the console
+/// info!("Main"; "Hello World!");
+/// # }
+/// ```
+pub fn route_target(target: &str, sink_name: &str, mode: RouteMode) {
+    let mut routes = routes()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let (sinks, route_mode) = routes
+        .entry(target.to_string())
+        .or_insert_with(|| (vec![], mode));
+    if !sinks.iter().any(|sink| sink == sink_name) {
+        sinks.push(sink_name.to_string());
+    }
+    *route_mode = mode;
+}
+
+/// Remove every route of the given `target` set using [`route_target`], so its logs get sent to every destination again.
+pub fn clear_route(target: &str) {
+    routes()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .remove(target);
+}
+
+/// Get the names of the sinks the logs of the given `target` get routed to and whether they get sent anywhere else, if it has
+/// been routed using [`route_target`].
+pub fn target_route(target: &str) -> Option<(Vec<String>, RouteMode)> {
+    routes()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(target)
+        .cloned()
+}
+
+/// Check whether any target has been routed using [`route_target`].
+pub(crate) fn is_routing() -> bool {
+    !routes()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .is_empty()
+}
+
+/// Check whether the logs of the given `target` may be printed to the console and written to the log file.
+pub(crate) fn to_builtin(target: &str) -> bool {
+    !routes()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(target)
+        .is_some_and(|(_, mode)| *mode == RouteMode::Only)
+}
+
+/// Check whether the logs of the given `target` may be sent to the sink with the given `sink_name`.
+pub(crate) fn to_sink(target: &str, sink_name: &str) -> bool {
+    let routes = routes()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    match routes.get(target) {
+        Some((sinks, _)) if sinks.iter().any(|sink| sink == sink_name) => true,
+        Some((_, RouteMode::Only)) => false,
+        // a sink a target has been routed to only receives the logs of its routed targets
+        _ => !routes
+            .values()
+            .any(|(sinks, _)| sinks.iter().any(|sink| sink == sink_name)),
+    }
+}
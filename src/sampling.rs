@@ -0,0 +1,130 @@
+//! This module provides the [`set_sampling`] function used to only print a sample of the logs of very chatty targets.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        OnceLock,
+        RwLock,
+    },
+};
+
+use crate::format::{
+    stable_hash,
+    FNV_OFFSET_BASIS,
+};
+
+/// The seed used to decide which logs of a sampled target get printed. \
+/// [`None`] means every `n`th log gets printed.
+static SAMPLING_SEED: RwLock<Option<u64>> = RwLock::new(None);
+
+/// The sampled targets. \
+/// Every target is mapped to the `n` of its `1 in n` sampling rate and the number of logs sent under it so far.
+static SAMPLED_TARGETS: OnceLock<RwLock<HashMap<String, (usize, AtomicUsize)>>> = OnceLock::new();
+
+/// Get the sampled targets.
+fn sampled_targets() -> &'static RwLock<HashMap<String, (usize, AtomicUsize)>> {
+    SAMPLED_TARGETS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Only print 1 in `one_in` logs sent under the given `target`. \
+/// \
+/// The first log of the target will be printed, followed by every `one_in`th log after it. This keeps the volume of a very chatty
+/// target under control while still printing a representative sample of its logs. The logs are counted across all threads and
+/// levels, but only if they would be printed otherwise, so muted or disabled logs do not count. Passing [`None`], `Some(0)` or
+/// `Some(1)` will restore the default of printing every log of the target. Changing the rate restarts the count.
+///
+/// Keep in mind that sampling changes which lines appear: a rare but important log of a sampled target may be dropped. Use a
+/// separate target for such logs. Logs dropped by the sampling are not reported to [`set_on_drop`](crate::set_on_drop).
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_sampling("Packets", Some(100));
+///
+/// for packet in 0..1000 {
+///     // only 10 of these lines will be printed
+///     info!("Packets"; "Received packet {packet}");
+/// }
+/// # }
+/// ```
+pub fn set_sampling(target: &str, one_in: Option<usize>) {
+    let mut sampled_targets = sampled_targets()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match one_in {
+        Some(one_in) if one_in > 1 => {
+            sampled_targets.insert(target.to_string(), (one_in, AtomicUsize::new(0)));
+        }
+        _ => {
+            sampled_targets.remove(target);
+        }
+    }
+}
+
+/// Set the `seed` used to decide which logs of a target sampled using [`set_sampling`] get printed. \
+/// \
+/// By default, the first log of a sampled target and every `n`th log after it get printed. With a seed, the logs to be printed
+/// are picked by hashing the seed, the target and the position of the log among the logs of its target instead. About 1 in `n`
+/// logs will still be printed, but which ones depends on the seed. Given the same seed, the same logs get picked on every run, so
+/// the sampled logs of two runs can be compared. Passing [`None`] will restore the default of printing every `n`th log.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_sampling("Packets", Some(100));
+/// set_sampling_seed(Some(42));
+/// # }
+/// ```
+pub fn set_sampling_seed(seed: Option<u64>) {
+    *SAMPLING_SEED
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = seed;
+}
+
+/// Get the seed used to decide which logs of a sampled target get printed.
+pub fn sampling_seed() -> Option<u64> {
+    *SAMPLING_SEED
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Get the `n` of the `1 in n` sampling rate of the given `target`, if it has been set using [`set_sampling`].
+pub fn sampling(target: &str) -> Option<usize> {
+    sampled_targets()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(target)
+        .map(|(one_in, _)| *one_in)
+}
+
+/// Count a log sent under the given `target` and check whether it is part of the sample to be printed.
+#[allow(clippy::manual_is_multiple_of)] // `is_multiple_of` would require Rust 1.87
+pub(crate) fn sample(target: &str) -> bool {
+    let sampled_targets = sampled_targets()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let Some((one_in, count)) = sampled_targets.get(target) else {
+        return true;
+    };
+    let position = count.fetch_add(1, Ordering::Relaxed);
+
+    match sampling_seed() {
+        Some(seed) => {
+            let hash = stable_hash(FNV_OFFSET_BASIS, &seed.to_le_bytes());
+            let hash = stable_hash(hash, target.as_bytes());
+            stable_hash(hash, &(position as u64).to_le_bytes()) % *one_in as u64 == 0
+        }
+        None => position % *one_in == 0,
+    }
+}
@@ -0,0 +1,60 @@
+//! This module provides the [`AndroidSink`] used to send logs to the Android logcat.
+
+use std::ffi::{
+    c_char,
+    c_int,
+    CString,
+};
+
+use log::{
+    Level,
+    Record,
+};
+
+use super::Sink;
+use crate::format::to_fixed_size;
+
+/// The maximum length of a tag accepted by the Android logcat.
+const MAX_TAG_LENGTH: u32 = 23;
+
+#[link(name = "log")]
+extern "C" {
+    /// Write a log to the Android logcat.
+    fn __android_log_write(priority: c_int, tag: *const c_char, text: *const c_char) -> c_int;
+}
+
+/// A [`Sink`] sending every log to the Android logcat. \
+/// \
+/// The target of a log is used as its tag and will be truncated to the 23 characters allowed by Android. Since the logcat adds its
+/// own timestamp and priority, only the message of a log gets sent.
+///
+/// # Example
+///
+/// ```ignore
+/// use goolog::*;
+///
+/// init_logger(None, None, None);
+/// add_sink(AndroidSink);
+/// ```
+pub struct AndroidSink;
+impl Sink for AndroidSink {
+    fn log(&self, record: &Record, fatal: bool) {
+        // see `android/log.h`
+        let priority = match record.level() {
+            _ if fatal => 7,
+            Level::Error => 6,
+            Level::Warn => 5,
+            Level::Info => 4,
+            Level::Debug => 3,
+            Level::Trace => 2,
+        };
+        let tag = to_fixed_size(MAX_TAG_LENGTH, record.target());
+        let tag = CString::new(tag.trim_end().replace('\0', "")).unwrap_or_default();
+        let text = CString::new(record.args().to_string().replace('\0', "")).unwrap_or_default();
+
+        // SAFETY: both pointers are valid nul-terminated strings for the duration of this call
+        unsafe {
+            __android_log_write(priority, tag.as_ptr(), text.as_ptr());
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//! This module provides the [`CborSink`] used to send logs as compact binary CBOR frames.
+
+use std::{
+    io::Write,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::{
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+use log::Record;
+
+use super::Sink;
+use crate::{
+    format::generate_bytes,
+    Formatter,
+};
+
+/// The CBOR major type of unsigned integers.
+const UNSIGNED: u8 = 0;
+/// The CBOR major type of UTF-8 strings.
+const TEXT: u8 = 3;
+/// The CBOR major type of maps.
+const MAP: u8 = 5;
+/// The CBOR encoding of `false`.
+const FALSE: u8 = 0xf4;
+/// The CBOR encoding of `true`.
+const TRUE: u8 = 0xf5;
+
+/// A [`Sink`] writing every log as a compact binary [CBOR](https://www.rfc-editor.org/rfc/rfc8949) frame to the given writer. \
+/// \
+/// This is meant for shipping logs over links where every byte counts, like from an embedded device to a gateway. The fields of a
+/// log are encoded directly, without any serialization framework, so this sink does not pull in any dependency.
+///
+/// # Framing
+///
+/// Every log is written as a frame consisting of the length of the encoded log in bytes, as a 32-bit big-endian unsigned integer,
+/// followed by the encoded log. A consumer reads the four bytes of the length, then exactly that many bytes, and decodes them
+/// using any CBOR decoder.
+///
+/// The encoded log is a CBOR map with text keys:
+///
+/// | Key | Type | Value |
+/// |-|-|-|
+/// | `timestamp` | unsigned integer | The milliseconds since the unix epoch. |
+/// | `level` | text | The level of the log, like `INFO`, or `FATAL` for logs sent by the [`fatal!`](crate::fatal) macro. |
+/// | `target` | text | The caller name of the log. |
+/// | `message` | text | The message of the log, after the transform set using [`set_message_transform`](crate::set_message_transform). |
+/// | `fatal` | boolean | Whether the log has been sent by the [`fatal!`](crate::fatal) macro. |
+/// | `module_path` | text | The module the log has been sent from. This key is missing if the module is unknown. |
+/// | `file` | text | The file the log has been sent from. This key is missing if the file is unknown. |
+/// | `line` | unsigned integer | The line the log has been sent from. This key is missing if the line is unknown. |
+///
+/// Consumers should ignore unknown keys, since more keys may be added in the future.
+///
+/// # Errors
+///
+/// Since logging must not fail, errors returned by the writer are ignored. Every frame is written using a single call to
+/// [`Write::write_all`], so a failed write loses at most that frame.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+///
+/// init_logger(None, None, None);
+/// // any owned writer works, like the handle of a serial port
+/// add_sink(CborSink::new(std::io::sink()));
+///
+/// info!("Main"; "This will also be written as a CBOR frame.");
+/// # }
+/// ```
+pub struct CborSink<W: Write + Send> {
+    /// The writer every frame gets written to.
+    writer: Mutex<W>,
+    /// The formatter used to encode the logs instead of the CBOR map, if any.
+    formatter: Option<Arc<dyn Formatter>>,
+}
+impl<W: Write + Send> CborSink<W> {
+    /// Create a sink writing every log as a CBOR frame to the given `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+            formatter: None,
+        }
+    }
+
+    /// Encode the logs using [`Formatter::format_bytes`] of the given `formatter` instead of the CBOR map described above. \
+    /// \
+    /// This can be used to send the logs in another binary format, like MessagePack. The bytes returned by the formatter are used
+    /// as they are, framed by their length just like the CBOR map.
+    pub fn with_formatter(mut self, formatter: Arc<dyn Formatter>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+}
+impl<W: Write + Send> Sink for CborSink<W> {
+    fn log(&self, record: &Record, fatal: bool) {
+        let frame = match &self.formatter {
+            Some(formatter) => length_prefixed(generate_bytes(record, fatal, formatter.as_ref())),
+            None => frame(record, fatal),
+        };
+        self.writer
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .write_all(&frame)
+            .ok();
+    }
+
+    fn flush(&self) {
+        self.writer
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .flush()
+            .ok();
+    }
+}
+
+/// Encode the given `record` as a length-prefixed CBOR frame. \
+/// `fatal` indicates whether the record has been sent by the [`fatal!`](crate::fatal) macro.
+pub(crate) fn frame(record: &Record, fatal: bool) -> Vec<u8> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+        .unwrap_or_default();
+    let level = if fatal {
+        "FATAL".to_string()
+    } else {
+        record.level().to_string()
+    };
+    let message = record.args().to_string();
+
+    let mut log = vec![];
+    let fields = 5
+        + usize::from(record.module_path().is_some())
+        + usize::from(record.file().is_some())
+        + usize::from(record.line().is_some());
+    head(&mut log, MAP, fields as u64);
+    text(&mut log, "timestamp");
+    head(&mut log, UNSIGNED, timestamp);
+    text(&mut log, "level");
+    text(&mut log, &level);
+    text(&mut log, "target");
+    text(&mut log, record.target());
+    text(&mut log, "message");
+    text(&mut log, &message);
+    text(&mut log, "fatal");
+    log.push(if fatal { TRUE } else { FALSE });
+    if let Some(module_path) = record.module_path() {
+        text(&mut log, "module_path");
+        text(&mut log, module_path);
+    }
+    if let Some(file) = record.file() {
+        text(&mut log, "file");
+        text(&mut log, file);
+    }
+    if let Some(line) = record.line() {
+        text(&mut log, "line");
+        head(&mut log, UNSIGNED, u64::from(line));
+    }
+
+    length_prefixed(log)
+}
+
+/// Prefix the given encoded `log` with its length as a 32-bit big-endian unsigned integer.
+fn length_prefixed(log: Vec<u8>) -> Vec<u8> {
+    let mut frame = u32::try_from(log.len())
+        .unwrap_or(u32::MAX)
+        .to_be_bytes()
+        .to_vec();
+    frame.extend(log);
+    frame
+}
+
+/// Write the head of a CBOR data item of the given `major` type, carrying the given `value`, to `out`.
+fn head(out: &mut Vec<u8>, major: u8, value: u64) {
+    let major = major << 5;
+    match value {
+        0..=23 => out.push(major | value as u8),
+        24..=0xff => out.extend([major | 24, value as u8]),
+        0x100..=0xffff => {
+            out.push(major | 25);
+            out.extend((value as u16).to_be_bytes());
+        }
+        0x1_0000..=0xffff_ffff => {
+            out.push(major | 26);
+            out.extend((value as u32).to_be_bytes());
+        }
+        _ => {
+            out.push(major | 27);
+            out.extend(value.to_be_bytes());
+        }
+    }
+}
+
+/// Write the given `value` as a CBOR text string to `out`.
+fn text(out: &mut Vec<u8>, value: &str) {
+    head(out, TEXT, value.len() as u64);
+    out.extend(value.as_bytes());
+}
@@ -0,0 +1,16 @@
+//! This module provides the function used to print logs to the browser console.
+
+use log::Level;
+
+/// Print the given `log` line to the browser console. \
+/// The console method used depends on the `level` of the log, so browsers can filter and highlight it accordingly.
+pub(crate) fn log(level: Level, log: String) {
+    let log = log.into();
+    match level {
+        Level::Error => web_sys::console::error_1(&log),
+        Level::Warn => web_sys::console::warn_1(&log),
+        Level::Info => web_sys::console::info_1(&log),
+        Level::Debug => web_sys::console::debug_1(&log),
+        Level::Trace => web_sys::console::log_1(&log),
+    }
+}
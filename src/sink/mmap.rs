@@ -0,0 +1,231 @@
+//! This module provides the [`MmapSink`] used to keep the most recent log lines in a memory-mapped file.
+
+use std::{
+    fs::{
+        File,
+        OpenOptions,
+    },
+    io,
+    path::Path,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use log::Record;
+use memmap2::MmapMut;
+
+use super::Sink;
+use crate::{
+    format::generate_plain_log,
+    Formatter,
+};
+
+/// The bytes every file written by a [`MmapSink`] starts with.
+const MAGIC: &[u8; 8] = b"GOOLOGMM";
+/// The number of bytes in front of the ring of a file written by a [`MmapSink`].
+const HEADER_LEN: usize = 24;
+
+/// A [`Sink`] keeping the most recent log lines in a fixed-size memory-mapped file, so they survive a crash of the application. \
+/// \
+/// Every log line is copied into memory shared with the operating system, which writes it to the file even if the application
+/// gets killed, aborts, or crashes before it had the chance to flush anything. Reading the file after the crash, using
+/// [`MmapSink::recover`], shows what the application was doing right before it died. Since the lines are only copied to memory,
+/// this is much cheaper than flushing a regular log file after every line. Logs still get lost if the whole system crashes
+/// before the operating system wrote them to the disk; calling [`flush`](Sink::flush) forces it to do so.
+///
+/// The log lines are rendered just like the ones printed to the console, but without any colors, and are separated by newlines.
+///
+/// # Ring
+///
+/// The file never grows beyond the size given to [`MmapSink::new`]. Once its ring is full, every new log line overwrites the
+/// oldest bytes, so the file always holds the most recent log lines that fit into it. A log line longer than the whole ring only
+/// keeps its end.
+///
+/// # Recovery format
+///
+/// The file starts with a header of 24 bytes, followed by the ring:
+///
+/// | Offset | Length | Value |
+/// |-|-|-|
+/// | 0 | 8 | The magic bytes `GOOLOGMM`. |
+/// | 8 | 8 | The capacity of the ring in bytes, as a 64-bit little-endian unsigned integer. |
+/// | 16 | 8 | The number of bytes ever written to the ring, as a 64-bit little-endian unsigned integer. |
+/// | 24 | capacity | The ring. |
+///
+/// If the number of bytes written is not larger than the capacity, the log lines are the first that many bytes of the ring.
+/// Otherwise, the oldest byte is at the number of bytes written modulo the capacity, and the log lines are the ring read from
+/// there to its end, followed by the ring read from its start up to there. The first line may then have been cut by the bytes
+/// written after it and should be dropped. The number of bytes written is only updated after a line has been copied into the ring,
+/// so a line being written during a crash is lost.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// # let path = std::env::temp_dir().join("goolog-mmap-doc.log");
+///
+/// init_logger(None, None, None);
+/// // keep the most recent 64 KiB of log lines
+/// add_sink(MmapSink::new(&path, 64 * 1024).unwrap_or_else(|error| panic!("{error}")));
+///
+/// info!("Main"; "This will survive a crash.");
+/// # }
+/// ```
+pub struct MmapSink {
+    /// The memory-mapped file.
+    map: Mutex<MmapMut>,
+    /// The formatter used to render the log lines, if it differs from the one set by [`set_formatter`](crate::set_formatter).
+    formatter: Option<Arc<dyn Formatter>>,
+}
+impl MmapSink {
+    /// Create a sink keeping the most recent log lines in the file at the given `path`, which takes up `size` bytes including its
+    /// header. \
+    /// \
+    /// If the file already holds a ring of the same size, new log lines get appended to it. Otherwise, it gets created or
+    /// overwritten. To read the log lines of a crashed run, call [`MmapSink::recover`] before creating a new sink.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `size` does not leave room for at least one byte after the header of 24 bytes, or if the
+    /// file could not be opened, resized, or mapped into memory.
+    pub fn new(path: impl AsRef<Path>, size: usize) -> io::Result<Self> {
+        if size <= HEADER_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("the size of a memory-mapped log file needs to be larger than {HEADER_LEN} bytes"),
+            ));
+        }
+        let capacity = (size - HEADER_LEN) as u64;
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        let reuse = file.metadata()?.len() == size as u64
+            && matches!(read_header(&file), Ok((existing, _)) if existing == capacity);
+        file.set_len(size as u64)?;
+
+        // SAFETY: the mapping is only accessed through the mutex of this sink, and the file is owned by it for as long as it is
+        // mapped; the offset read from the header is always reduced modulo the capacity before being used
+        let mut map = unsafe { MmapMut::map_mut(&file)? };
+        if !reuse {
+            map.fill(0);
+            map[..8].copy_from_slice(MAGIC);
+            map[8..16].copy_from_slice(&capacity.to_le_bytes());
+        }
+
+        Ok(Self {
+            map: Mutex::new(map),
+            formatter: None,
+        })
+    }
+
+    /// Render the log lines kept by this sink using the given `formatter` instead of the one set by
+    /// [`set_formatter`](crate::set_formatter).
+    pub fn with_formatter(mut self, formatter: Arc<dyn Formatter>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Read the log lines kept in the file at the given `path`, from the oldest to the most recent one. \
+    /// \
+    /// This is meant to be called after a crash, before a new [`MmapSink`] is created for the same file. A line which has been
+    /// partly overwritten by newer ones is dropped, and invalid UTF-8 is replaced.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the file could not be read or was not written by a [`MmapSink`].
+    pub fn recover(path: impl AsRef<Path>) -> io::Result<Vec<String>> {
+        let data = std::fs::read(path)?;
+        let invalid = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "not a memory-mapped goolog log file",
+            )
+        };
+        if data.len() < HEADER_LEN || &data[..8] != MAGIC {
+            return Err(invalid());
+        }
+        let (capacity, written) = parse_header(&data);
+        let ring = &data[HEADER_LEN..];
+        if capacity == 0 || ring.len() as u64 != capacity {
+            return Err(invalid());
+        }
+
+        let lines = if written <= capacity {
+            String::from_utf8_lossy(&ring[..written as usize]).into_owned()
+        } else {
+            let oldest = (written % capacity) as usize;
+            let mut bytes = ring[oldest..].to_vec();
+            bytes.extend_from_slice(&ring[..oldest]);
+            let lines = String::from_utf8_lossy(&bytes).into_owned();
+            match lines.split_once('\n') {
+                Some((_, rest)) => rest.to_string(),
+                None => String::new(),
+            }
+        };
+
+        Ok(lines.lines().map(str::to_string).collect())
+    }
+}
+impl Sink for MmapSink {
+    fn log(&self, record: &Record, fatal: bool) {
+        let line = format!(
+            "{}\n",
+            generate_plain_log(record, fatal, self.formatter.as_deref())
+        );
+        let mut map = self
+            .map
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let (header, ring) = map.split_at_mut(HEADER_LEN);
+        let (_, mut written) = parse_header(header);
+
+        let capacity = ring.len();
+        let bytes = &line.as_bytes()[line.len().saturating_sub(capacity)..];
+        let start = (written % capacity as u64) as usize;
+        let first = bytes.len().min(capacity - start);
+        ring[start..start + first].copy_from_slice(&bytes[..first]);
+        ring[..bytes.len() - first].copy_from_slice(&bytes[first..]);
+
+        written = written.wrapping_add(bytes.len() as u64);
+        header[16..24].copy_from_slice(&written.to_le_bytes());
+    }
+
+    fn flush(&self) {
+        self.map
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .flush()
+            .ok();
+    }
+}
+
+/// Read the capacity of the ring and the number of bytes written from the header of the given `file`.
+fn read_header(file: &File) -> io::Result<(u64, u64)> {
+    use std::io::Read;
+
+    let mut header = [0; HEADER_LEN];
+    (&*file).read_exact(&mut header)?;
+    if &header[..8] != MAGIC {
+        return Err(io::ErrorKind::InvalidData.into());
+    }
+
+    Ok(parse_header(&header))
+}
+
+/// Get the capacity of the ring and the number of bytes written from the given `header`.
+fn parse_header(header: &[u8]) -> (u64, u64) {
+    let field = |offset: usize| {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&header[offset..offset + 8]);
+        u64::from_le_bytes(bytes)
+    };
+
+    (field(8), field(16))
+}
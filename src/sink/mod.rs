@@ -0,0 +1,218 @@
+//! This module provides the [`Sink`] trait used to send logs to additional destinations.
+
+#[cfg(all(feature = "android", target_os = "android"))]
+mod android;
+#[cfg(feature = "cbor")]
+pub(crate) mod cbor;
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub(crate) mod console;
+#[cfg(all(unix, feature = "mmap"))]
+mod mmap;
+#[cfg(feature = "net")]
+mod net;
+#[cfg(any(unix, feature = "net"))]
+pub(crate) mod stream;
+#[cfg(unix)]
+mod unix_socket;
+mod writer;
+
+use std::sync::RwLock;
+
+#[cfg(all(feature = "android", target_os = "android"))]
+pub use android::AndroidSink;
+#[cfg(feature = "cbor")]
+pub use cbor::CborSink;
+use log::{
+    LevelFilter,
+    Record,
+};
+#[cfg(all(unix, feature = "mmap"))]
+pub use mmap::MmapSink;
+#[cfg(feature = "net")]
+pub use net::{
+    TcpSink,
+    UdpSink,
+};
+#[cfg(any(unix, feature = "net"))]
+pub use stream::STREAM_BUFFER_CAP;
+#[cfg(unix)]
+pub use unix_socket::UnixSocketSink;
+pub use writer::WriterSink;
+
+use crate::{
+    entry,
+    flush,
+    format::{
+        strip_fatal_marker,
+        transform_message,
+    },
+    routing,
+};
+
+/// The sinks every log will be sent to in addition to the console and the log file.
+static SINKS: RwLock<Vec<Box<dyn Sink>>> = RwLock::new(Vec::new());
+/// The destinations set up while initiating the goolog logger, like the console and the log file.
+static BUILTIN_SINKS: RwLock<Vec<SinkInfo>> = RwLock::new(Vec::new());
+
+/// A snapshot of the configuration of a destination logs get sent to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SinkInfo {
+    /// A human-readable name of the destination.
+    pub name: String,
+    /// The level a log needs to be at or above to be sent to the destination.
+    pub level: LevelFilter,
+}
+
+/// A destination logs can be sent to in addition to the console and the log file. \
+/// \
+/// Sinks can be registered using the [`add_sink`] function.
+pub trait Sink: Send + Sync {
+    /// Send the given `record` to this sink. \
+    /// `fatal` indicates whether the record has been sent by the [`fatal!`](crate::fatal) macro. The arguments of the record
+    /// have already been materialized into a string once for every destination, so formatting them is as cheap as copying it.
+    fn log(&self, record: &Record, fatal: bool);
+
+    /// Get a human-readable name of this sink. \
+    /// By default, this is the name of the type implementing this trait.
+    fn name(&self) -> String {
+        std::any::type_name::<Self>().to_string()
+    }
+
+    /// Get the level a log needs to be at or above to be sent to this sink. \
+    /// By default, this sink will receive every log passing the log level of the goolog logger.
+    fn level(&self) -> LevelFilter {
+        LevelFilter::Trace
+    }
+
+    /// Flush any buffered logs.
+    fn flush(&self) {}
+}
+
+/// Register a `sink` every log will be sent to in addition to the console and the log file. \
+/// \
+/// The sink will only receive logs passing the log level and all other filters of the goolog logger.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// use goolog::log::Record;
+///
+/// struct StderrSink;
+/// impl Sink for StderrSink {
+///     fn log(&self, record: &Record, _fatal: bool) {
+///         eprintln!("{}: {}", record.target(), record.args());
+///     }
+/// }
+/// # fn main() {
+///
+/// init_logger(None, None, None);
+/// add_sink(StderrSink);
+///
+/// info!("Main"; "This will also be printed to stderr.");
+/// # }
+/// ```
+pub fn add_sink(sink: impl Sink + 'static) {
+    SINKS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(Box::new(sink));
+}
+
+/// Get a snapshot of every destination logs get sent to. \
+/// \
+/// This includes the console and the log file set up while initiating the goolog logger, followed by every sink registered using
+/// [`add_sink`]. This can be used to confirm the setup of the logger, for example by printing it on startup.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+///
+/// for sink in sinks() {
+///     info!("Main"; "Sending logs at or above {} to {}", sink.level, sink.name);
+/// }
+/// # }
+/// ```
+pub fn sinks() -> Vec<SinkInfo> {
+    let mut sinks = BUILTIN_SINKS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone();
+    sinks.extend(
+        SINKS
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .map(|sink| SinkInfo {
+                name: sink.name(),
+                level: sink.level(),
+            }),
+    );
+
+    sinks
+}
+
+/// Register a destination set up while initiating the goolog logger.
+pub(crate) fn add_builtin_sink(name: impl Into<String>, level: LevelFilter) {
+    BUILTIN_SINKS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(SinkInfo {
+            name: name.into(),
+            level,
+        });
+}
+
+/// Send the given `record` to every registered sink.
+pub(crate) fn log(record: &Record) {
+    let sinks = SINKS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if sinks.is_empty() {
+        return;
+    }
+
+    // the log has usually been captured already, so its arguments do not need to be formatted again
+    let (fatal, message) = match entry::current() {
+        Some(entry) => (entry.fatal, entry.message.clone()),
+        None => {
+            let (fatal, message) = strip_fatal_marker(record.level(), record.args().to_string());
+            (fatal, transform_message(message))
+        }
+    };
+    let flush = flush::should_flush(record.level());
+    let routing = routing::is_routing();
+    for sink in sinks.iter().filter(|sink| {
+        record.level() <= sink.level()
+            && (!routing || routing::to_sink(record.target(), &sink.name()))
+    }) {
+        sink.log(
+            &Record::builder()
+                .level(record.level())
+                .target(record.target())
+                .args(format_args!("{message}"))
+                .module_path(record.module_path())
+                .file(record.file())
+                .line(record.line())
+                .build(),
+            fatal,
+        );
+        if flush {
+            sink.flush();
+        }
+    }
+}
+
+/// Flush every registered sink.
+pub(crate) fn flush() {
+    for sink in SINKS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+    {
+        sink.flush();
+    }
+}
@@ -0,0 +1,235 @@
+//! This module provides the [`TcpSink`] and [`UdpSink`] used to send logs to a remote log collector.
+
+use std::{
+    io,
+    net::{
+        Ipv4Addr,
+        Ipv6Addr,
+        SocketAddr,
+        TcpStream,
+        ToSocketAddrs,
+        UdpSocket,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use log::Record;
+
+use super::{
+    stream::{
+        ReconnectingStream,
+        WRITE_TIMEOUT,
+    },
+    Sink,
+};
+use crate::{
+    format::generate_plain_log,
+    Formatter,
+};
+
+/// The longest time an attempt to connect to a remote log collector may block the logging thread.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// A [`Sink`] sending every log line to a remote log collector over TCP, like the TCP input of Logstash. \
+/// \
+/// The log lines are rendered just like the ones printed to the console, but without any colors, and are separated by newlines.
+///
+/// # Delivery
+///
+/// Logs are sent on a best-effort basis. The connection gets established once the first log is sent. Whenever the connection is
+/// lost or could not be established, the sink waits before attempting to reconnect, starting at 100 milliseconds and doubling the
+/// time after every failed attempt up to 30 seconds. In the meantime, at most [`STREAM_BUFFER_CAP`](crate::STREAM_BUFFER_CAP) log
+/// lines will be buffered; once this limit has been reached, the oldest line will be dropped for every new one. Lines written
+/// right before the connection breaks may be lost. The address gets resolved once when the sink is created, so reconnecting
+/// never waits for a DNS lookup. An attempt to connect blocks the logging thread for at most one second in total, even if the
+/// address resolved to several socket addresses, and writing a line for at most 100 milliseconds.
+///
+/// # Example
+///
+/// ```no_run
+/// use goolog::*;
+/// # fn main() {
+///
+/// init_logger(None, None, None);
+/// add_sink(TcpSink::new("logs.example.com:5000").unwrap());
+///
+/// info!("Main"; "This will also be sent to the log collector.");
+/// # }
+/// ```
+pub struct TcpSink {
+    /// The address of the log collector, as given to [`TcpSink::new`].
+    address: String,
+    /// The socket addresses the address of the log collector resolved to.
+    socket_addresses: Vec<SocketAddr>,
+    /// The connection to the log collector.
+    stream: Mutex<ReconnectingStream<TcpStream>>,
+    /// The formatter used to render the log lines, if it differs from the one set by [`set_formatter`](crate::set_formatter).
+    formatter: Option<Arc<dyn Formatter>>,
+}
+impl TcpSink {
+    /// Create a sink sending every log line to the log collector at the given `address`, like `logs.example.com:5000`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the address could not be resolved.
+    pub fn new(address: impl Into<String>) -> io::Result<Self> {
+        let address = address.into();
+        let socket_addresses: Vec<_> = address.as_str().to_socket_addrs()?.collect();
+        if socket_addresses.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the address could not be resolved",
+            ));
+        }
+
+        Ok(Self {
+            address,
+            socket_addresses,
+            stream: Mutex::new(ReconnectingStream::new()),
+            formatter: None,
+        })
+    }
+
+    /// Render the log lines sent by this sink using the given `formatter` instead of the one set by
+    /// [`set_formatter`](crate::set_formatter). \
+    /// \
+    /// Every log line will then be rendered once more for this sink, which costs about as much as rendering the line for the
+    /// console. The same formatter can be shared by multiple sinks by cloning the [`Arc`].
+    pub fn with_formatter(mut self, formatter: Arc<dyn Formatter>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Connect to the log collector, trying every socket address it resolved to until [`CONNECT_TIMEOUT`] has passed.
+    fn connect(&self) -> io::Result<TcpStream> {
+        let deadline = Instant::now() + CONNECT_TIMEOUT;
+        let mut error = io::Error::new(
+            io::ErrorKind::TimedOut,
+            "connecting to the log collector timed out",
+        );
+        for address in &self.socket_addresses {
+            let timeout = deadline.saturating_duration_since(Instant::now());
+            if timeout.is_zero() {
+                break;
+            }
+            match TcpStream::connect_timeout(address, timeout) {
+                Ok(stream) => {
+                    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+                    return Ok(stream);
+                }
+                Err(connect_error) => error = connect_error,
+            }
+        }
+
+        Err(error)
+    }
+}
+impl Sink for TcpSink {
+    fn log(&self, record: &Record, fatal: bool) {
+        let line = format!(
+            "{}\n",
+            generate_plain_log(record, fatal, self.formatter.as_deref())
+        );
+        self.stream
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .send(line, || self.connect());
+    }
+
+    fn name(&self) -> String {
+        format!("tcp `{}`", self.address)
+    }
+
+    fn flush(&self) {
+        self.stream
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .flush(|| self.connect());
+    }
+}
+
+/// A [`Sink`] sending every log line to a remote log collector over UDP. \
+/// \
+/// Every log line is rendered just like the ones printed to the console, but without any colors, and sent as its own datagram.
+///
+/// # Delivery
+///
+/// Logs are sent fire-and-forget. Datagrams which get lost or could not be sent will not be retried, and nothing will be
+/// buffered. Long log lines may exceed the maximum size of a datagram and get dropped.
+///
+/// # Example
+///
+/// ```no_run
+/// use goolog::*;
+/// # fn main() {
+///
+/// init_logger(None, None, None);
+/// add_sink(UdpSink::new("logs.example.com:5000").unwrap());
+///
+/// info!("Main"; "This will also be sent to the log collector.");
+/// # }
+/// ```
+pub struct UdpSink {
+    /// The address of the log collector.
+    address: SocketAddr,
+    /// The socket used to send the log lines.
+    socket: UdpSocket,
+    /// The formatter used to render the log lines, if it differs from the one set by [`set_formatter`](crate::set_formatter).
+    formatter: Option<Arc<dyn Formatter>>,
+}
+impl UdpSink {
+    /// Create a sink sending every log line to the log collector at the given `address`, like `logs.example.com:5000`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the address could not be resolved or no local socket could be bound.
+    pub fn new(address: impl ToSocketAddrs) -> io::Result<Self> {
+        let address = address.to_socket_addrs()?.next().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the address could not be resolved",
+            )
+        })?;
+        let socket = match address {
+            SocketAddr::V4(_) => UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?,
+            SocketAddr::V6(_) => UdpSocket::bind((Ipv6Addr::UNSPECIFIED, 0))?,
+        };
+
+        Ok(Self {
+            address,
+            socket,
+            formatter: None,
+        })
+    }
+
+    /// Render the log lines sent by this sink using the given `formatter` instead of the one set by
+    /// [`set_formatter`](crate::set_formatter). \
+    /// \
+    /// Every log line will then be rendered once more for this sink, which costs about as much as rendering the line for the
+    /// console. The same formatter can be shared by multiple sinks by cloning the [`Arc`].
+    pub fn with_formatter(mut self, formatter: Arc<dyn Formatter>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+}
+impl Sink for UdpSink {
+    fn log(&self, record: &Record, fatal: bool) {
+        let line = format!(
+            "{}\n",
+            generate_plain_log(record, fatal, self.formatter.as_deref())
+        );
+        // this is fire-and-forget, so the error is ignored on purpose
+        let _ = self.socket.send_to(line.as_bytes(), self.address);
+    }
+
+    fn name(&self) -> String {
+        format!("udp `{}`", self.address)
+    }
+}
@@ -0,0 +1,101 @@
+//! This module provides the [`ReconnectingStream`] used by the sinks sending logs over a connection.
+
+use std::{
+    collections::VecDeque,
+    io::{
+        self,
+        Write,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use crate::dropped;
+
+/// The maximum number of log lines buffered while a connection is down. \
+/// If this limit has been reached, the oldest buffered line will be dropped for every new one.
+pub const STREAM_BUFFER_CAP: usize = 1024;
+
+/// The time to wait before the first attempt to reconnect.
+const MIN_BACKOFF: Duration = Duration::from_millis(100);
+/// The longest time to wait between two attempts to reconnect.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+/// The longest time writing a line may block the logging thread.
+pub(crate) const WRITE_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// A connection which gets reestablished whenever it breaks, buffering the lines which could not be sent in the meantime.
+pub(crate) struct ReconnectingStream<S: Write> {
+    /// The current connection, if it is established.
+    stream: Option<S>,
+    /// The lines waiting to be sent.
+    buffer: VecDeque<String>,
+    /// The time to wait before the next attempt to reconnect.
+    backoff: Duration,
+    /// The earliest time to attempt to reconnect.
+    next_attempt: Option<Instant>,
+}
+impl<S: Write> ReconnectingStream<S> {
+    /// Create a new stream which will connect on the first line sent.
+    pub(crate) fn new() -> Self {
+        Self {
+            stream: None,
+            buffer: VecDeque::new(),
+            backoff: MIN_BACKOFF,
+            next_attempt: None,
+        }
+    }
+
+    /// Buffer the given `line` and send every buffered line, connecting using `connect` if needed.
+    pub(crate) fn send(&mut self, line: String, connect: impl FnOnce() -> io::Result<S>) {
+        if self.buffer.len() == STREAM_BUFFER_CAP {
+            self.buffer.pop_front();
+            dropped::record_drop();
+        }
+        self.buffer.push_back(line);
+        self.flush(connect);
+    }
+
+    /// Send every buffered line, connecting using `connect` if needed.
+    pub(crate) fn flush(&mut self, connect: impl FnOnce() -> io::Result<S>) {
+        if self.stream.is_none() {
+            if self
+                .next_attempt
+                .is_some_and(|next_attempt| Instant::now() < next_attempt)
+            {
+                return;
+            }
+
+            match connect() {
+                Ok(stream) => {
+                    self.stream = Some(stream);
+                    self.backoff = MIN_BACKOFF;
+                    self.next_attempt = None;
+                }
+                Err(_) => {
+                    self.disconnect();
+                    return;
+                }
+            }
+        }
+
+        while let (Some(stream), Some(line)) = (self.stream.as_mut(), self.buffer.front()) {
+            if stream.write_all(line.as_bytes()).is_err() {
+                self.disconnect();
+                return;
+            }
+            self.buffer.pop_front();
+        }
+        if let Some(Err(_)) = self.stream.as_mut().map(Write::flush) {
+            self.disconnect();
+        }
+    }
+
+    /// Drop the current connection and schedule the next attempt to reconnect.
+    fn disconnect(&mut self) {
+        self.stream = None;
+        self.next_attempt = Some(Instant::now() + self.backoff);
+        self.backoff = (self.backoff * 2).min(MAX_BACKOFF);
+    }
+}
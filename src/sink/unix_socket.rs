@@ -0,0 +1,112 @@
+//! This module provides the [`UnixSocketSink`] used to send logs to a Unix domain socket.
+
+use std::{
+    os::unix::net::UnixStream,
+    path::PathBuf,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use log::Record;
+
+use super::{
+    stream::{
+        ReconnectingStream,
+        WRITE_TIMEOUT,
+    },
+    Sink,
+};
+use crate::{
+    format::generate_plain_log,
+    Formatter,
+};
+
+/// A [`Sink`] sending every log line to a Unix domain socket, like the one of a local log collector. \
+/// \
+/// The log lines are rendered just like the ones printed to the console, but without any colors, and are separated by newlines.
+///
+/// # Reconnecting
+///
+/// The socket gets connected once the first log is sent. Whenever the connection is lost or could not be established, the sink
+/// waits before attempting to reconnect, starting at 100 milliseconds and doubling the time after every failed attempt up to 30
+/// seconds. No attempt is made in the background; the sink only reconnects while sending a log.
+///
+/// # Buffering
+///
+/// Log lines which could not be sent are buffered until the connection has been reestablished, so the logging thread never waits
+/// for the socket. At most [`STREAM_BUFFER_CAP`](crate::STREAM_BUFFER_CAP) lines will be buffered; once this limit has been
+/// reached, the oldest line will be dropped for every new one. Writing a line blocks for at most 100 milliseconds.
+///
+/// # Example
+///
+/// ```no_run
+/// use goolog::*;
+/// # fn main() {
+///
+/// init_logger(None, None, None);
+/// add_sink(UnixSocketSink::new("/run/collector.sock"));
+///
+/// info!("Main"; "This will also be sent to the log collector.");
+/// # }
+/// ```
+pub struct UnixSocketSink {
+    /// The path of the socket.
+    path: PathBuf,
+    /// The connection to the socket.
+    stream: Mutex<ReconnectingStream<UnixStream>>,
+    /// The formatter used to render the log lines, if it differs from the one set by [`set_formatter`](crate::set_formatter).
+    formatter: Option<Arc<dyn Formatter>>,
+}
+impl UnixSocketSink {
+    /// Create a sink sending every log line to the socket at the given `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            stream: Mutex::new(ReconnectingStream::new()),
+            formatter: None,
+        }
+    }
+
+    /// Render the log lines sent by this sink using the given `formatter` instead of the one set by
+    /// [`set_formatter`](crate::set_formatter). \
+    /// \
+    /// Every log line will then be rendered once more for this sink, which costs about as much as rendering the line for the
+    /// console. The same formatter can be shared by multiple sinks by cloning the [`Arc`].
+    pub fn with_formatter(mut self, formatter: Arc<dyn Formatter>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Connect to the socket.
+    fn connect(&self) -> std::io::Result<UnixStream> {
+        let stream = UnixStream::connect(&self.path)?;
+        stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+
+        Ok(stream)
+    }
+}
+impl Sink for UnixSocketSink {
+    fn log(&self, record: &Record, fatal: bool) {
+        let line = format!(
+            "{}\n",
+            generate_plain_log(record, fatal, self.formatter.as_deref())
+        );
+        self.stream
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .send(line, || self.connect());
+    }
+
+    fn name(&self) -> String {
+        format!("unix socket `{}`", self.path.display())
+    }
+
+    fn flush(&self) {
+        self.stream
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .flush(|| self.connect());
+    }
+}
@@ -0,0 +1,88 @@
+//! This module provides the [`WriterSink`] used to send logs to any [`Write`] implementation.
+
+use std::{
+    io::Write,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use log::Record;
+
+use super::Sink;
+use crate::{
+    format::generate_plain_log,
+    Formatter,
+};
+
+/// A [`Sink`] writing every log line to the given writer, like a serial port or any other handle to an output device. \
+/// \
+/// The writer gets moved into the sink, so it only needs to be owned instead of being a `&'static` reference. There is no need to
+/// leak it using [`Box::leak`] or to store it in a `static mut`. The log lines are rendered just like the ones printed to the
+/// console, but without any colors, and are separated by newlines.
+///
+/// # Errors
+///
+/// Since logging must not fail, errors returned by the writer are ignored. Every log line is written using a single call to
+/// [`Write::write_all`], so a failed write loses at most that line.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+///
+/// init_logger(None, None, None);
+/// // any owned writer works, like a file or the handle of a serial port
+/// add_sink(WriterSink::new(std::io::sink()));
+///
+/// info!("Main"; "This will also be written to the writer.");
+/// # }
+/// ```
+pub struct WriterSink<W: Write + Send> {
+    /// The writer every log line gets written to.
+    writer: Mutex<W>,
+    /// The formatter used to render the log lines, if it differs from the one set by [`set_formatter`](crate::set_formatter).
+    formatter: Option<Arc<dyn Formatter>>,
+}
+impl<W: Write + Send> WriterSink<W> {
+    /// Create a sink writing every log line to the given `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+            formatter: None,
+        }
+    }
+
+    /// Render the log lines sent by this sink using the given `formatter` instead of the one set by
+    /// [`set_formatter`](crate::set_formatter). \
+    /// \
+    /// Every log line will then be rendered once more for this sink, which costs about as much as rendering the line for the
+    /// console. The same formatter can be shared by multiple sinks by cloning the [`Arc`].
+    pub fn with_formatter(mut self, formatter: Arc<dyn Formatter>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+}
+impl<W: Write + Send> Sink for WriterSink<W> {
+    fn log(&self, record: &Record, fatal: bool) {
+        let line = format!(
+            "{}\n",
+            generate_plain_log(record, fatal, self.formatter.as_deref())
+        );
+        self.writer
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .write_all(line.as_bytes())
+            .ok();
+    }
+
+    fn flush(&self) {
+        self.writer
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .flush()
+            .ok();
+    }
+}
@@ -0,0 +1,113 @@
+//! This module provides the [`Span`] created by the [`span!`](crate::span) macro to give structure to nested operations.
+
+use std::{
+    cell::RefCell,
+    fmt::{
+        Display,
+        Write,
+    },
+    sync::atomic::{
+        AtomicU64,
+        Ordering,
+    },
+    time::Duration,
+};
+
+use crate::{
+    delta,
+    human_duration,
+    indent,
+    IndentGuard,
+};
+
+/// The id of the next span. \
+/// Span ids are unique across all threads of the process, starting at `1`.
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    /// The id and name of every [`Span`] currently alive on this thread, from the outermost to the innermost one.
+    static SPANS: RefCell<Vec<(u64, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A span of work created by the [`span!`](crate::span) macro, logging its exit together with its duration once dropped. \
+/// \
+/// While a span is alive, every message sent on its thread gets indented just like with [`indent`], and the innermost span can be
+/// read using [`current_span`], for example by a custom [`Formatter`](crate::Formatter) or through the `span_name` and
+/// `span_number` fields passed to a [`RecordVisitor`](crate::RecordVisitor). Spans are meant to be dropped in the reverse order
+/// they have been created in, which happens automatically when they are bound to a variable in nested scopes.
+///
+/// # Thread-scoping
+///
+/// Spans belong to the thread which created them: they cannot be sent to another thread, and they only indent and provide
+/// context to the logs sent on their own thread. Work handed to another thread, like a spawned task, needs its own span. Their
+/// ids, however, are unique across all threads, so the logs of the same span can be found even if several threads log at once.
+#[must_use = "the span will be exited immediately if it is not held"]
+pub struct Span {
+    /// The unique id of this span.
+    id: u64,
+    /// The name of this span.
+    name: String,
+    /// The name under which the exit of this span gets logged.
+    target: String,
+    /// The time this span has been entered at, as returned by [`delta::now`].
+    start: u64,
+    /// The indentation of the logs sent within this span. \
+    /// It gets removed before the exit of this span is logged, so the exit lines up with the entry.
+    indent: Option<IndentGuard>,
+}
+impl Span {
+    /// Get the unique id of this span.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Get the name of this span.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+impl Drop for Span {
+    fn drop(&mut self) {
+        self.indent.take();
+        SPANS.with(|spans| spans.borrow_mut().retain(|(id, _)| *id != self.id));
+
+        let elapsed = Duration::from_micros(delta::now().saturating_sub(self.start));
+        crate::info!(
+            self.target.as_str();
+            "{} (span #{}) finished in {}",
+            self.name,
+            self.id,
+            human_duration(elapsed)
+        );
+    }
+}
+
+/// Get the id and name of the innermost [`Span`] alive on the current thread, if any.
+pub fn current_span() -> Option<(u64, String)> {
+    SPANS.with(|spans| spans.borrow().last().cloned())
+}
+
+/// # DO NOT TOUCH THIS FUNCTION
+///
+/// Enter a span with the given `name` and `fields`, logging its entry under the given `target`. This is used by the
+/// [`span!`](crate::span) macro.
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub fn INTERNAL__span(target: &str, name: &str, fields: &[(&str, &dyn Display)]) -> Span {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+
+    let mut details = format!("span #{id}");
+    for (key, value) in fields {
+        write!(details, ", {key}={value}").ok();
+    }
+    crate::info!(target; "{name} ({details}) started");
+
+    SPANS.with(|spans| spans.borrow_mut().push((id, name.to_string())));
+    Span {
+        id,
+        name: name.to_string(),
+        target: target.to_string(),
+        start: delta::now(),
+        indent: Some(indent()),
+    }
+}
@@ -0,0 +1,80 @@
+//! This module provides the [`suppressed_counts`] function used to see how many logs the log level has kept from being printed.
+
+use std::sync::atomic::{
+    AtomicBool,
+    AtomicUsize,
+    Ordering,
+};
+
+use log::Level;
+
+/// The number of logs suppressed by the log level so far, for every level in the order `[error, warn, info, debug, trace]`.
+static SUPPRESSED: [AtomicUsize; 5] = [
+    AtomicUsize::new(0),
+    AtomicUsize::new(0),
+    AtomicUsize::new(0),
+    AtomicUsize::new(0),
+    AtomicUsize::new(0),
+];
+
+/// Whether logs suppressed by the log level get counted.
+static COUNT_SUPPRESSED: AtomicBool = AtomicBool::new(false);
+
+/// Set whether the logs suppressed by the log level should be counted for [`suppressed_counts`]. \
+/// \
+/// Counting is disabled by default, since every counted log increments a counter shared across all threads, which would turn a
+/// disabled [`trace!`](crate::trace) in a hot loop into a contended write. While disabled, a suppressed log only costs the atomic
+/// load checking this setting. Logs suppressed while counting is disabled are not counted afterwards.
+pub fn set_count_suppressed(count: bool) {
+    COUNT_SUPPRESSED.store(count, Ordering::Relaxed);
+}
+
+/// Get whether the logs suppressed by the log level are counted for [`suppressed_counts`].
+pub fn count_suppressed() -> bool {
+    COUNT_SUPPRESSED.load(Ordering::Relaxed)
+}
+
+/// Get the number of logs suppressed by the log level so far. \
+/// \
+/// The numbers are given for every level in the order `[error, warn, info, debug, trace]`. When running at the info level, this
+/// shows how many debug and trace logs are not being printed, which helps to judge whether lowering the level would flood the
+/// output before doing so. The logs are counted across all threads.
+///
+/// Only logs sent using the macros of this crate and suppressed because of their level are counted. Logs of a muted target, logs
+/// dropped by the sampling, and logs sent using the macros of the [log] crate directly are not. Since counting is disabled by
+/// default, every number stays `0` until it has been enabled using [`set_count_suppressed`].
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_count_suppressed(true);
+///
+/// debug!("Main"; "This will not be printed.");
+/// let [_, _, _, debug, _] = suppressed_counts();
+///
+/// info!("Main"; "{debug} debug logs have not been printed so far.");
+/// # }
+/// ```
+pub fn suppressed_counts() -> [usize; 5] {
+    let mut counts = [0; 5];
+    for (count, suppressed) in counts.iter_mut().zip(&SUPPRESSED) {
+        *count = suppressed.load(Ordering::Relaxed);
+    }
+    counts
+}
+
+/// # DO NOT TOUCH THIS FUNCTION
+///
+/// Count a log at the given `level` suppressed by the log level, if enabled using [`set_count_suppressed`]. This is used by the
+/// log macros of this crate.
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[inline]
+pub fn INTERNAL__suppressed(level: Level) {
+    if COUNT_SUPPRESSED.load(Ordering::Relaxed) {
+        SUPPRESSED[level as usize - 1].fetch_add(1, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,215 @@
+//! This module provides the [`set_target_level`] function used to override the log level for single targets.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        OnceLock,
+        RwLock,
+    },
+};
+
+use log::{
+    LevelFilter,
+    Metadata,
+};
+
+use crate::adaptive;
+
+/// The targets whose log level has been overridden, mapped to their log level.
+static TARGET_LEVELS: OnceLock<RwLock<HashMap<String, LevelFilter>>> = OnceLock::new();
+
+/// The log level the goolog logger has been initiated with. \
+/// [`usize::MAX`] means the goolog logger has not been initiated yet.
+static LOG_LEVEL: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// Get the targets whose log level has been overridden.
+fn target_levels_map() -> &'static RwLock<HashMap<String, LevelFilter>> {
+    TARGET_LEVELS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Override the log level of the goolog logger for every log sent under the given `target`. \
+/// \
+/// This can be used to silence a chatty target while keeping the rest of the application at the debug level, or to look into a
+/// single target without flooding the console with the logs of every other one. Use [`clear_target_level`] to restore the log
+/// level of the goolog logger for the target again.
+///
+/// Levels more verbose than the one the goolog logger has been initiated with are supported as well. To let such logs pass the
+/// cheap level check of the log macros, the maximum level of the [log] crate gets raised to the most verbose override, and lowered
+/// again once the override has been cleared. Every log above the log level of the goolog logger then gets checked against the
+/// overrides before it gets formatted.
+///
+/// # Thread-safety
+///
+/// The overrides are shared across all threads and can be changed at any time, before or after
+/// [`init_logger`](crate::init_logger). A change takes effect immediately: every log sent after this function returned, on any
+/// thread, is checked against the new level. Logs being sent at the same time on another thread may still be checked against
+/// the previous one.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// use goolog::log::LevelFilter;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_target_level("Database", LevelFilter::Debug);
+/// set_target_level("Network", LevelFilter::Warn);
+///
+/// // this will be printed even though the log level is `info`
+/// debug!("Database"; "Opening a connection...");
+/// // but this will not
+/// info!("Network"; "Sending 42 bytes...");
+/// # }
+/// ```
+pub fn set_target_level(target: &str, level: LevelFilter) {
+    let mut target_levels = target_levels_map()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    target_levels.insert(target.to_string(), level);
+    update_max_level(&target_levels);
+}
+
+/// Get the log level of the given `target`, if it has been overridden using [`set_target_level`].
+pub fn target_level(target: &str) -> Option<LevelFilter> {
+    target_levels_map()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(target)
+        .copied()
+}
+
+/// Get every target whose log level has been overridden using [`set_target_level`] together with its log level, sorted by the
+/// target. \
+/// \
+/// This is a snapshot: changes made after this function returned are not reflected in the returned list. Together with
+/// [`set_target_level`] and [`clear_target_level`], this can be used to build an endpoint inspecting and adjusting the logging
+/// of a running application.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// use goolog::log::LevelFilter;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_target_level("Network", LevelFilter::Warn);
+/// set_target_level("Database", LevelFilter::Error);
+///
+/// assert_eq!(
+///     target_levels(),
+///     [("Database".to_string(), LevelFilter::Error), ("Network".to_string(), LevelFilter::Warn)]
+/// );
+/// # }
+/// ```
+pub fn target_levels() -> Vec<(String, LevelFilter)> {
+    let mut target_levels: Vec<_> = target_levels_map()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+        .map(|(target, level)| (target.clone(), *level))
+        .collect();
+    target_levels.sort();
+
+    target_levels
+}
+
+/// Remove the log level set for the given `target` using [`set_target_level`], so the log level of the goolog logger applies to
+/// it again. \
+/// \
+/// Just like setting a level, this takes effect immediately for every log sent afterwards, on any thread. Clearing a target
+/// without an override does nothing.
+pub fn clear_target_level(target: &str) {
+    let mut target_levels = target_levels_map()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    target_levels.remove(target);
+    update_max_level(&target_levels);
+}
+
+/// Get the level a logger with the given `log_level` needs to handle for [`set_target_level`] to work.
+pub(crate) fn print_level(log_level: LevelFilter) -> LevelFilter {
+    max_level(
+        &target_levels_map()
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        log_level,
+    )
+}
+
+/// Remember the given `log_level` the goolog logger has been initiated with, so later changes to the overrides can adjust the
+/// maximum level of the [log] crate. This needs to be called once the goolog logger has been set.
+pub(crate) fn init(log_level: LevelFilter) {
+    let target_levels = target_levels_map()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    LOG_LEVEL.store(log_level as usize, Ordering::Relaxed);
+    // the overrides may have changed since the maximum level has been set
+    update_max_level(&target_levels);
+}
+
+/// Set the maximum level of the [log] crate to the one needed for the overrides and the targets set using
+/// [`set_adaptive_debug`](crate::set_adaptive_debug) to work, if the goolog logger has been initiated.
+pub(crate) fn refresh_max_level() {
+    update_max_level(
+        &target_levels_map()
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+    );
+}
+
+/// Get the log level the goolog logger has been initiated with, if it has been initiated.
+fn log_level() -> Option<LevelFilter> {
+    match LOG_LEVEL.load(Ordering::Relaxed) {
+        usize::MAX => None,
+        log_level => Some(
+            LevelFilter::iter()
+                .nth(log_level)
+                .unwrap_or(LevelFilter::Trace),
+        ),
+    }
+}
+
+/// Get the level a logger with the given `log_level` needs to handle for the given `target_levels` to work.
+fn max_level(target_levels: &HashMap<String, LevelFilter>, log_level: LevelFilter) -> LevelFilter {
+    target_levels
+        .values()
+        .max()
+        .map_or(log_level, |max_level| log_level.max(*max_level))
+}
+
+/// Set the maximum level of the [log] crate to the one needed for the given `target_levels` to work, if the goolog logger has
+/// been initiated.
+fn update_max_level(target_levels: &HashMap<String, LevelFilter>) {
+    if let Some(log_level) = log_level() {
+        // debug logs of targets set using `set_adaptive_debug` may be printed above the log level as well
+        log::set_max_level(max_level(target_levels, adaptive::print_level(log_level)));
+    }
+}
+
+/// Get the log level of the given `target`, which is the given `log_level` unless it has been overridden using
+/// [`set_target_level`].
+pub(crate) fn level_for(target: &str, log_level: LevelFilter) -> LevelFilter {
+    target_level(target).unwrap_or(log_level)
+}
+
+/// Check whether a log with the given `metadata` passes the log level set for its target using [`set_target_level`], if any.
+pub(crate) fn allows(metadata: &Metadata) -> bool {
+    match target_level(metadata.target()) {
+        Some(level) => metadata.level() <= level,
+        None => true,
+    }
+}
+
+/// Check whether a log with the given `metadata` reached the goolog logger only because of [`set_target_level`], but gets
+/// suppressed by the log level of its target.
+pub(crate) fn suppresses(metadata: &Metadata) -> bool {
+    let Some(log_level) = log_level() else {
+        return false;
+    };
+
+    !adaptive::allows(metadata, level_for(metadata.target(), log_level))
+}
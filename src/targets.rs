@@ -0,0 +1,69 @@
+//! This module provides the [`set_new_target_hook`] function used to get notified about every new target.
+
+use std::{
+    collections::HashSet,
+    sync::{
+        Mutex,
+        OnceLock,
+        RwLock,
+    },
+};
+
+/// A hook called the first time a log is sent under a target.
+pub type NewTargetHook = &'static (dyn Fn(&str) + Sync);
+
+/// The hook called the first time a log is sent under a target.
+static NEW_TARGET_HOOK: RwLock<Option<NewTargetHook>> = RwLock::new(None);
+/// Every target a log has been sent under.
+static SEEN_TARGETS: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+
+/// Register a `hook` which will be called the first time a log gets sent under a target. \
+/// \
+/// This can be used to build a live list of all active subsystems, for example to populate a configuration UI. Registering a new
+/// hook will replace the previous one. Targets which have already been seen before the hook was registered will not be reported.
+///
+/// # Performance
+///
+/// The hook runs inside the logger, while the log is being sent. It should therefore be fast and must not send any logs itself.
+/// After the first occurrence of a target, only a set lookup is needed.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_new_target_hook(&|target| println!("New target: {target}"));
+///
+/// // this will call the hook
+/// info!("Main"; "Hello World!");
+/// // but this will not
+/// info!("Main"; "Hello again!");
+/// # }
+/// ```
+pub fn set_new_target_hook(hook: NewTargetHook) {
+    *NEW_TARGET_HOOK
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(hook);
+}
+
+/// Remember the given `target` and call the new target hook if it has not been seen before.
+pub(crate) fn track(target: &str) {
+    let new = {
+        let mut seen_targets = SEEN_TARGETS
+            .get_or_init(|| Mutex::new(HashSet::new()))
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        !seen_targets.contains(target) && seen_targets.insert(target.to_string())
+    };
+    if !new {
+        return;
+    }
+
+    let hook = *NEW_TARGET_HOOK
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(hook) = hook {
+        hook(target);
+    }
+}
@@ -0,0 +1,299 @@
+//! This module provides the [`Template`] used to customize the layout of the log lines printed by the goolog logger.
+
+use std::{
+    fmt::{
+        self,
+        Display,
+    },
+    str::FromStr,
+    sync::RwLock,
+};
+
+/// The template used to generate the log lines. \
+/// [`None`] means the default layout will be used.
+static FORMAT: RwLock<Option<Template>> = RwLock::new(None);
+
+/// The names of all placeholders which can be used in a [`Template`].
+const PLACEHOLDER_NAMES: [&str; 7] = [
+    "timestamp",
+    "version",
+    "pid",
+    "delta",
+    "target",
+    "level",
+    "message",
+];
+
+/// A placeholder of a [`Template`] which will be replaced by a part of the log.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Placeholder {
+    /// `{timestamp}`: The date and time of the log. This will be empty if the `timestamp` feature is disabled.
+    Timestamp,
+    /// `{version}`: The version of the application. This will be empty unless enabled using
+    /// [`set_show_version`](crate::set_show_version) together with the [`VersionPlacement::Line`](crate::VersionPlacement::Line).
+    Version,
+    /// `{pid}`: The id of the process which sent the log. This will be empty unless enabled using
+    /// [`set_show_pid`](crate::set_show_pid).
+    Pid,
+    /// `{delta}`: The time passed since the previous log. This will be empty unless enabled using
+    /// [`set_show_delta`](crate::set_show_delta).
+    Delta,
+    /// `{target}`: The name under which the log was sent.
+    Target,
+    /// `{level}`: The level of the log.
+    Level,
+    /// `{message}`: The message of the log.
+    Message,
+}
+impl Placeholder {
+    /// Get the placeholder with the given `name`.
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "timestamp" => Some(Self::Timestamp),
+            "version" => Some(Self::Version),
+            "pid" => Some(Self::Pid),
+            "delta" => Some(Self::Delta),
+            "target" => Some(Self::Target),
+            "level" => Some(Self::Level),
+            "message" => Some(Self::Message),
+            _ => None,
+        }
+    }
+}
+
+/// The already formatted parts of a log which can be inserted into a [`Template`].
+pub(crate) struct LogParts<'a> {
+    /// The date and time of the log.
+    pub(crate) timestamp: &'a str,
+    /// The version of the application.
+    pub(crate) version: &'a str,
+    /// The id of the process which sent the log.
+    pub(crate) pid: &'a str,
+    /// The time passed since the previous log.
+    pub(crate) delta: &'a str,
+    /// The name under which the log was sent.
+    pub(crate) target: &'a str,
+    /// The level of the log.
+    pub(crate) level: &'a str,
+    /// The message of the log.
+    pub(crate) message: &'a str,
+}
+
+/// A part of a [`Template`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Segment {
+    /// Text which will be printed as is.
+    Literal(String),
+    /// A placeholder which will be replaced by a part of the log.
+    Placeholder(Placeholder),
+}
+
+/// An error which can occur while parsing a [`Template`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemplateError {
+    /// The template contains a placeholder with an unknown name.
+    UnknownPlaceholder(String),
+    /// The template contains a `{` which is never closed.
+    UnclosedPlaceholder,
+    /// The template contains a `}` which has not been opened. Use `}}` to print a literal `}`.
+    UnmatchedBrace,
+}
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownPlaceholder(name) => write!(
+                f,
+                "unknown placeholder `{{{name}}}`, expected one of {PLACEHOLDER_NAMES:?}"
+            ),
+            Self::UnclosedPlaceholder => {
+                write!(f, "unclosed placeholder, use `{{{{` to print a `{{`")
+            }
+            Self::UnmatchedBrace => write!(f, "unmatched `}}`, use `}}}}` to print a `}}`"),
+        }
+    }
+}
+impl std::error::Error for TemplateError {}
+
+/// A template describing the layout of the log lines printed by the goolog logger. \
+/// \
+/// A template is a string containing any of the placeholders described by [`Placeholder`], like `{level}`. Use `{{` and `}}` to
+/// print literal braces. To catch typos in placeholder names at compile time, use the [`goolog_format!`](crate::goolog_format)
+/// macro instead of [`Template::parse`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Template {
+    /// The parts of this template.
+    segments: Vec<Segment>,
+}
+impl Template {
+    /// Parse the given `template`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the template contains an unknown placeholder or unmatched braces.
+    pub fn parse(template: &str) -> Result<Self, TemplateError> {
+        let mut segments = vec![];
+        let mut literal = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(char) = chars.next() {
+            match char {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(char) => name.push(char),
+                            None => return Err(TemplateError::UnclosedPlaceholder),
+                        }
+                    }
+                    let placeholder = Placeholder::from_name(&name)
+                        .ok_or(TemplateError::UnknownPlaceholder(name))?;
+
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                    }
+                    segments.push(Segment::Placeholder(placeholder));
+                }
+                '}' => return Err(TemplateError::UnmatchedBrace),
+                char => literal.push(char),
+            }
+        }
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// Render this template using the given `parts` of a log.
+    pub(crate) fn render(&self, parts: &LogParts) -> String {
+        let mut log = String::new();
+        for segment in &self.segments {
+            log.push_str(match segment {
+                Segment::Literal(literal) => literal,
+                Segment::Placeholder(Placeholder::Timestamp) => parts.timestamp,
+                Segment::Placeholder(Placeholder::Version) => parts.version,
+                Segment::Placeholder(Placeholder::Pid) => parts.pid,
+                Segment::Placeholder(Placeholder::Delta) => parts.delta,
+                Segment::Placeholder(Placeholder::Target) => parts.target,
+                Segment::Placeholder(Placeholder::Level) => parts.level,
+                Segment::Placeholder(Placeholder::Message) => parts.message,
+            });
+        }
+
+        log
+    }
+}
+impl FromStr for Template {
+    type Err = TemplateError;
+
+    fn from_str(template: &str) -> Result<Self, Self::Err> {
+        Self::parse(template)
+    }
+}
+
+/// Set the `template` used to generate the log lines. \
+/// \
+/// Passing [`None`] will restore the default layout. Templates known at compile time should be created using the
+/// [`goolog_format!`](crate::goolog_format) macro, while [`Template::parse`] can be used for templates only known at runtime.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_format(Some(goolog_format!("[{level}] {target}: {message}")));
+///
+/// // this will be printed as `[INFO ] Main            : Hello World!`
+/// info!("Main"; "Hello World!");
+/// # }
+/// ```
+pub fn set_format(template: Option<Template>) {
+    *FORMAT
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = template;
+}
+
+/// Get the template used to generate the log lines.
+pub fn format() -> Option<Template> {
+    FORMAT
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// Render the given `parts` of a log using the template set by [`set_format`]. \
+/// Returns [`None`] if no template has been set.
+pub(crate) fn render(parts: &LogParts) -> Option<String> {
+    FORMAT
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .as_ref()
+        .map(|template| template.render(parts))
+}
+
+/// # DO NOT TOUCH THIS FUNCTION
+///
+/// Check whether the given `template` is valid at compile time. This is used by the [`goolog_format!`](crate::goolog_format)
+/// macro.
+///
+/// # Panics
+///
+/// This function will panic if the template is invalid.
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub const fn INTERNAL__validate_template(template: &str) {
+    let template = template.as_bytes();
+    let mut index = 0;
+
+    while index < template.len() {
+        match template[index] {
+            b'{' if index + 1 < template.len() && template[index + 1] == b'{' => index += 2,
+            b'}' if index + 1 < template.len() && template[index + 1] == b'}' => index += 2,
+            b'{' => {
+                let start = index + 1;
+                let mut end = start;
+                while end < template.len() && template[end] != b'}' {
+                    end += 1;
+                }
+                if end == template.len() {
+                    panic!("unclosed placeholder in goolog format, use `{{{{` to print a `{{`");
+                }
+
+                let mut known = false;
+                let mut placeholder = 0;
+                while placeholder < PLACEHOLDER_NAMES.len() {
+                    let name = PLACEHOLDER_NAMES[placeholder].as_bytes();
+                    if name.len() == end - start {
+                        let mut matches = true;
+                        let mut char = 0;
+                        while char < name.len() {
+                            if name[char] != template[start + char] {
+                                matches = false;
+                            }
+                            char += 1;
+                        }
+                        known |= matches;
+                    }
+                    placeholder += 1;
+                }
+                if !known {
+                    panic!("unknown placeholder in goolog format, expected one of `{{timestamp}}`, `{{version}}`, `{{pid}}`, `{{delta}}`, `{{target}}`, `{{level}}` or `{{message}}`");
+                }
+
+                index = end + 1;
+            }
+            b'}' => panic!("unmatched `}}` in goolog format, use `}}}}` to print a `}}`"),
+            _ => index += 1,
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! This module provides the [`init_for_test`] function used to initiate the goolog logger for tests.
+
+use std::sync::{
+    atomic::Ordering,
+    Arc,
+    Mutex,
+    OnceLock,
+};
+
+use log::{
+    Level,
+    LevelFilter,
+};
+
+use crate::{
+    adaptive,
+    builtin_dispatch,
+    format::{
+        self,
+        set_colors_enabled,
+    },
+    level_colors,
+    sink,
+    target_level,
+    transaction,
+    try_apply,
+    InitConfig,
+    INIT_CONFIG,
+};
+
+/// The length of the target column used by [`init_for_test`].
+pub const TEST_TARGET_LENGTH: u32 = 10;
+
+/// A handle to every log line captured by the logger initiated using [`init_for_test`].
+#[derive(Clone, Debug, Default)]
+pub struct Capture {
+    /// The captured log lines and the level of their logs.
+    lines: Arc<Mutex<Vec<(Level, String)>>>,
+}
+impl Capture {
+    /// Get a copy of every log line captured so far.
+    pub fn lines(&self) -> Vec<String> {
+        self.lines
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .map(|(_, line)| line.clone())
+            .collect()
+    }
+
+    /// Check whether any captured log line contains the given `text`.
+    pub fn contains(&self, text: &str) -> bool {
+        self.lines
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .any(|(_, line)| line.contains(text))
+    }
+
+    /// Check whether any log line captured at the given `level` contains the given `text`. \
+    /// \
+    /// Unlike [`Capture::contains`], this checks the level of the log itself instead of the text of its line, so a message
+    /// mentioning another level does not match. See the [`assert_logged!`](crate::assert_logged) macro for an assertion with a
+    /// helpful message.
+    pub fn logged(&self, level: Level, text: &str) -> bool {
+        self.lines
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .any(|(line_level, line)| *line_level == level && line.contains(text))
+    }
+
+    /// Remove every captured log line.
+    pub fn clear(&self) {
+        self.lines
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clear();
+    }
+}
+
+/// Initiate the goolog logger with a predictable layout for tests and capture every log line in memory. \
+/// \
+/// The logger will be initiated with colors disabled, a target column [`TEST_TARGET_LENGTH`] characters long, and the log level
+/// set to trace. Instead of being printed to the console, every log line gets captured synchronously and can be inspected using
+/// the returned [`Capture`]. Apart from that, the logger is the same as the one initiated by [`init_logger`](crate::init_logger):
+/// every filter applies, a [`transaction`](crate::transaction) groups the captured lines, and registered sinks will still
+/// receive every log.
+///
+/// Every test of a test binary can call this function: only the first call initiates the goolog logger, while every later call
+/// returns a handle to the same [`Capture`]. Since the tests of a binary run in parallel by default, the captured log lines are
+/// shared between them, so tests should check for lines unique to them instead of counting every line.
+///
+/// # Panics
+///
+/// This function will panic if another logger, for example one initiated using [`init_logger`](crate::init_logger), has already
+/// been set. Unlike the failure of [`init_logger`](crate::init_logger), this is a regular panic failing only the calling test.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// let capture = init_for_test();
+///
+/// info!("Main"; "Hello World!");
+///
+/// assert!(capture.contains("Main       | INFO  | Hello World!"));
+/// # }
+/// ```
+pub fn init_for_test() -> Capture {
+    /// The capture of the logger initiated by the first call.
+    static CAPTURE: OnceLock<Capture> = OnceLock::new();
+
+    CAPTURE.get_or_init(init_capture).clone()
+}
+
+/// Initiate the goolog logger for [`init_for_test`] and return the [`Capture`] of its log lines.
+fn init_capture() -> Capture {
+    set_colors_enabled(false);
+    format::MAX_NAME_LENGTH.store(TEST_TARGET_LENGTH, Ordering::Relaxed);
+
+    let capture = Capture::default();
+    let lines = capture.lines.clone();
+    // the captured lines take the place of the console, so transactions group them just like console lines
+    let console = transaction::output(Arc::new(move |new_lines| {
+        lines
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .extend_from_slice(new_lines)
+    }));
+
+    let mut init_config = INIT_CONFIG
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    try_apply(
+        builtin_dispatch(
+            LevelFilter::Trace,
+            TEST_TARGET_LENGTH,
+            level_colors(),
+            console,
+        ),
+        LevelFilter::Trace,
+    )
+    .unwrap_or_else(|error| {
+        panic!("Failed to initiate the goolog logger for tests. Error: {error}")
+    });
+    adaptive::init(LevelFilter::Trace);
+    target_level::init(LevelFilter::Trace);
+    sink::add_builtin_sink("test capture", LevelFilter::Trace);
+    *init_config = Some(InitConfig {
+        log_level: LevelFilter::Trace,
+        max_name_length: TEST_TARGET_LENGTH,
+        #[cfg(not(feature = "wasm"))]
+        log_file: None,
+        to_stderr: false,
+    });
+
+    capture
+}
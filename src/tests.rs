@@ -1,31 +1,2154 @@
 #![cfg(test)]
 
-use std::fs;
+use std::{
+    borrow::Cow,
+    fmt::{
+        self,
+        Display,
+    },
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Mutex,
+        MutexGuard,
+        Once,
+    },
+    time::Duration,
+};
+
+use fern::colors::ColoredLevelConfig;
+use log::{
+    Level,
+    Metadata,
+    Record,
+};
 
 use super::*;
+use crate::format::strip_ansi;
+
+/// Initiate the goolog logger once for all tests of this process.
+fn init() {
+    /// Makes sure the logger only gets initiated once.
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| {
+        init_logger(
+            Some(LevelFilter::Trace),
+            None,
+            #[cfg(not(feature = "wasm"))]
+            None,
+        )
+    });
+}
+
+/// Lock the global configuration of the goolog logger for the duration of a test.
+fn lock_config() -> MutexGuard<'static, ()> {
+    /// Makes sure only one test changes the global configuration at a time.
+    static CONFIG: Mutex<()> = Mutex::new(());
+
+    CONFIG
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Generate the log line for a log with the given `level`, `target` and `message`.
+fn render(level: Level, target: &str, message: &str) -> String {
+    let colors = ColoredLevelConfig::new();
+    let arguments = format_args!("{message}");
+    let record = Record::builder()
+        .level(level)
+        .target(target)
+        .args(arguments)
+        .build();
+
+    generate_log(16, &record, colors, record.args())
+}
+
+/// Check whether the active logger would print a log with the given `level` and `target`.
+fn enabled(level: Level, target: &str) -> bool {
+    log::logger().enabled(&Metadata::builder().level(level).target(target).build())
+}
+
+#[test]
+fn mute_target_until_dropped() {
+    init();
+
+    assert!(enabled(Level::Info, "MuteTest"));
+    {
+        let _outer = mute_target("MuteTest");
+        {
+            let _inner = mute_target("MuteTest");
+            assert!(!enabled(Level::Info, "MuteTest"));
+        }
+        assert!(
+            !enabled(Level::Info, "MuteTest"),
+            "The target should stay muted until the last guard is dropped."
+        );
+        assert!(enabled(Level::Info, "OtherMuteTest"));
+    }
+    assert!(enabled(Level::Info, "MuteTest"));
+}
+#[test]
+fn color_scope_whole_line() {
+    let _config = lock_config();
+
+    set_color_scope(ColorScope::WholeLine);
+    let log = render(Level::Error, "Main", "Hello World!");
+    set_color_scope(ColorScope::LevelOnly);
+
+    assert!(log.starts_with("\x1b[31m"), "{log:?}");
+    assert!(log.ends_with("Hello World!\x1b[0m"), "{log:?}");
+    assert_eq!(
+        log.matches("\x1b[0m").count(),
+        1,
+        "The color must only be reset at the end of the line: {log:?}"
+    );
+}
+#[test]
+fn color_scope_level_only() {
+    let _config = lock_config();
+
+    let log = render(Level::Error, "Main", "Hello World!");
+
+    assert!(log.contains("\x1b[31mERROR\x1b[0m"), "{log:?}");
+    assert!(log.ends_with(" | Hello World!"), "{log:?}");
+}
+#[test]
+fn would_log_respects_filters() {
+    init();
+
+    assert!(would_log(Level::Trace, "WouldLogTest"));
+    let _guard = mute_target("WouldLogTest");
+    assert!(!would_log(Level::Error, "WouldLogTest"));
+}
+#[test]
+fn trace_ids_column() {
+    let _config = lock_config();
+
+    set_trace_ids("4bf92f3577b34da6", "00f067aa0ba902b7");
+    let log = render(Level::Info, "Main", "Hello World!");
+    clear_trace_ids();
+
+    assert!(
+        log.ends_with(" | trace_id=4bf92f3577b34da6 span_id=00f067aa0ba902b7 | Hello World!"),
+        "{log:?}"
+    );
+    assert!(render(Level::Info, "Main", "Hello World!").ends_with(" | Hello World!"));
+}
+#[test]
+fn log_if_skips_arguments() {
+    init();
+
+    let mut evaluations = 0;
+    let mut evaluate = || {
+        evaluations += 1;
+        evaluations
+    };
+
+    info_if!(false; "Main"; "Evaluated {} times", evaluate());
+    warn_if!(1 > 2; "Main"; "Evaluated {} times", evaluate());
+    info_if!(true; "Main"; "Evaluated {} times", evaluate());
+
+    assert_eq!(evaluations, 1);
+}
+#[test]
+fn max_message_len_truncates() {
+    let _config = lock_config();
+
+    set_max_message_len(Some(8));
+    // `ä` is two bytes long, so the limit falls right into the middle of one
+    let log = render(Level::Info, "Main", "äääääääääää");
+    set_max_message_len(None);
+
+    assert!(log.ends_with(" | ääää…(truncated 14 bytes)"), "{log:?}");
+    assert!(std::str::from_utf8(log.as_bytes()).is_ok());
+
+    set_max_message_len(Some(7));
+    let log = render(Level::Info, "Main", "äääääääääää");
+    set_max_message_len(None);
+
+    assert!(log.ends_with(" | äää…(truncated 16 bytes)"), "{log:?}");
+}
+#[test]
+fn level_column_width() {
+    let _config = lock_config();
+
+    for level in [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ] {
+        let log = strip_ansi(&render(level, "Main", "Hello World!"));
+        let columns: Vec<&str> = log.split(" | ").collect();
+
+        assert_eq!(columns[columns.len() - 2], format!("{level:5}"), "{log:?}");
+    }
+}
+#[test]
+fn new_target_hook() {
+    /// The targets reported by the hook.
+    static NEW_TARGETS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    init();
+    set_new_target_hook(&|target| {
+        NEW_TARGETS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(target.to_string())
+    });
+
+    info!("NewTargetTest"; "Hello World!");
+    info!("NewTargetTest"; "Hello again!");
+    info!("OtherNewTargetTest"; "Hello World!");
+
+    let new_targets = NEW_TARGETS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let new_targets: Vec<&String> = new_targets
+        .iter()
+        .filter(|target| target.ends_with("NewTargetTest"))
+        .collect();
+    assert_eq!(new_targets, ["NewTargetTest", "OtherNewTargetTest"]);
+}
+#[test]
+fn template_format() {
+    let _config = lock_config();
+
+    set_format(Some(goolog_format!("[{level}] {target}: {{{message}}}")));
+    let log = strip_ansi(&render(Level::Warn, "Main", "Hello World!"));
+    set_format(None);
+
+    assert_eq!(log, "[WARN ] Main            : {Hello World!}");
+    assert_eq!(
+        Template::parse("{levle}"),
+        Err(TemplateError::UnknownPlaceholder("levle".to_string()))
+    );
+    assert_eq!(
+        Template::parse("{level"),
+        Err(TemplateError::UnclosedPlaceholder)
+    );
+    assert_eq!(
+        Template::parse("level}"),
+        Err(TemplateError::UnmatchedBrace)
+    );
+}
+#[test]
+fn recursive_logging() {
+    /// A message argument which logs itself while being formatted.
+    struct Recursive;
+    impl Display for Recursive {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            info!("RecursiveTest"; "Formatting {}", Recursive);
+            write!(f, "recursive")
+        }
+    }
+
+    init();
+
+    info!("RecursiveTest"; "Hello {}!", Recursive);
+}
+#[test]
+fn multiline_raw() {
+    let _config = lock_config();
+
+    let log = render(Level::Info, "Main", "First line\nSecond line");
+
+    assert!(log.ends_with(" | First line\nSecond line"), "{log:?}");
+}
+#[test]
+fn multiline_indent_continuation() {
+    let _config = lock_config();
+
+    set_multiline(MultilineMode::IndentContinuation);
+    let log = strip_ansi(&render(Level::Info, "Main", "First line\nSecond line"));
+    set_multiline(MultilineMode::Raw);
+
+    let lines: Vec<&str> = log.lines().collect();
+    assert_eq!(lines.len(), 2, "{log:?}");
+    assert_eq!(
+        lines[0].find("First line"),
+        lines[1].find("Second line"),
+        "{log:?}"
+    );
+}
+#[test]
+fn multiline_escape_newlines() {
+    let _config = lock_config();
+
+    set_multiline(MultilineMode::EscapeNewlines);
+    let log = render(Level::Info, "Main", "First line\r\nSecond line");
+    set_multiline(MultilineMode::Raw);
+
+    assert!(log.ends_with(" | First line\\r\\nSecond line"), "{log:?}");
+    assert_eq!(log.lines().count(), 1);
+}
+#[test]
+fn pid_column() {
+    let _config = lock_config();
+
+    set_show_pid(true);
+    let log = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    set_show_pid(false);
+
+    assert!(
+        log.contains(&format!("{} | Main ", std::process::id())),
+        "{log:?}"
+    );
+}
+#[test]
+fn message_transform() {
+    let _config = lock_config();
+
+    set_message_transform(&|message| match message.contains("hunter2") {
+        true => Cow::Owned(message.replace("hunter2", "<redacted>")),
+        false => Cow::Borrowed(message),
+    });
+    let log = render(Level::Info, "Main", "password=hunter2");
+
+    assert!(log.ends_with(" | password=<redacted>"), "{log:?}");
+}
+#[test]
+fn target_color_column() {
+    let _config = lock_config();
+
+    set_target_color(Some(Color::BrightBlack));
+    let log = render(Level::Info, "Main", "Hello World!");
+    set_target_color(None);
+
+    assert!(log.contains("\x1b[90mMain            \x1b[0m"), "{log:?}");
+}
+#[test]
+fn auto_target_colors_are_stable() {
+    let _config = lock_config();
+
+    set_auto_target_colors(true);
+    let first = render(Level::Info, "Database", "Hello World!");
+    let second = render(Level::Warn, "Database", "Hello World!");
+    set_colors_enabled(false);
+    let plain = render(Level::Info, "Database", "Hello World!");
+    set_colors_enabled(true);
+    set_target_palette_size(1);
+    let server = render(Level::Info, "Server", "Hello World!");
+    set_target_palette_size(usize::MAX);
+    set_auto_target_colors(false);
+
+    let database = format!(
+        "\x1b[{}mDatabase        \x1b[0m",
+        format::auto_target_color("Database").to_fg_str()
+    );
+    assert!(first.contains(&database), "{first:?}");
+    assert!(second.contains(&database), "{second:?}");
+    assert!(!plain.contains('\x1b'), "{plain:?}");
+    assert!(
+        server.contains("\x1b[36mServer          \x1b[0m"),
+        "{server:?}"
+    );
+    assert_eq!(target_palette_size(), 12);
+}
+#[test]
+fn sink_level() {
+    /// A sink counting the logs it received.
+    struct CountingSink(&'static AtomicUsize);
+    impl Sink for CountingSink {
+        fn log(&self, record: &Record, _fatal: bool) {
+            if record.target() == "SinkLevelTest" {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        fn name(&self) -> String {
+            "counting sink".to_string()
+        }
+
+        fn level(&self) -> LevelFilter {
+            LevelFilter::Warn
+        }
+    }
+    /// The number of logs received by the sink.
+    static RECEIVED: AtomicUsize = AtomicUsize::new(0);
+
+    init();
+    add_sink(CountingSink(&RECEIVED));
+
+    info!("SinkLevelTest"; "Hello World!");
+    warn!("SinkLevelTest"; "Hello World!");
+
+    assert_eq!(RECEIVED.load(Ordering::Relaxed), 1);
+    assert!(sinks().contains(&SinkInfo {
+        name: "counting sink".to_string(),
+        level: LevelFilter::Warn
+    }));
+}
+#[cfg(feature = "timestamp")]
+#[test]
+fn custom_timestamp_format() {
+    let _config = lock_config();
+
+    assert!(set_timestamp_format(Some("%G-W%V-%u %Q")).is_err());
+    set_timestamp_format(Some("%G-W%V-%u")).unwrap_or_else(|error| panic!("{error}"));
+    let log = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    set_timestamp_format(None).unwrap_or_else(|error| panic!("{error}"));
+
+    let timestamp = log.split(" | ").next().unwrap_or_default();
+    assert_eq!(
+        timestamp,
+        chrono::Local::now().format("%G-W%V-%u").to_string()
+    );
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn rotation_size_or_daily() {
+    use crate::rotation::{
+        to_date,
+        today,
+        RotatingFile,
+    };
+
+    let _config = lock_config();
+    let logs_dir = std::path::PathBuf::from("rotation_logs");
+    std::fs::create_dir_all(&logs_dir).unwrap_or_else(|error| panic!("{error}"));
+
+    assert_eq!(to_date(19506), "2023-05-29");
+    assert_eq!(to_date(19782), "2024-02-29");
+
+    set_rotation_policy(Some(RotationPolicy::Any(vec![
+        RotationPolicy::Size { max_bytes: 40 },
+        RotationPolicy::Daily,
+    ])));
+    let mut file =
+        RotatingFile::open(&logs_dir.join("main.log")).unwrap_or_else(|error| panic!("{error}"));
+    let today = today();
+    for (line, day) in [("first", today), ("second", today), ("third", today + 1)] {
+        file.write_line_on(&format_args!("{line:20}"), day)
+            .unwrap_or_else(|error| panic!("{error}"));
+    }
+    set_rotation_policy(None);
+
+    let date = to_date(today);
+    let mut files = std::fs::read_dir(&logs_dir)
+        .unwrap_or_else(|error| panic!("{error}"))
+        .filter_map(|entry| entry.ok())
+        .map(|entry| {
+            let content = std::fs::read_to_string(entry.path()).unwrap_or_default();
+            (entry.file_name().to_string_lossy().into_owned(), content)
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    std::fs::remove_dir_all(&logs_dir).unwrap_or_else(|error| panic!("{error}"));
+
+    assert_eq!(
+        files,
+        [
+            (format!("main.{date}.0.log"), format!("{:20}\n", "first")),
+            (format!("main.{date}.1.log"), format!("{:20}\n", "second")),
+            ("main.log".to_string(), format!("{:20}\n", "third")),
+        ]
+    );
+}
+
+#[test]
+fn max_name_length_precedence() {
+    assert_eq!(
+        resolve_max_name_length(Some(8), Some("4".into())),
+        (8, None)
+    );
+    assert_eq!(resolve_max_name_length(None, Some(" 4 ".into())), (4, None));
+    assert_eq!(resolve_max_name_length(None, None), (16, None));
+    assert_eq!(
+        resolve_max_name_length(None, Some("four".into())),
+        (16, Some("four".into()))
+    );
+}
+
+#[test]
+fn error_chain() {
+    #[derive(Debug)]
+    struct Failed(&'static str, Option<Box<Failed>>);
+    impl Display for Failed {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+    impl std::error::Error for Failed {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            self.1.as_deref().map(|error| error as _)
+        }
+    }
+
+    let error = Failed(
+        "failed to load the config",
+        Some(Box::new(Failed(
+            "failed to read `config.toml`",
+            Some(Box::new(Failed("permission denied", None))),
+        ))),
+    );
+    assert_eq!(
+        ErrorChain::new(&error).to_string(),
+        "failed to load the config: caused by: failed to read `config.toml`: caused by: permission denied"
+    );
+
+    init();
+    error_chain!("ErrorChainTest"; error);
+}
+
+#[test]
+fn compact_layout() {
+    let _config = lock_config();
+
+    set_layout_preset(LayoutPreset::Compact);
+    let log = strip_ansi(&render(Level::Warn, "Main", "Hello World!"));
+    set_layout_preset(LayoutPreset::Full);
+
+    assert_eq!(log, "W Main             Hello World!");
+}
+
+#[test]
+fn guard_flushes_sinks() {
+    /// A sink counting how often it has been flushed.
+    struct FlushingSink(&'static AtomicUsize);
+    impl Sink for FlushingSink {
+        fn log(&self, _record: &Record, _fatal: bool) {}
+
+        fn flush(&self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+    static FLUSHED: AtomicUsize = AtomicUsize::new(0);
+
+    init();
+    add_sink(FlushingSink(&FLUSHED));
+
+    drop(guard::LoggerGuard::new());
+    assert_eq!(FLUSHED.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn target_length_per_level() {
+    let _config = lock_config();
+
+    set_target_length_for(Level::Error, Some(0));
+    let error = strip_ansi(&render(
+        Level::Error,
+        "MySuperAwesomeMCManageClient",
+        "Hello World!",
+    ));
+    let info = strip_ansi(&render(
+        Level::Info,
+        "MySuperAwesomeMCManageClient",
+        "Hello World!",
+    ));
+    set_target_length_for(Level::Error, None);
+
+    assert!(error.ends_with("MySuperAwesomeMCManageClient | ERROR | Hello World!"));
+    assert!(info.ends_with("MySuperAwesomeMC | INFO  | Hello World!"));
+    assert_eq!(target_length_for(Level::Error), None);
+}
+
+#[test]
+fn dbg_tap_returns_value() {
+    init();
+
+    let values = dbg_tap!("DbgTapTest"; vec![1, 2, 3]);
+    assert_eq!(dbg_tap!("DbgTapTest"; values.len() * 2), 6);
+}
+
+#[test]
+fn column_order_validation() {
+    let _config = lock_config();
+
+    assert_eq!(
+        set_column_order(Some(&[Column::Level])),
+        Err(ColumnOrderError::MissingMessage)
+    );
+    assert_eq!(
+        set_column_order(Some(&[Column::Message, Column::Level])),
+        Err(ColumnOrderError::MessageNotLast)
+    );
+    assert_eq!(
+        set_column_order(Some(&[Column::Level, Column::Level, Column::Message])),
+        Err(ColumnOrderError::DuplicateColumn(Column::Level))
+    );
+
+    set_column_order(Some(&[Column::Level, Column::Target, Column::Message]))
+        .unwrap_or_else(|error| panic!("{error}"));
+    let log = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    set_column_order(None).unwrap_or_else(|error| panic!("{error}"));
+
+    assert_eq!(log, "INFO  | Main             | Hello World!");
+}
+
+#[test]
+fn target_length_boundaries() {
+    use crate::format::to_fixed_size;
+
+    // a length of 0 removes the limit
+    assert_eq!(to_fixed_size(0, "Main"), "Main");
+    assert_eq!(to_fixed_size(4, "Main"), "Main");
+    assert_eq!(to_fixed_size(2, "Main"), "Ma");
+    assert_eq!(to_fixed_size(6, "Main"), "Main  ");
+    assert_eq!(to_fixed_size(3, ""), "   ");
+    assert_eq!(to_fixed_size(0, ""), "");
+    // the length is counted in characters, not bytes
+    assert_eq!(to_fixed_size(2, "Ünïcode"), "Ün");
+    assert_eq!(to_fixed_size(4, "Ün"), "Ün  ");
+}
+
+#[test]
+fn delta_column() {
+    let _config = lock_config();
+
+    set_show_delta(true);
+    delta::track();
+    let log = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    set_show_delta(false);
+
+    let columns = log.split(" | ").collect::<Vec<_>>();
+    let delta = columns[columns.len() - 4];
+    assert!(
+        delta.starts_with('+') && delta.trim_end().ends_with("ms"),
+        "{log}"
+    );
+    assert_eq!(delta.len(), 8);
+}
+
+#[test]
+fn custom_formatter() {
+    /// A formatter printing the target and the message separated by an arrow.
+    struct ArrowFormatter;
+    impl Formatter for ArrowFormatter {
+        fn format(
+            &self,
+            out: &mut dyn fmt::Write,
+            record: &Record,
+            ctx: &FormatCtx,
+        ) -> fmt::Result {
+            write!(out, "{} -> {}", record.target(), ctx.message())?;
+            if ctx.fatal() {
+                write!(out, " (fatal)")?;
+            }
+            Ok(())
+        }
+    }
+
+    let _config = lock_config();
+
+    set_formatter(Some(Box::new(ArrowFormatter)));
+    let log = render(Level::Error, "Main", "$goolog:fatal=Hello World!");
+    set_formatter(None);
 
+    assert_eq!(log, "Main -> Hello World! (fatal)");
+}
+
+#[cfg(unix)]
 #[test]
-fn no_log_file_set() {
-    init_logger(None, None, None);
+fn unix_socket_sink() {
+    use std::{
+        io::{
+            BufRead,
+            BufReader,
+        },
+        os::unix::net::UnixListener,
+    };
+
+    let _config = lock_config();
+    let logs_dir = std::path::PathBuf::from("unix_socket_logs");
+    std::fs::create_dir_all(&logs_dir).unwrap_or_else(|error| panic!("{error}"));
+    let path = logs_dir.join("collector.sock");
+    let sink = UnixSocketSink::new(&path);
+
+    // the socket does not exist yet, so this log has to be buffered
+    sink.log(
+        &Record::builder()
+            .level(Level::Info)
+            .target("Main")
+            .args(format_args!("First"))
+            .build(),
+        false,
+    );
+    let listener = UnixListener::bind(&path).unwrap_or_else(|error| panic!("{error}"));
+    // wait for the first attempt to reconnect
+    std::thread::sleep(std::time::Duration::from_millis(150));
+    sink.log(
+        &Record::builder()
+            .level(Level::Error)
+            .target("Main")
+            .args(format_args!("Second"))
+            .build(),
+        true,
+    );
 
-    info!("Main"; "Hello World!");
-    info!("MySuperAwesomeMCManageClient"; "Hello World!");
+    let (stream, _) = listener.accept().unwrap_or_else(|error| panic!("{error}"));
+    let lines = BufReader::new(stream)
+        .lines()
+        .take(2)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap_or_else(|error| panic!("{error}"));
+    std::fs::remove_dir_all(&logs_dir).unwrap_or_else(|error| panic!("{error}"));
+
+    assert!(
+        lines[0].ends_with("Main             | INFO  | First"),
+        "{lines:?}"
+    );
+    assert!(
+        lines[1].ends_with("Main             | FATAL | Second"),
+        "{lines:?}"
+    );
+    assert_eq!(sink.name(), format!("unix socket `{}`", path.display()));
 }
+
+#[cfg(feature = "net")]
 #[test]
-fn log_file_set() {
-    let mut log_file_path = PathBuf::from("logs/main.log");
+fn net_sinks() {
+    use std::{
+        io::{
+            BufRead,
+            BufReader,
+        },
+        net::{
+            TcpListener,
+            UdpSocket,
+        },
+    };
 
-    init_logger(None, None, Some(log_file_path.clone()));
+    init();
+    let tcp_listener = TcpListener::bind("127.0.0.1:0").unwrap_or_else(|error| panic!("{error}"));
+    let tcp_address = tcp_listener
+        .local_addr()
+        .unwrap_or_else(|error| panic!("{error}"));
+    let udp_listener = UdpSocket::bind("127.0.0.1:0").unwrap_or_else(|error| panic!("{error}"));
+    let udp_address = udp_listener
+        .local_addr()
+        .unwrap_or_else(|error| panic!("{error}"));
 
-    info!("Main"; "Hello World!");
-    info!("MySuperAwesomeMCManageClient"; "Hello World!");
+    let tcp_sink = TcpSink::new(tcp_address.to_string()).unwrap_or_else(|error| panic!("{error}"));
+    let udp_sink = UdpSink::new(udp_address).unwrap_or_else(|error| panic!("{error}"));
+    for sink in [&tcp_sink as &dyn Sink, &udp_sink] {
+        sink.log(
+            &Record::builder()
+                .level(Level::Info)
+                .target("Main")
+                .args(format_args!("Hello World!"))
+                .build(),
+            false,
+        );
+    }
 
+    let (tcp_stream, _) = tcp_listener
+        .accept()
+        .unwrap_or_else(|error| panic!("{error}"));
+    let mut tcp_line = String::new();
+    BufReader::new(tcp_stream)
+        .read_line(&mut tcp_line)
+        .unwrap_or_else(|error| panic!("{error}"));
+    let mut udp_datagram = [0; 1024];
+    let length = udp_listener
+        .recv(&mut udp_datagram)
+        .unwrap_or_else(|error| panic!("{error}"));
+    let udp_line = String::from_utf8_lossy(&udp_datagram[..length]);
+
+    assert!(
+        tcp_line.ends_with("Main             | INFO  | Hello World!\n"),
+        "{tcp_line}"
+    );
     assert!(
-        log_file_path.exists(),
-        "The log file should have been created by now."
+        udp_line.ends_with("Main             | INFO  | Hello World!\n"),
+        "{udp_line}"
+    );
+    assert_eq!(tcp_sink.name(), format!("tcp `{tcp_address}`"));
+}
+
+#[test]
+fn parse_level_strings() {
+    use crate::filter::parse_level;
+
+    assert_eq!(parse_level("debug"), Ok(LevelFilter::Debug));
+    assert_eq!(parse_level("DEBUG"), Ok(LevelFilter::Debug));
+    assert_eq!(parse_level(" Off "), Ok(LevelFilter::Off));
+    assert_eq!(parse_level("0"), Ok(LevelFilter::Error));
+    assert_eq!(parse_level(" 3"), Ok(LevelFilter::Debug));
+    assert_eq!(parse_level("4"), Ok(LevelFilter::Trace));
+    assert!(parse_level("5").is_err());
+    assert!(parse_level("-1").is_err());
+    assert_eq!(
+        parse_level("verbose").map_err(|error| error.to_string()),
+        Err("unknown log level `verbose`, expected one of `off`, `error`, `warn`, `info`, `debug`, `trace` or a number from 0 to 4".to_string())
+    );
+    assert_eq!(level_from_verbosity(2), LevelFilter::Info);
+    assert_eq!(level_from_verbosity(200), LevelFilter::Trace);
+}
+
+#[test]
+fn raised_levels() {
+    use crate::filter::raise_level;
+
+    assert_eq!(raise_level(LevelFilter::Info, 0), LevelFilter::Info);
+    assert_eq!(raise_level(LevelFilter::Info, 1), LevelFilter::Debug);
+    assert_eq!(raise_level(LevelFilter::Warn, 2), LevelFilter::Debug);
+    assert_eq!(raise_level(LevelFilter::Off, 1), LevelFilter::Error);
+    assert_eq!(raise_level(LevelFilter::Info, u8::MAX), LevelFilter::Trace);
+}
+
+#[cfg(unix)]
+#[test]
+fn dropped_lines() {
+    use crate::sink::stream::ReconnectingStream;
+
+    static NOTIFIED: AtomicUsize = AtomicUsize::new(0);
+
+    let _config = lock_config();
+    set_on_drop(&|dropped| NOTIFIED.store(dropped, Ordering::Relaxed));
+
+    let dropped = dropped_count();
+    let mut stream = ReconnectingStream::<Vec<u8>>::new();
+    for _ in 0..STREAM_BUFFER_CAP + 2 {
+        stream.send("Hello World!\n".to_string(), || {
+            Err(std::io::ErrorKind::ConnectionRefused.into())
+        });
+    }
+
+    assert_eq!(dropped_count(), dropped + 2);
+    assert_eq!(NOTIFIED.load(Ordering::Relaxed), dropped + 2);
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn rotation_max_total_size() {
+    use crate::rotation::{
+        to_date,
+        today,
+        RotatingFile,
+    };
+
+    let _config = lock_config();
+    let logs_dir = std::path::PathBuf::from("rotation_total_logs");
+    std::fs::create_dir_all(&logs_dir).unwrap_or_else(|error| panic!("{error}"));
+    for file_name in [
+        "main.2023-01-01.0.log",
+        "main.2023-01-02.0.log",
+        "other.txt",
+    ] {
+        std::fs::write(logs_dir.join(file_name), [b'x'; 100])
+            .unwrap_or_else(|error| panic!("{error}"));
+    }
+    // files sharing the stem of the log file but not written by the rotation must survive, even if they are the oldest ones
+    for file_name in ["main.other", "main.2023-01-01.log", "main.lock.log"] {
+        std::fs::File::create(logs_dir.join(file_name))
+            .and_then(|mut file| {
+                std::io::Write::write_all(&mut file, &[b'x'; 100])?;
+                file.set_modified(std::time::UNIX_EPOCH)
+            })
+            .unwrap_or_else(|error| panic!("{error}"));
+    }
+
+    set_rotation_policy(Some(RotationPolicy::Size { max_bytes: 30 }));
+    set_max_total_size(Some(150));
+    let mut file =
+        RotatingFile::open(&logs_dir.join("main.log")).unwrap_or_else(|error| panic!("{error}"));
+    for line in ["first", "second"] {
+        file.write_line(&format_args!("{line:20}"))
+            .unwrap_or_else(|error| panic!("{error}"));
+    }
+    set_rotation_policy(None);
+    set_max_total_size(None);
+
+    let mut files = std::fs::read_dir(&logs_dir)
+        .unwrap_or_else(|error| panic!("{error}"))
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    files.sort();
+    std::fs::remove_dir_all(&logs_dir).unwrap_or_else(|error| panic!("{error}"));
+
+    assert_eq!(
+        files,
+        [
+            "main.2023-01-01.log".to_string(),
+            "main.2023-01-02.0.log".to_string(),
+            format!("main.{}.0.log", to_date(today())),
+            "main.lock.log".to_string(),
+            "main.log".to_string(),
+            "main.other".to_string(),
+            "other.txt".to_string(),
+        ]
+    );
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn rotation_pruning_is_best_effort() {
+    use crate::rotation::RotatingFile;
+
+    let _config = lock_config();
+    let logs_dir = std::path::PathBuf::from("rotation_prune_logs");
+    std::fs::create_dir_all(&logs_dir).unwrap_or_else(|error| panic!("{error}"));
+    std::fs::write(logs_dir.join("main.2023-01-02.0.log"), [b'x'; 100])
+        .unwrap_or_else(|error| panic!("{error}"));
+
+    let mut file =
+        RotatingFile::open(&logs_dir.join("main.log")).unwrap_or_else(|error| panic!("{error}"));
+    // a rotated file deleted by another process in the meantime cannot be removed, but must not stop the pruning
+    file.remove_oldest(
+        vec![
+            (
+                std::time::UNIX_EPOCH,
+                logs_dir.join("main.2023-01-01.0.log"),
+                100,
+            ),
+            (
+                std::time::SystemTime::now(),
+                logs_dir.join("main.2023-01-02.0.log"),
+                100,
+            ),
+        ],
+        0,
+    );
+    let pruned = !logs_dir.join("main.2023-01-02.0.log").exists();
+
+    set_rotation_policy(Some(RotationPolicy::Size { max_bytes: 30 }));
+    set_max_total_size(Some(0));
+    for line in ["first", "second"] {
+        file.write_line(&format_args!("{line:20}"))
+            .unwrap_or_else(|error| panic!("{error}"));
+    }
+    set_rotation_policy(None);
+    set_max_total_size(None);
+    drop(file);
+
+    let log = std::fs::read_to_string(logs_dir.join("main.log"));
+    std::fs::remove_dir_all(&logs_dir).unwrap_or_else(|error| panic!("{error}"));
+
+    assert!(pruned);
+    // the line triggering the rotation is written even though pruning could not get the size down to the limit
+    assert!(log
+        .unwrap_or_else(|error| panic!("{error}"))
+        .contains("second"));
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn file_header_on_new_files() {
+    use crate::rotation::{
+        to_date,
+        today,
+        RotatingFile,
+    };
+
+    let _config = lock_config();
+    let logs_dir = std::path::PathBuf::from("header_logs");
+    std::fs::create_dir_all(&logs_dir).unwrap_or_else(|error| panic!("{error}"));
+
+    set_file_header(Some("# header".to_string()));
+    for line in ["first", "second"] {
+        // reopening the non-empty file must not write the header again
+        let mut file = RotatingFile::open(&logs_dir.join("main.log"))
+            .unwrap_or_else(|error| panic!("{error}"));
+        file.write_line(&format_args!("{line}"))
+            .unwrap_or_else(|error| panic!("{error}"));
+    }
+    set_rotation_policy(Some(RotationPolicy::Daily));
+    let mut file =
+        RotatingFile::open(&logs_dir.join("main.log")).unwrap_or_else(|error| panic!("{error}"));
+    file.write_line_on(&format_args!("third"), today() + 1)
+        .unwrap_or_else(|error| panic!("{error}"));
+    set_rotation_policy(None);
+    set_file_header(None);
+
+    let read = |file_name: &str| {
+        std::fs::read_to_string(logs_dir.join(file_name)).unwrap_or_else(|error| panic!("{error}"))
+    };
+    let rotated = read(&format!("main.{}.0.log", to_date(today())));
+    let current = read("main.log");
+    std::fs::remove_dir_all(&logs_dir).unwrap_or_else(|error| panic!("{error}"));
+
+    assert_eq!(rotated, "# header\nfirst\nsecond\n");
+    assert_eq!(current, "# header\nthird\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn sink_formatter() {
+    use std::{
+        io::Read,
+        os::unix::net::UnixListener,
+        sync::Arc,
+    };
+
+    /// A formatter printing the target and the message separated by an arrow.
+    struct ArrowFormatter;
+    impl Formatter for ArrowFormatter {
+        fn format(
+            &self,
+            out: &mut dyn fmt::Write,
+            record: &Record,
+            ctx: &FormatCtx,
+        ) -> fmt::Result {
+            write!(out, "{} -> {}", record.target(), ctx.message())
+        }
+    }
+
+    let logs_dir = std::path::PathBuf::from("sink_formatter_logs");
+    std::fs::create_dir_all(&logs_dir).unwrap_or_else(|error| panic!("{error}"));
+    let path = logs_dir.join("collector.sock");
+    let listener = UnixListener::bind(&path).unwrap_or_else(|error| panic!("{error}"));
+
+    let sink = UnixSocketSink::new(&path).with_formatter(Arc::new(ArrowFormatter));
+    sink.log(
+        &Record::builder()
+            .level(Level::Info)
+            .target("Main")
+            .args(format_args!("Hello World!"))
+            .build(),
+        false,
+    );
+    drop(sink);
+
+    let (mut stream, _) = listener.accept().unwrap_or_else(|error| panic!("{error}"));
+    let mut lines = String::new();
+    stream
+        .read_to_string(&mut lines)
+        .unwrap_or_else(|error| panic!("{error}"));
+    std::fs::remove_dir_all(&logs_dir).unwrap_or_else(|error| panic!("{error}"));
+
+    assert_eq!(lines, "Main -> Hello World!\n");
+}
+
+#[test]
+fn explicit_location() {
+    use std::panic::Location;
+
+    /// A sink storing the location of the last log it received.
+    struct LocationSink(&'static Mutex<Option<(String, u32)>>);
+    impl Sink for LocationSink {
+        fn log(&self, record: &Record, _fatal: bool) {
+            if record.target() == "LocationTest" {
+                *self
+                    .0
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner()) = record
+                    .file()
+                    .zip(record.line())
+                    .map(|(file, line)| (file.to_string(), line));
+            }
+        }
+    }
+    static LOCATION: Mutex<Option<(String, u32)>> = Mutex::new(None);
+
+    #[track_caller]
+    fn helper() {
+        log_at!(Location::caller(); Level::Info; "LocationTest"; "Hello World!");
+    }
+
+    init();
+    add_sink(LocationSink(&LOCATION));
+
+    let line = line!() + 1;
+    helper();
+
+    assert_eq!(
+        *LOCATION
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        Some((file!().to_string(), line))
+    );
+}
+
+#[test]
+fn debug_var_names() {
+    /// A sink storing the message of the last log it received.
+    struct MessageSink(&'static Mutex<String>);
+    impl Sink for MessageSink {
+        fn log(&self, record: &Record, _fatal: bool) {
+            if record.target() == "DebugVarTest" {
+                *self
+                    .0
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner()) = record.args().to_string();
+            }
+        }
+    }
+    static MESSAGE: Mutex<String> = Mutex::new(String::new());
+
+    init();
+    add_sink(MessageSink(&MESSAGE));
+
+    let (x, y) = (1, "two");
+    debug_var!("DebugVarTest"; x, y);
+
+    #[cfg(debug_assertions)]
+    assert_eq!(
+        *MESSAGE
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        "x = 1, y = \"two\""
+    );
+}
+
+#[cfg(feature = "anyhow")]
+#[test]
+fn log_anyhow_chain() {
+    /// A sink storing the message of the first log it received.
+    struct MessageSink(&'static Mutex<String>);
+    impl Sink for MessageSink {
+        fn log(&self, record: &Record, _fatal: bool) {
+            let mut message = self
+                .0
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            if record.target() == "AnyhowTest" && message.is_empty() {
+                *message = record.args().to_string();
+            }
+        }
+    }
+    static MESSAGE: Mutex<String> = Mutex::new(String::new());
+
+    init();
+    add_sink(MessageSink(&MESSAGE));
+
+    let error = anyhow::anyhow!("permission denied").context("failed to read `config.toml`");
+    log_anyhow!(Level::Error; "AnyhowTest"; &error);
+
+    assert_eq!(
+        *MESSAGE
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        "failed to read `config.toml`: permission denied"
+    );
+}
+
+#[test]
+fn nested_capture() {
+    init();
+
+    let mut inner = vec![];
+    let outer = capture(|| {
+        info!("CaptureTest"; "outer");
+        inner = capture(|| {
+            info!("CaptureTest"; "inner");
+        });
+        std::thread::spawn(|| {
+            info!("CaptureTest"; "other thread");
+        })
+        .join()
+        .unwrap_or_else(|_| panic!("the thread panicked"));
+    });
+
+    assert_eq!(inner.len(), 1, "{inner:?}");
+    assert!(inner[0].ends_with("| INFO  | inner"), "{inner:?}");
+    assert_eq!(outer.len(), 2, "{outer:?}");
+    assert!(outer[0].ends_with("| INFO  | outer"), "{outer:?}");
+    assert_eq!(outer[1], inner[0]);
+    assert!(capture(|| {}).is_empty());
+}
+
+#[test]
+fn panicking_display() {
+    /// A value whose `Display` implementation always panics.
+    struct Panicking;
+    impl Display for Panicking {
+        fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            panic!("this value cannot be displayed")
+        }
+    }
+
+    init();
+
+    let lines = capture(|| {
+        info!("PanicTest"; "The value is {}", Panicking);
+        info!("PanicTest"; "Still logging");
+    });
+
+    assert_eq!(lines.len(), 2, "{lines:?}");
+    assert!(
+        lines[0].ends_with("| INFO  | <formatting panicked>"),
+        "{lines:?}"
+    );
+    assert!(lines[1].ends_with("| INFO  | Still logging"), "{lines:?}");
+}
+
+#[test]
+fn sampling_keeps_every_nth_log() {
+    let _config = lock_config();
+    init();
+
+    set_sampling("SamplingTest", Some(3));
+    let lines = capture(|| {
+        for index in 0..7 {
+            info!("SamplingTest"; "log {index}");
+        }
+    });
+    set_sampling("SamplingTest", None);
+
+    let messages = lines
+        .iter()
+        .map(|line| line.rsplit(" | ").next().unwrap_or_default())
+        .collect::<Vec<_>>();
+    assert_eq!(messages, ["log 0", "log 3", "log 6"]);
+    assert_eq!(sampling("SamplingTest"), None);
+}
+
+#[test]
+fn indented_messages() {
+    let _config = lock_config();
+
+    let outer = indent();
+    let inner = indent();
+    let nested = strip_ansi(&render(Level::Info, "Main", "nested"));
+    set_multiline(MultilineMode::IndentContinuation);
+    let multiline = strip_ansi(&render(Level::Info, "Main", "first\nsecond"));
+    set_multiline(MultilineMode::Raw);
+    drop(inner);
+    let single = strip_ansi(&render(Level::Info, "Main", "single"));
+    drop(outer);
+
+    assert!(nested.ends_with("| INFO  |     nested"), "{nested:?}");
+    assert!(single.ends_with("| INFO  |   single"), "{single:?}");
+    let (first, second) = multiline.split_once('\n').unwrap_or_default();
+    assert!(first.ends_with("| INFO  |     first"), "{multiline:?}");
+    assert_eq!(second.trim_start(), "second");
+    assert_eq!(second.len() - "second".len(), first.len() - "first".len());
+    assert_eq!(indent_depth(), 0);
+}
+
+#[test]
+fn level_icons_are_aligned() {
+    let _config = lock_config();
+
+    set_level_icons(Some(["❌", "⚠️", "i", "🔧", "🔍"]));
+    let error = strip_ansi(&render(Level::Error, "Main", "Hello World!"));
+    let info = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    set_level_icons(None);
+
+    assert!(error.ends_with("| ❌ ERROR | Hello World!"), "{error:?}");
+    assert!(info.ends_with("| i  INFO  | Hello World!"), "{info:?}");
+    assert_eq!(format::display_width("⚠️"), 2);
+    assert_eq!(format::display_width("INFO"), 4);
+}
+
+#[test]
+fn effective_config_snapshot() {
+    let _config = lock_config();
+
+    set_show_pid(true);
+    set_target_length_for(Level::Debug, Some(4));
+    let config = effective_config();
+    set_target_length_for(Level::Debug, None);
+    set_show_pid(false);
+
+    assert!(config.show_pid);
+    assert_eq!(config.target_lengths, [None, None, None, Some(4), None]);
+    assert_eq!(config.column_order, column_order());
+    assert!(!config.custom_formatter);
+    assert!(!effective_config().show_pid);
+}
+
+#[cfg(not(feature = "fatal-exit"))]
+#[test]
+#[should_panic = "a fatal error has been logged"]
+fn fatal_panics_without_exit() {
+    init();
+
+    fatal!("FatalTest"; "Something went terribly wrong!");
+}
+
+#[cfg(feature = "timestamp")]
+#[test]
+fn timestamp_format_per_sink() {
+    /// A sink storing the last log line of the timestamp test, rendered using its own formatter.
+    struct FormattingSink(&'static Mutex<String>, TimestampFormatter);
+    impl Sink for FormattingSink {
+        fn log(&self, record: &Record, fatal: bool) {
+            if record.target() == "TimestampSinkTest" {
+                *self
+                    .0
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner()) =
+                    format::generate_plain_log(record, fatal, Some(&self.1));
+            }
+        }
+    }
+    static ISO: Mutex<String> = Mutex::new(String::new());
+    static YEAR: Mutex<String> = Mutex::new(String::new());
+
+    init();
+    add_sink(FormattingSink(
+        &ISO,
+        TimestampFormatter::new("%Y-%m-%dT%H:%M").unwrap_or_else(|error| panic!("{error}")),
+    ));
+    add_sink(FormattingSink(
+        &YEAR,
+        TimestampFormatter::new("%Y").unwrap_or_else(|error| panic!("{error}")),
+    ));
+    assert!(TimestampFormatter::new("%Q").is_err());
+
+    info!("TimestampSinkTest"; "Hello World!");
+
+    let iso = ISO
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone();
+    let year = YEAR
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone();
+    let now = chrono::Local::now();
+    assert!(
+        iso.starts_with(&format!("{}T", now.format("%Y-%m-%d"))),
+        "{iso:?}"
+    );
+    assert!(iso.ends_with("| INFO  | Hello World!"), "{iso:?}");
+    assert!(
+        year.starts_with(&format!("{} | TimestampSinkTes", now.format("%Y"))),
+        "{year:?}"
+    );
+}
+
+#[test]
+fn ring_buffer_keeps_recent_lines() {
+    /// Dump the ring buffer into a string.
+    fn dump() -> String {
+        let mut dumped = vec![];
+        dump_ring_buffer(&mut dumped).unwrap_or_else(|error| panic!("{error}"));
+        String::from_utf8(dumped).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    let _config = lock_config();
+    init();
+
+    // other tests may log at the same time, so the buffer needs to be large enough to keep every line of this test
+    set_ring_buffer_size(1000);
+    for index in 0..3 {
+        info!("RingBufferTest"; "line {index}");
+    }
+    let dumped = dump();
+    let dumped_again = dump();
+    set_ring_buffer_size(1);
+    let shrunk = dump();
+    set_ring_buffer_size(0);
+
+    let lines = dumped
+        .lines()
+        .filter(|line| line.contains("RingBufferTest"))
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 3, "{dumped:?}");
+    assert!(lines[0].ends_with("| INFO  | line 0"), "{dumped:?}");
+    assert!(lines[2].ends_with("| INFO  | line 2"), "{dumped:?}");
+    assert!(dumped_again.starts_with(&dumped), "{dumped_again:?}");
+    assert_eq!(shrunk.lines().count(), 1, "{shrunk:?}");
+    assert!(dump().is_empty());
+}
+
+#[test]
+fn leveled_at_runtime_level() {
+    init();
+
+    let evaluated = std::cell::Cell::new(0);
+    let level = |status: u16| {
+        evaluated.set(evaluated.get() + 1);
+        match status {
+            500.. => Level::Error,
+            400.. => Level::Warn,
+            _ => Level::Trace,
+        }
+    };
+    let lines = capture(|| {
+        leveled!(level(503); "LeveledTest"; "status {}", 503);
+        leveled!(level(404); "LeveledTest"; "status {}", 404);
+    });
+
+    assert_eq!(evaluated.get(), 2);
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.ends_with("| ERROR | status 503")),
+        "{lines:?}"
+    );
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.ends_with("| WARN  | status 404")),
+        "{lines:?}"
+    );
+}
+
+#[test]
+fn writer_sink() {
+    /// A writer owned by the sink, sharing what it has written with the test.
+    struct SharedWriter(std::sync::Arc<Mutex<Vec<u8>>>);
+    impl std::io::Write for SharedWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let written = std::sync::Arc::new(Mutex::new(vec![]));
+    init();
+    add_sink(WriterSink::new(SharedWriter(written.clone())));
+
+    info!("WriterSinkTest"; "Hello World!");
+
+    let written = String::from_utf8(
+        written
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone(),
+    )
+    .unwrap_or_else(|error| panic!("{error}"));
+    // the sink receives the logs of every other test too
+    assert!(
+        written
+            .lines()
+            .any(|line| line.ends_with("WriterSinkTest   | INFO  | Hello World!")),
+        "{written:?}"
+    );
+}
+
+#[test]
+fn target_message_separator_column() {
+    let _config = lock_config();
+
+    set_target_message_separator(Some("\t"));
+    let full = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    set_layout_preset(LayoutPreset::Compact);
+    let compact = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    set_layout_preset(LayoutPreset::Full);
+    set_target_message_separator(None);
+    let default = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+
+    assert!(
+        full.ends_with("Main             | INFO \tHello World!"),
+        "{full:?}"
+    );
+    assert_eq!(compact, "I Main            \tHello World!");
+    assert!(
+        default.ends_with("Main             | INFO  | Hello World!"),
+        "{default:?}"
+    );
+}
+
+/// Embedded users build this crate without the `timestamp` feature, so the layout they see is only covered by running the
+/// tests using `cargo test --no-default-features`.
+#[cfg(not(feature = "timestamp"))]
+#[test]
+fn layout_without_timestamp() {
+    let _config = lock_config();
+
+    let log = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+
+    assert_eq!(log, "Main             | INFO  | Hello World!");
+}
+
+#[test]
+fn seeded_sampling_is_reproducible() {
+    /// Sample 200 logs, returning the indices of the printed ones.
+    fn run() -> Vec<String> {
+        // setting the rate again restarts the count
+        set_sampling("SeededSamplingTest", Some(4));
+        capture(|| {
+            for index in 0..200 {
+                info!("SeededSamplingTest"; "{index}");
+            }
+        })
+        .iter()
+        .map(|line| line.rsplit(" | ").next().unwrap_or_default().to_string())
+        .collect()
+    }
+
+    let _config = lock_config();
+    init();
+
+    set_sampling_seed(Some(42));
+    let first = run();
+    let second = run();
+    set_sampling_seed(None);
+    let unseeded = run();
+    set_sampling("SeededSamplingTest", None);
+
+    assert_eq!(first, second);
+    assert!((20..80).contains(&first.len()), "{first:?}");
+    assert_ne!(first, unseeded);
+    assert_eq!(unseeded.len(), 50);
+}
+
+#[test]
+fn human_durations() {
+    for (duration, expected) in [
+        (Duration::ZERO, "0ns"),
+        (Duration::from_nanos(850), "850ns"),
+        (Duration::from_nanos(12_345), "12.3µs"),
+        (Duration::from_micros(450), "450µs"),
+        (Duration::from_millis(450), "450ms"),
+        (Duration::from_micros(1_500), "1.5ms"),
+        (Duration::from_nanos(1_234_567_891), "1.23s"),
+        (Duration::from_secs(2), "2s"),
+        (Duration::from_secs(123), "2m03s"),
+        (Duration::from_secs(3_723), "1h02m03s"),
+        (Duration::from_secs(30 * 60 * 60), "30h00m00s"),
+    ] {
+        assert_eq!(human_duration(duration).to_string(), expected);
+    }
+}
+
+#[test]
+fn log_for_uses_type_target() {
+    init();
+
+    const GOOLOG_CALLER: &str = "ModuleCaller";
+    struct Worker;
+    impl Logged for Worker {
+        const TARGET: &'static str = "LoggedWorker";
+    }
+    impl Worker {
+        fn run(&self) {
+            log_for!(Self; info; "running {}", 1);
+        }
+    }
+
+    let lines = capture(|| {
+        Worker.run();
+        log_for!(Worker; warn; "stopped");
+        info!("still the module caller");
+    });
+
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.ends_with("LoggedWorker     | INFO  | running 1")),
+        "{lines:?}"
+    );
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.ends_with("LoggedWorker     | WARN  | stopped")),
+        "{lines:?}"
+    );
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.ends_with("ModuleCaller     | INFO  | still the module caller")),
+        "{lines:?}"
+    );
+}
+
+#[cfg(feature = "timestamp")]
+#[test]
+fn timestamp_counter_column() {
+    let _config = lock_config();
+
+    /// Get the counter appended to the timestamp of the given `log`, if any.
+    fn counter(log: &str) -> Option<u32> {
+        let timestamp = log.split(" | ").nth(1)?;
+        timestamp.split_once(" #")?.1.parse().ok()
+    }
+
+    set_timestamp_counter(true);
+    timestamp::track_counter();
+    let first = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    timestamp::track_counter();
+    let second = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    set_timestamp_format(Some("%H:%M:%S%.3f")).unwrap_or_else(|error| panic!("{error}"));
+    let precise = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    set_timestamp_format(None).unwrap_or_else(|error| panic!("{error}"));
+    set_timestamp_counter(false);
+    let disabled = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+
+    let (first, second) = (
+        counter(&first).unwrap_or_else(|| panic!("{first}")),
+        counter(&second).unwrap_or_else(|| panic!("{second}")),
+    );
+    // the counter gets reset once a new second starts
+    assert!(second > first || second == 0, "{first} {second}");
+    assert!(!precise.contains(" #"), "{precise}");
+    assert!(!disabled.contains(" #"), "{disabled}");
+}
+
+#[cfg(feature = "timestamp")]
+#[test]
+fn hidden_timestamp_column() {
+    let _config = lock_config();
+
+    set_show_timestamp(false);
+    let hidden = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    set_layout_preset(LayoutPreset::Compact);
+    set_timestamp_format(Some("%H:%M")).unwrap_or_else(|error| panic!("{error}"));
+    let compact = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    set_timestamp_format(None).unwrap_or_else(|error| panic!("{error}"));
+    set_layout_preset(LayoutPreset::Full);
+    set_show_timestamp(true);
+    let shown = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+
+    assert_eq!(hidden, "Main             | INFO  | Hello World!");
+    assert_eq!(compact, "I Main             Hello World!");
+    assert!(
+        shown.len() > hidden.len() && shown.ends_with(&hidden),
+        "{shown}"
+    );
+}
+
+#[test]
+fn level_width_pads_level_column() {
+    let _config = lock_config();
+
+    set_level_width(8);
+    let wide = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    set_level_width(2);
+    let clamped = level_width();
+    let tight = strip_ansi(&render(Level::Error, "Main", "Hello World!"));
+    set_level_width(5);
+
+    assert!(
+        wide.ends_with("Main             | INFO     | Hello World!"),
+        "{wide}"
+    );
+    assert_eq!(clamped, 5);
+    assert!(
+        tight.ends_with("Main             | ERROR | Hello World!"),
+        "{tight}"
+    );
+}
+
+#[test]
+fn message_whitespace_is_trimmed() {
+    let _config = lock_config();
+
+    let message = "  exit status 1\r\n\n";
+    let kept = render(Level::Info, "Main", message);
+    set_trim_message(TrimMode::TrailingWhitespace);
+    let trailing = render(Level::Info, "Main", message);
+    set_trim_message(TrimMode::Both);
+    let both = render(Level::Info, "Main", message);
+    let inner = render(Level::Info, "Main", "first\n  second\n");
+    set_trim_message(TrimMode::None);
+
+    assert!(kept.ends_with(message), "{kept:?}");
+    assert!(trailing.ends_with("|   exit status 1"), "{trailing:?}");
+    assert!(both.ends_with("| exit status 1"), "{both:?}");
+    assert!(!both.ends_with("|  exit status 1"), "{both:?}");
+    assert!(inner.ends_with("first\n  second"), "{inner:?}");
+}
+
+#[test]
+fn record_components_are_visited_in_order() {
+    let _config = lock_config();
+
+    /// A visitor writing down every component it visits.
+    #[derive(Default)]
+    struct Components(Vec<String>);
+    impl RecordVisitor for Components {
+        fn timestamp(&mut self, _time: std::time::SystemTime) {
+            self.0.push("timestamp".to_string());
+        }
+
+        fn level(&mut self, level: Level, fatal: bool) {
+            self.0.push(format!("level={level} fatal={fatal}"));
+        }
+
+        fn target(&mut self, target: &str) {
+            self.0.push(format!("target={target}"));
+        }
+
+        fn message(&mut self, message: &str) {
+            self.0.push(format!("message={message}"));
+        }
+
+        fn field(&mut self, key: &str, value: &dyn Display) {
+            self.0.push(format!("{key}={value}"));
+        }
+    }
+
+    let mut components = Components::default();
+    set_trace_ids("4bf92f35", "00f067aa");
+    visit_record(
+        &Record::builder()
+            .level(Level::Error)
+            .target("MySuperAwesomeMCManageClient")
+            .args(format_args!("$goolog:fatal=Disk full"))
+            .module_path(Some("goolog::tests"))
+            .line(Some(7))
+            .build(),
+        &mut components,
+    );
+    clear_trace_ids();
+
+    assert_eq!(
+        components.0,
+        [
+            "timestamp",
+            "level=ERROR fatal=true",
+            "target=MySuperAwesomeMCManageClient",
+            "message=Disk full",
+            "module_path=goolog::tests",
+            "line=7",
+            "trace_id=4bf92f35",
+            "span_id=00f067aa"
+        ]
+    );
+}
+
+#[test]
+fn fatal_line_highlighted() {
+    let _config = lock_config();
+
+    /// Generate the console line for a log with the given `message`, sent at the error level.
+    fn console(message: &str) -> String {
+        let colors = ColoredLevelConfig::new();
+        let arguments = format_args!("{message}");
+        let record = Record::builder()
+            .level(Level::Error)
+            .target("Main")
+            .args(arguments)
+            .build();
+
+        let log = generate_log(16, &record, colors, record.args());
+        format::highlight_fatal(&record, record.args(), log)
+    }
+
+    let plain_fatal = console("$goolog:fatal=Disk full");
+    set_fatal_highlight(true);
+    let fatal = console("$goolog:fatal=Disk full");
+    let error = console("Disk full");
+    set_colors_enabled(false);
+    let uncolored = console("$goolog:fatal=Disk full");
+    set_colors_enabled(true);
+    set_fatal_highlight(false);
+
+    assert!(!plain_fatal.contains("\x1b[41m"), "{plain_fatal:?}");
+    assert!(fatal.starts_with("\x1b[41m"), "{fatal:?}");
+    assert!(fatal.ends_with("| Disk full\x1b[0m"), "{fatal:?}");
+    // every reset within the line re-applies the background
+    assert_eq!(
+        fatal.matches("\x1b[0m").count(),
+        fatal.matches("\x1b[0m\x1b[41m").count() + 1,
+        "{fatal:?}"
+    );
+    assert_eq!(strip_ansi(&fatal), strip_ansi(&plain_fatal));
+    assert!(!error.contains("\x1b[41m"), "{error:?}");
+    assert!(!uncolored.contains('\x1b'), "{uncolored:?}");
+}
+
+#[test]
+fn lazy_argument_built_once_after_filters() {
+    init();
+
+    /// The number of times the lazy argument has been built.
+    static BUILT: AtomicUsize = AtomicUsize::new(0);
+    let expensive = || {
+        BUILT.fetch_add(1, Ordering::Relaxed);
+        "rows: [1, 2, 3]"
+    };
+
+    let muted = mute_target("LazyTest");
+    let filtered = capture(|| {
+        info!("LazyTest"; "Fetched {}", lazy!(expensive));
+    });
+    drop(muted);
+    let lines = capture(|| {
+        info!("LazyTest"; "Fetched {}", lazy!(expensive));
+    });
+
+    assert!(filtered.is_empty(), "{filtered:?}");
+    assert_eq!(lines.len(), 1);
+    assert!(
+        lines[0].ends_with("| INFO  | Fetched rows: [1, 2, 3]"),
+        "{lines:?}"
+    );
+    assert_eq!(BUILT.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn incoming_ansi_is_stripped() {
+    let _config = lock_config();
+
+    let message = "exit \x1b[31mstatus\x1b[0m 1\x1b[2J\x1b]0;title\x07!";
+    set_colors_enabled(false);
+    let kept = render(Level::Info, "Main", message);
+    set_strip_incoming_ansi(true);
+    let stripped = render(Level::Info, "Main", message);
+    set_strip_incoming_ansi(false);
+    set_colors_enabled(true);
+
+    assert!(kept.ends_with(message), "{kept:?}");
+    assert!(
+        stripped.ends_with("| INFO  | exit status 1!"),
+        "{stripped:?}"
+    );
+    assert!(!stripped.contains('\x1b'), "{stripped:?}");
+    assert_eq!(strip_ansi("a\x1b]8;;link\x1b\\b\x1b[?25lc\x1b(Bd"), "abcd");
+}
+
+#[test]
+fn log_backtrace_appends_backtrace() {
+    init();
+
+    let lines = capture(|| {
+        log_backtrace!(Level::Warn; "BacktraceTest"; "reached {}", "here");
+    });
+
+    let log = lines.concat();
+    assert!(log.contains("| WARN  | reached here"), "{log}");
+    assert!(log.contains("Backtrace:"), "{log}");
+}
+
+#[test]
+fn on_fatal_can_be_reset() {
+    let _config = lock_config();
+
+    assert!(!on_fatal_is_custom());
+    set_on_fatal(|| {});
+    assert!(on_fatal_is_custom());
+    reset_on_fatal();
+    assert!(!on_fatal_is_custom());
+}
+
+#[test]
+fn on_fatal_handler_can_reenter() {
+    let _config = lock_config();
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    set_on_fatal(|| {
+        CALLS.fetch_add(1, Ordering::Relaxed);
+        // neither of these must deadlock while the handler is running
+        assert!(!on_fatal_is_custom());
+        INTERNAL__on_fatal();
+    });
+    INTERNAL__on_fatal();
+    assert_eq!(CALLS.load(Ordering::Relaxed), 1);
+    assert!(on_fatal_is_custom());
+    reset_on_fatal();
+}
+
+#[cfg(feature = "cbor")]
+#[test]
+fn cbor_frame_layout() {
+    let record = Record::builder()
+        .level(Level::Warn)
+        .target("Main")
+        .args(format_args!("Hi"))
+        .line(Some(300))
+        .build();
+
+    let frame = sink::cbor::frame(&record, false);
+
+    let length = u32::from_be_bytes([frame[0], frame[1], frame[2], frame[3]]);
+    assert_eq!(length as usize, frame.len() - 4);
+    // a map of six entries, starting with the timestamp as a 64-bit unsigned integer
+    assert_eq!(frame[4], 0xa6);
+    assert_eq!(&frame[5..15], b"\x69timestamp");
+    assert_eq!(frame[15], 0x1b);
+    assert_eq!(
+        &frame[24..],
+        b"\x65level\x64WARN\x66target\x64Main\x67message\x62Hi\x65fatal\xf4\x64line\x19\x01\x2c"
+    );
+}
+
+#[cfg(all(unix, feature = "mmap"))]
+#[test]
+fn mmap_ring_keeps_most_recent_lines() {
+    let path = std::env::temp_dir().join(format!("goolog-mmap-{}.log", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let sink = MmapSink::new(&path, 24 + 64).unwrap_or_else(|error| panic!("{error}"));
+    let log = |message: &str| {
+        sink.log(
+            &Record::builder()
+                .level(Level::Info)
+                .target("Main")
+                .args(format_args!("{message}"))
+                .build(),
+            false,
+        );
+    };
+
+    log("first");
+    let lines = MmapSink::recover(&path).unwrap_or_else(|error| panic!("{error}"));
+    assert_eq!(lines.len(), 1, "{lines:?}");
+    assert!(lines[0].ends_with("| INFO  | first"), "{lines:?}");
+
+    for index in 0..10 {
+        log(&format!("line {index}"));
+    }
+    drop(sink);
+    let lines = MmapSink::recover(&path).unwrap_or_else(|error| panic!("{error}"));
+    assert!(!lines.is_empty());
+    assert!(
+        lines
+            .last()
+            .is_some_and(|line| line.ends_with("| INFO  | line 9")),
+        "{lines:?}"
+    );
+    assert!(
+        lines.iter().all(|line| !line.contains("first")),
+        "{lines:?}"
+    );
+
+    // a sink for a ring of the same size continues it
+    let sink = MmapSink::new(&path, 24 + 64).unwrap_or_else(|error| panic!("{error}"));
+    drop(sink);
+    assert_eq!(
+        MmapSink::recover(&path).unwrap_or_else(|error| panic!("{error}")),
+        lines
+    );
+    assert!(MmapSink::new(&path, 24).is_err());
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn checkpoints_are_numbered() {
+    init();
+
+    let lines = capture(|| {
+        checkpoint!("CheckpointTest");
+        checkpoint!("CheckpointTest");
+    });
+
+    let numbers = lines
+        .iter()
+        .map(|line| {
+            let (_, checkpoint) = line
+                .split_once("| TRACE | checkpoint #")
+                .unwrap_or_else(|| panic!("{line}"));
+            assert!(checkpoint.contains(" at src/tests.rs:"), "{line}");
+            checkpoint
+                .split(' ')
+                .next()
+                .and_then(|number| number.parse::<usize>().ok())
+                .unwrap_or_else(|| panic!("{line}"))
+        })
+        .collect::<Vec<_>>();
+    // other tests may reach checkpoints at the same time
+    assert!(numbers.len() == 2 && numbers[0] < numbers[1], "{lines:?}");
+}
+
+#[test]
+fn column_header_aligned_with_lines() {
+    let _config = lock_config();
+
+    assert_eq!(format::header_line(), None);
+    set_column_header(true);
+    set_level_width(7);
+    let header = format::header_line().unwrap_or_default();
+    let log = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    set_layout_preset(LayoutPreset::Compact);
+    let compact = format::header_line();
+    set_layout_preset(LayoutPreset::Full);
+    set_level_width(5);
+    set_column_header(false);
+
+    assert!(
+        header.ends_with("TARGET           | LEVEL   | MESSAGE"),
+        "{header}"
+    );
+    assert_eq!(header.find("TARGET"), log.find("Main"), "{header}\n{log}");
+    assert_eq!(header.find("LEVEL"), log.find("INFO"), "{header}\n{log}");
+    assert_eq!(header.find("MESSAGE"), log.find("Hello"), "{header}\n{log}");
+    assert_eq!(compact, None);
+}
+
+#[test]
+fn message_materialized_once() {
+    /// The number of times the message of this test has been transformed.
+    static TRANSFORMED: AtomicUsize = AtomicUsize::new(0);
+
+    let _config = lock_config();
+    init();
+    add_sink(WriterSink::new(std::io::sink()));
+
+    set_message_transform(&|message| {
+        if message == "Rendered once" {
+            TRANSFORMED.fetch_add(1, Ordering::Relaxed);
+        }
+        Cow::Borrowed(message)
+    });
+    let lines = capture(|| {
+        info!("EntryTest"; "Rendered once");
+    });
+
+    assert!(lines[0].ends_with("| INFO  | Rendered once"), "{lines:?}");
+    // the console and the sinks render the line from the same entry
+    assert_eq!(TRANSFORMED.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn deprecated_warns_once_per_call_site() {
+    init();
+
+    fn old_api() {
+        deprecated!("DeprecatedTest"; "use `{}` instead", "new_api");
+    }
+    let lines = capture(|| {
+        // a muted call site must not use up its warning
+        let muted = mute_target("DeprecatedTest");
+        old_api();
+        drop(muted);
+
+        for _ in 0..3 {
+            old_api();
+        }
+        deprecated!("DeprecatedTest"; "another call site");
+    });
+
+    assert_eq!(lines.len(), 2, "{lines:?}");
+    assert!(
+        lines[0].ends_with("| WARN  | [deprecated] use `new_api` instead"),
+        "{lines:?}"
+    );
+    assert!(
+        lines[1].ends_with("| WARN  | [deprecated] another call site"),
+        "{lines:?}"
+    );
+}
+
+#[test]
+fn audit_events_are_json_lines() {
+    /// A writer owned by the audit stream, sharing what it has written with the test.
+    struct SharedWriter(std::sync::Arc<Mutex<Vec<u8>>>);
+    impl std::io::Write for SharedWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    const GOOLOG_CALLER: &str = "AuditTest";
+    let _config = lock_config();
+    init();
+    let written = std::sync::Arc::new(Mutex::new(vec![]));
+    set_audit_writer(Some(Box::new(SharedWriter(written.clone()))));
+    let muted = mute_target("AuditTest");
+
+    audit!("Auth"; user = "al\"ice", attempts = 3);
+    audit!(action = "logout\n",);
+    set_audit_writer(None);
+    drop(muted);
+
+    let written = String::from_utf8(
+        written
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone(),
+    )
+    .unwrap_or_else(|error| panic!("{error}"));
+    let lines: Vec<_> = written.lines().collect();
+    assert_eq!(lines.len(), 2, "{written:?}");
+    assert!(lines[0].starts_with("{\"timestamp\":"), "{written:?}");
+    assert!(
+        lines[0].ends_with(",\"target\":\"Auth\",\"user\":\"al\\\"ice\",\"attempts\":\"3\"}"),
+        "{written:?}"
+    );
+    assert!(
+        lines[1].ends_with(",\"target\":\"AuditTest\",\"action\":\"logout\\n\"}"),
+        "{written:?}"
+    );
+}
+
+#[test]
+fn version_column() {
+    let _config = lock_config();
+
+    set_show_version(Some("1.2.3"));
+    let header = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    set_version_placement(VersionPlacement::Line);
+    let line = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    set_format(Some(goolog_format!("{version} {level} {message}")));
+    let template = strip_ansi(&render(Level::Info, "Main", "Hello World!"));
+    set_format(None);
+    set_version_placement(VersionPlacement::Header);
+    set_show_version(None);
+
+    assert!(!header.contains("1.2.3"), "{header}");
+    assert!(
+        line.ends_with("1.2.3 | Main             | INFO  | Hello World!"),
+        "{line}"
+    );
+    assert_eq!(template, "1.2.3 INFO  Hello World!");
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn version_in_file_header() {
+    use crate::rotation::RotatingFile;
+
+    let _config = lock_config();
+    let logs_dir = std::path::PathBuf::from("version_header_logs");
+    std::fs::create_dir_all(&logs_dir).unwrap_or_else(|error| panic!("{error}"));
+
+    set_file_header(Some("# my-app".to_string()));
+    set_show_version(Some("1.2.3"));
+    let mut file =
+        RotatingFile::open(&logs_dir.join("main.log")).unwrap_or_else(|error| panic!("{error}"));
+    file.write_line(&format_args!("first"))
+        .unwrap_or_else(|error| panic!("{error}"));
+    set_show_version(None);
+    set_file_header(None);
+
+    let log = std::fs::read_to_string(logs_dir.join("main.log"))
+        .unwrap_or_else(|error| panic!("{error}"));
+    std::fs::remove_dir_all(&logs_dir).unwrap_or_else(|error| panic!("{error}"));
+
+    assert_eq!(log, "# my-app\n# version 1.2.3\nfirst\n");
+}
+
+#[test]
+fn formatter_bytes_default_to_text() {
+    /// A formatter writing the target followed by the message.
+    struct ArrowFormatter;
+    impl Formatter for ArrowFormatter {
+        fn format(
+            &self,
+            out: &mut dyn fmt::Write,
+            record: &Record,
+            ctx: &FormatCtx,
+        ) -> fmt::Result {
+            write!(out, "{} -> {}", record.target(), ctx.message())
+        }
+    }
+    /// A formatter writing binary data.
+    struct BinaryFormatter;
+    impl Formatter for BinaryFormatter {
+        fn format(
+            &self,
+            out: &mut dyn fmt::Write,
+            _record: &Record,
+            _ctx: &FormatCtx,
+        ) -> fmt::Result {
+            out.write_str("binary")
+        }
+
+        fn format_bytes(&self, record: &Record, ctx: &FormatCtx) -> Vec<u8> {
+            vec![record.level() as u8, 0xff, ctx.message().len() as u8]
+        }
+    }
+
+    let record = Record::builder()
+        .level(Level::Warn)
+        .target("Main")
+        .args(format_args!("Hi"))
+        .build();
+
+    assert_eq!(
+        format::generate_bytes(&record, false, &ArrowFormatter),
+        b"Main -> Hi"
+    );
+    assert_eq!(
+        format::generate_bytes(&record, false, &BinaryFormatter),
+        [2, 0xff, 2]
     );
-    // remove the file from the path
-    log_file_path.pop();
-    fs::remove_dir_all(log_file_path)
-        .unwrap_or_else(|erro| fatal!("Main"; "Could not remove the log file. Error: {erro}"));
 }
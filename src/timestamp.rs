@@ -0,0 +1,353 @@
+//! This module provides the [`set_timestamp_format`] function used to customize the timestamp of every log line.
+
+use std::{
+    cell::Cell,
+    fmt::{
+        self,
+        Display,
+        Write,
+    },
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Mutex,
+        RwLock,
+    },
+};
+
+use chrono::format::{
+    Fixed,
+    Item,
+    Numeric,
+    StrftimeItems,
+};
+use log::Record;
+
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+use crate::entry;
+use crate::{
+    format::{
+        default_log,
+        to_fixed_size,
+    },
+    FormatCtx,
+    Formatter,
+};
+
+/// The custom chrono format of the timestamp and the width of a timestamp rendered using it. \
+/// [`None`] means the default format will be used.
+static TIMESTAMP_FORMAT: RwLock<Option<(String, usize)>> = RwLock::new(None);
+/// Whether the timestamp column is printed at all.
+static SHOW_TIMESTAMP: AtomicBool = AtomicBool::new(true);
+/// Whether a counter is appended to timestamps without sub-second precision.
+static TIMESTAMP_COUNTER: AtomicBool = AtomicBool::new(false);
+/// The second the previous log has been sent in, as rendered by [`now`], and the number of logs sent in it before.
+static SECOND: Mutex<(String, u32)> = Mutex::new((String::new(), 0));
+
+thread_local! {
+    /// The counter of the log currently being sent on this thread within its second.
+    static COUNTER: Cell<Option<u32>> = const { Cell::new(None) };
+}
+
+/// An error returned by [`set_timestamp_format`] if the given format is not a valid chrono format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimestampFormatError {
+    /// The invalid format.
+    format: String,
+}
+impl Display for TimestampFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is not a valid chrono format", self.format)
+    }
+}
+impl std::error::Error for TimestampFormatError {}
+
+/// Set a custom chrono `format` used to print the timestamp of every log line. \
+/// \
+/// Any specifier supported by [chrono](chrono::format::strftime), like the ISO week `%G-W%V-%u`, can be used. Since the width of
+/// a rendered timestamp depends on the format, it gets measured once while setting the format. Every timestamp will then be
+/// padded to this width to keep the columns of the log aligned. Passing [`None`] will restore the default format of
+/// `%d.%m.%Y | %H:%M:%S`.
+///
+/// # Errors
+///
+/// This function will return an error if the given format contains an invalid specifier. The previous format will be kept in
+/// that case.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_timestamp_format(Some("%G-W%V-%u %H:%M")).unwrap();
+///
+/// // this will be printed as `2023-W22-1 14:34 | Main             | INFO  | Hello World!`
+/// info!("Main"; "Hello World!");
+/// # }
+/// ```
+pub fn set_timestamp_format(format: Option<&str>) -> Result<(), TimestampFormatError> {
+    let format = format.map(parse_format).transpose()?;
+
+    *TIMESTAMP_FORMAT
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = format;
+    Ok(())
+}
+
+/// Get the custom chrono format used to print the timestamp of every log line.
+pub fn timestamp_format() -> Option<String> {
+    TIMESTAMP_FORMAT
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .as_ref()
+        .map(|(format, _)| format.clone())
+}
+
+/// Set whether the timestamp column is printed. \
+/// \
+/// When the logs are collected by a service prepending its own timestamps, like journald, Docker or Kubernetes, the timestamp
+/// of goolog is redundant. Disabling it removes the whole column including its separator, while
+/// [`set_timestamp_format`] only changes how the column is rendered. The `{timestamp}` placeholder of a custom format will
+/// be empty. The timestamp is printed by default.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_show_timestamp(false);
+///
+/// // this will be printed as `Main             | INFO  | Hello World!`
+/// info!("Main"; "Hello World!");
+/// # }
+/// ```
+pub fn set_show_timestamp(show_timestamp: bool) {
+    SHOW_TIMESTAMP.store(show_timestamp, Ordering::Relaxed);
+}
+
+/// Get whether the timestamp column is printed.
+pub fn show_timestamp() -> bool {
+    SHOW_TIMESTAMP.load(Ordering::Relaxed)
+}
+
+/// Set whether a counter is appended to the timestamp of every log line if the timestamp has no sub-second precision. \
+/// \
+/// With the default format, which ends with the seconds, many log lines share the same timestamp and their order gets lost once
+/// the logs of multiple sources are merged and sorted. If switching to a format with sub-second precision, like `%H:%M:%S%.3f`,
+/// is not an option, this appends a counter like `#002` to the timestamp. It starts at `0` for the first log of every second and
+/// is raised by one for every log after it, so log lines sharing a timestamp can be ordered by it. Timestamps rendered using a
+/// format with sub-second precision never get a counter. The counter is not appended by default.
+///
+/// The counter only orders log lines within the same second. It is shared across all threads and does not identify a log line
+/// across the whole log, unlike a sequence number. Every destination of the same log prints the same counter.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_timestamp_counter(true);
+///
+/// // this will be printed as `29.05.2023 | 14:34:33 #000 | Main             | INFO  | Loading...`
+/// info!("Main"; "Loading...");
+/// // this will be printed as `29.05.2023 | 14:34:33 #001 | Main             | INFO  | Done.`
+/// info!("Main"; "Done.");
+/// # }
+/// ```
+pub fn set_timestamp_counter(timestamp_counter: bool) {
+    TIMESTAMP_COUNTER.store(timestamp_counter, Ordering::Relaxed);
+}
+
+/// Get whether a counter is appended to the timestamp of every log line if the timestamp has no sub-second precision.
+pub fn timestamp_counter() -> bool {
+    TIMESTAMP_COUNTER.load(Ordering::Relaxed)
+}
+
+/// Store the counter within the current second for the log currently being sent on this thread.
+pub(crate) fn track_counter() {
+    if !timestamp_counter() {
+        return;
+    }
+
+    let second = now("%s");
+    let mut previous = SECOND
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if previous.0 == second {
+        previous.1 = previous.1.saturating_add(1);
+    } else {
+        *previous = (second, 0);
+    }
+    COUNTER.with(|counter| counter.set(Some(previous.1)));
+}
+
+/// Check whether a timestamp rendered using the given chrono `format` has sub-second precision.
+fn has_subsecond_precision(format: &str) -> bool {
+    StrftimeItems::new(format).any(|item| {
+        matches!(
+            item,
+            Item::Numeric(Numeric::Nanosecond, _)
+                | Item::Fixed(
+                    Fixed::Nanosecond
+                        | Fixed::Nanosecond3
+                        | Fixed::Nanosecond6
+                        | Fixed::Nanosecond9
+                        // the variants of `%3f`, `%6f` and `%9f`
+                        | Fixed::Internal(_)
+                )
+        )
+    })
+}
+
+/// Get the name of the timestamp column padded to the width of the timestamps printed using the format set by
+/// [`set_timestamp_format`].
+pub(crate) fn header() -> String {
+    let format = TIMESTAMP_FORMAT
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let mut header = match format.as_ref() {
+        Some((_, width)) => to_fixed_size(*width as u32, "TIMESTAMP"),
+        None => format!("{:10} | {:8}", "DATE", "TIME"),
+    };
+    if timestamp_counter()
+        && !format
+            .as_ref()
+            .is_some_and(|(format, _)| has_subsecond_precision(format))
+    {
+        // the width of a counter like ` #000`
+        header.push_str(&" ".repeat(5));
+    }
+
+    header
+}
+
+/// Validate the given chrono `format` and measure the width of a timestamp rendered using it.
+fn parse_format(format: &str) -> Result<(String, usize), TimestampFormatError> {
+    if StrftimeItems::new(format).any(|item| item == Item::Error) {
+        return Err(TimestampFormatError {
+            format: format.to_string(),
+        });
+    }
+
+    Ok((format.to_string(), now(format).chars().count()))
+}
+
+/// A formatter generating the default log lines with its own timestamp format. \
+/// \
+/// Unlike [`set_timestamp_format`], this only changes the timestamp of the destinations using this formatter. For example, a sink
+/// read by another program can print ISO 8601 timestamps while the console keeps the default format. Every other setting of the
+/// default layout is respected just like by the [`DefaultFormatter`](crate::DefaultFormatter).
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+///
+/// # #[cfg(unix)]
+/// add_sink(
+///     UnixSocketSink::new("/run/collector.sock")
+///         .with_formatter(Arc::new(TimestampFormatter::new("%Y-%m-%dT%H:%M:%S%.3f%:z").unwrap())),
+/// );
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimestampFormatter {
+    /// The chrono format of the timestamp.
+    format: String,
+    /// The width of a timestamp rendered using the format.
+    width: usize,
+}
+impl TimestampFormatter {
+    /// Create a formatter printing the timestamp using the given chrono `format`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the given format contains an invalid specifier.
+    pub fn new(format: &str) -> Result<Self, TimestampFormatError> {
+        let (format, width) = parse_format(format)?;
+
+        Ok(Self { format, width })
+    }
+}
+impl Formatter for TimestampFormatter {
+    fn format(&self, out: &mut dyn fmt::Write, record: &Record, ctx: &FormatCtx) -> fmt::Result {
+        out.write_str(&default_log(
+            record,
+            &FormatCtx {
+                timestamp_format: Some((&self.format, self.width)),
+                ..*ctx
+            },
+        ))
+    }
+}
+
+/// Format the time of the log currently being sent on this thread, or the current local time if there is none, using the given
+/// chrono `format`. \
+/// \
+/// On `wasm32` targets, the current time is always used. It is read using the JavaScript `Date` API, since chrono cannot read
+/// the local time there.
+fn now(format: &str) -> String {
+    let mut timestamp = String::new();
+
+    #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+    {
+        let date = js_sys::Date::new_0();
+        if let Some(time) = chrono::NaiveDate::from_ymd_opt(
+            date.get_full_year() as i32,
+            date.get_month() + 1,
+            date.get_date(),
+        )
+        .and_then(|day| day.and_hms_opt(date.get_hours(), date.get_minutes(), date.get_seconds()))
+        {
+            // a specifier needing a time zone cannot be rendered without one
+            write!(timestamp, "{}", time.format(format)).ok();
+        }
+    }
+    #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+    {
+        // every destination prints the time the log currently being sent has been captured at
+        let time = entry::current().map_or_else(chrono::Local::now, |entry| entry.time);
+        write!(timestamp, "{}", time.format(format)).ok();
+    }
+
+    timestamp
+}
+
+/// Get the timestamp of a log sent right now, using the given `format` and its width or the one set by [`set_timestamp_format`]. \
+/// If `styled` is true, the timestamp will be dimmed and bold using ANSI escape codes.
+pub(crate) fn timestamp(styled: bool, format: Option<(&str, usize)>) -> String {
+    let global_format = TIMESTAMP_FORMAT
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let format = format.or(global_format
+        .as_ref()
+        .map(|(format, width)| (format.as_str(), *width)));
+
+    let mut timestamp = match (format, styled) {
+        (Some((format, width)), true) => format!("\x1b[2m\x1b[1m{:width$}\x1b[0m", now(format)),
+        (Some((format, width)), false) => format!("{:width$}", now(format)),
+        (None, true) => now("\x1b[2m\x1b[1m%d.%m.%Y\x1b[0m | \x1b[2m\x1b[1m%H:%M:%S\x1b[0m"),
+        (None, false) => now("%d.%m.%Y | %H:%M:%S"),
+    };
+
+    if let Some(counter) = COUNTER.with(Cell::get).filter(|_| timestamp_counter()) {
+        if !format.is_some_and(|(format, _)| has_subsecond_precision(format)) {
+            match styled {
+                true => write!(timestamp, " \x1b[2m\x1b[1m#{counter:03}\x1b[0m").ok(),
+                false => write!(timestamp, " #{counter:03}").ok(),
+            };
+        }
+    }
+
+    timestamp
+}
@@ -0,0 +1,152 @@
+//! This module provides the [`transaction`] function used to print a group of log lines as one contiguous block.
+
+use std::{
+    cell::RefCell,
+    io::{
+        self,
+        Write,
+    },
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    },
+};
+
+use log::Level;
+
+/// A destination receiving a block of log lines together with the level of their logs, like the console.
+pub(crate) type Destination = Arc<dyn Fn(&[(Level, String)]) + Send + Sync>;
+
+/// The log lines buffered for one output: its id, its destination, and the lines it received.
+type Block = (usize, Destination, Vec<(Level, String)>);
+
+/// The id of the next output created using [`output`].
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The log lines buffered by the transaction currently running on this thread, if any. \
+    /// The lines are grouped by the id of the output which received them, in the order the outputs received their first line.
+    static BUFFER: RefCell<Option<Vec<Block>>> = const { RefCell::new(None) };
+}
+
+/// Commits the transaction of this thread once dropped, even if the closure of the transaction panicked.
+struct TransactionGuard;
+impl Drop for TransactionGuard {
+    fn drop(&mut self) {
+        commit();
+        BUFFER.with(|buffer| buffer.replace(None));
+    }
+}
+
+/// Run the given closure and print every log line it sends to the console as one contiguous block once it returns. \
+/// \
+/// Log lines sent by other threads at the same time are never printed in between the lines of the block, so a logical group of
+/// lines, like the steps of a multi-line report, stays readable even under concurrent logging. The block is written using a
+/// single write while holding the lock of the console, and the closure's return value is passed through.
+///
+/// # Delayed visibility
+///
+/// The lines sent within the transaction only become visible once it commits, which happens when the closure returns or panics.
+/// Until then, they are kept in memory, so a long-running transaction delays its output accordingly. If the closure sends a
+/// fatal log, the lines buffered so far are printed before the [`fatal!`](crate::fatal) macro exits the application.
+///
+/// # Scope
+///
+/// Only the lines printed to the console, or captured in its place by [`init_for_test`](crate::init_for_test), are grouped: the
+/// log file, every [`Sink`](crate::Sink) and [`capture`](crate::capture) still receive every line right away. Transactions are scoped to the current thread, so logs sent by threads spawned by the
+/// closure are not part of the block. A transaction started within another one on the same thread simply joins the outer one.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+///
+/// transaction(|| {
+///     // these lines will be printed together, without any line of another thread in between
+///     info!("Report"; "Processed 42 files:");
+///     info!("Report"; "  40 succeeded");
+///     info!("Report"; "  2 failed");
+/// });
+/// # }
+/// ```
+pub fn transaction<T>(closure: impl FnOnce() -> T) -> T {
+    let outermost = BUFFER.with(|buffer| {
+        let mut buffer = buffer.borrow_mut();
+        match *buffer {
+            Some(_) => false,
+            None => {
+                *buffer = Some(vec![]);
+                true
+            }
+        }
+    });
+    if !outermost {
+        return closure();
+    }
+
+    let _guard = TransactionGuard;
+    closure()
+}
+
+/// Create the console output printing every log line to stderr if `to_stderr` is true and to stdout otherwise, or buffering it
+/// while a [`transaction`] is running on the current thread.
+pub(crate) fn console(to_stderr: bool) -> fern::Output {
+    output(Arc::new(move |lines| {
+        let block: String = lines.iter().map(|(_, line)| format!("{line}\n")).collect();
+        match to_stderr {
+            true => write(&mut io::stderr().lock(), &block),
+            false => write(&mut io::stdout().lock(), &block),
+        }
+    }))
+}
+
+/// Create the output sending every log line to the given `destination`, or buffering it while a [`transaction`] is running on
+/// the current thread. \
+/// The lines buffered by a transaction reach the `destination` as one block once the transaction commits.
+pub(crate) fn output(destination: Destination) -> fern::Output {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+
+    fern::Output::call(move |record| {
+        let line = (record.level(), record.args().to_string());
+        let unbuffered = BUFFER.with(|buffer| match &mut *buffer.borrow_mut() {
+            Some(blocks) => {
+                match blocks.iter_mut().find(|(block_id, _, _)| *block_id == id) {
+                    Some((_, _, lines)) => lines.push(line),
+                    None => blocks.push((id, destination.clone(), vec![line])),
+                }
+                None
+            }
+            None => Some(line),
+        });
+
+        if let Some(line) = unbuffered {
+            destination(&[line]);
+        }
+    })
+}
+
+/// Send every line buffered by the [`transaction`] running on the current thread, if any, to its destination, keeping the
+/// transaction running.
+pub(crate) fn commit() {
+    let blocks = BUFFER.with(|buffer| buffer.borrow_mut().as_mut().map(std::mem::take));
+
+    for (_, destination, lines) in blocks.unwrap_or_default() {
+        destination(&lines);
+    }
+}
+
+/// Write the given `text` to the given `console` and flush it.
+fn write(console: &mut impl Write, text: &str) {
+    // logging this error would end up on the console again
+    if let Err(error) = console
+        .write_all(text.as_bytes())
+        .and_then(|_| console.flush())
+    {
+        eprintln!("Failed to write to the console. Error: {error}");
+    }
+}
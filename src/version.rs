@@ -0,0 +1,93 @@
+//! This module provides the [`set_show_version`] function used to include the version of the application in the logs.
+
+use std::sync::{
+    atomic::{
+        AtomicU8,
+        Ordering,
+    },
+    RwLock,
+};
+
+/// The version of the application included in the logs. \
+/// [`None`] means no version will be included.
+static VERSION: RwLock<Option<&'static str>> = RwLock::new(None);
+/// Where the version of the application gets included. \
+/// This is stored as the discriminant of a [`VersionPlacement`] so no lock is needed to read it.
+static VERSION_PLACEMENT: AtomicU8 = AtomicU8::new(VersionPlacement::Header as u8);
+
+/// This enum describes where the version set using [`set_show_version`] gets included.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum VersionPlacement {
+    /// The version will be written once to the top of every new log file, as a line like `# version 1.2.3` following the header
+    /// set using [`set_file_header`](crate::set_file_header). Without a log file, the version will not be included at all. This is
+    /// the default.
+    #[default]
+    Header,
+    /// The version will be printed in its own column of every log line, just like the process id: `29.05.2023 | 14:34:33 |
+    /// 1.2.3 | Main             | INFO  | Hello World!`. This is verbose, but keeps the version attached to every line, even once
+    /// the lines of multiple versions have been merged.
+    Line,
+}
+
+/// Set the `version` of the application to be included in the logs, to correlate them with a release. \
+/// \
+/// The version is best passed as `env!("CARGO_PKG_VERSION")`. By default, it gets written to the top of every new log file; see
+/// [`VersionPlacement`] and [`set_version_placement`] for including it in every log line instead. Passing [`None`] will restore
+/// the default of not including any version.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// set_show_version(Some(env!("CARGO_PKG_VERSION")));
+/// init_logger(None, None, None);
+/// # }
+/// ```
+pub fn set_show_version(version: Option<&'static str>) {
+    *VERSION
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = version;
+}
+
+/// Get the version of the application included in the logs.
+pub fn show_version() -> Option<&'static str> {
+    *VERSION
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Set where the version set using [`set_show_version`] gets included. \
+/// \
+/// See [`VersionPlacement`] for what each placement does. By default, the [`VersionPlacement::Header`] is used.
+///
+/// # Example
+///
+/// ```
+/// use goolog::*;
+/// # fn main() {
+/// init_logger(None, None, None);
+/// set_show_version(Some("1.2.3"));
+/// set_version_placement(VersionPlacement::Line);
+///
+/// // this will be printed as `29.05.2023 | 14:34:33 | 1.2.3 | Main             | INFO  | Hello World!`
+/// info!("Main"; "Hello World!");
+/// # }
+/// ```
+pub fn set_version_placement(placement: VersionPlacement) {
+    VERSION_PLACEMENT.store(placement as u8, Ordering::Relaxed);
+}
+
+/// Get where the version set using [`set_show_version`] gets included.
+pub fn version_placement() -> VersionPlacement {
+    match VERSION_PLACEMENT.load(Ordering::Relaxed) {
+        1 => VersionPlacement::Line,
+        _ => VersionPlacement::Header,
+    }
+}
+
+/// Get the version to be included with the given `placement`, if any.
+pub(crate) fn version_for(placement: VersionPlacement) -> Option<&'static str> {
+    show_version().filter(|_| version_placement() == placement)
+}
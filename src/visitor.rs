@@ -0,0 +1,154 @@
+//! This module provides the [`visit_record`] function used to walk through the components of a log without building a log line.
+
+use std::fmt::Display;
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+use std::time::SystemTime;
+
+use log::{
+    Level,
+    Record,
+};
+
+use crate::{
+    context,
+    entry,
+    format::{
+        strip_fatal_marker,
+        transform_message,
+    },
+    span,
+};
+
+/// A visitor receiving the components of a log one after another, as decomposed by the goolog logger. \
+/// \
+/// This is a lower-level hook than the [`Formatter`](crate::Formatter) trait: instead of writing a log line, the visitor gets
+/// called once for every component, so it can stream them straight into a custom encoder without building a string first. Every
+/// function does nothing by default, so a visitor only needs to implement the ones it is interested in. See [`visit_record`] for
+/// the order the functions get called in.
+pub trait RecordVisitor {
+    /// Visit the time the log has been sent at. \
+    /// This is not called on `wasm32` targets with the `wasm` feature enabled, since the system time cannot be read there.
+    #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+    fn timestamp(&mut self, _time: SystemTime) {}
+
+    /// Visit the level of the log. \
+    /// `fatal` indicates whether the log has been sent by the [`fatal!`](crate::fatal) macro, in which case `level` is
+    /// [`Level::Error`].
+    fn level(&mut self, _level: Level, _fatal: bool) {}
+
+    /// Visit the name under which the log has been sent. \
+    /// Unlike the target column of a log line, the name is neither padded nor truncated.
+    fn target(&mut self, _target: &str) {}
+
+    /// Visit the message of the log. \
+    /// Just like [`FormatCtx::message`](crate::FormatCtx::message), the message has already been processed by the transform set
+    /// using [`set_message_transform`](crate::set_message_transform) and does not contain the marker added by the
+    /// [`fatal!`](crate::fatal) macro.
+    fn message(&mut self, _message: &str) {}
+
+    /// Visit an additional field of the log, like the file it has been sent from.
+    fn field(&mut self, _key: &str, _value: &dyn Display) {}
+}
+
+/// Walk through the components of the given `record`, calling the given `visitor` once for every one of them. \
+/// \
+/// This exposes the decomposition the goolog logger uses to build its log lines, including the detection of the
+/// [`fatal!`](crate::fatal) macro and the processing of the message, so integrations like custom sinks do not have to
+/// reimplement it. If called while the record is being sent, for example from [`Sink::log`](crate::Sink::log), the message and
+/// the time are taken from the log already captured by the goolog logger instead of being computed again.
+///
+/// # Visitation order
+///
+/// 1. [`RecordVisitor::timestamp`] (not on `wasm32` targets with the `wasm` feature enabled)
+/// 2. [`RecordVisitor::level`]
+/// 3. [`RecordVisitor::target`]
+/// 4. [`RecordVisitor::message`]
+/// 5. [`RecordVisitor::field`] for every field present, in this order: `module_path`, `file` and `line` of the record, followed by
+///    `trace_id` and `span_id` if set using [`set_trace_ids`](crate::set_trace_ids), followed by `span_name` and `span_number`
+///    if sent within a [`Span`](crate::Span).
+///
+/// The settings deciding which columns get printed, like [`set_show_timestamp`](crate::set_show_timestamp), do not affect the
+/// visitation.
+///
+/// # Example
+///
+/// ```
+/// use std::fmt::Display;
+///
+/// use goolog::*;
+/// use goolog::log::{
+///     Level,
+///     Record,
+/// };
+///
+/// struct KeyValues(Vec<String>);
+/// impl RecordVisitor for KeyValues {
+///     fn level(&mut self, level: Level, fatal: bool) {
+///         self.0.push(format!("level={}", if fatal { "FATAL".to_string() } else { level.to_string() }));
+///     }
+///
+///     fn message(&mut self, message: &str) {
+///         self.0.push(format!("message={message:?}"));
+///     }
+///
+///     fn field(&mut self, key: &str, value: &dyn Display) {
+///         self.0.push(format!("{key}={value}"));
+///     }
+/// }
+/// # fn main() {
+///
+/// let mut key_values = KeyValues(vec![]);
+/// visit_record(
+///     &Record::builder()
+///         .level(Level::Info)
+///         .target("Main")
+///         .args(format_args!("Hello World!"))
+///         .line(Some(42))
+///         .build(),
+///     &mut key_values,
+/// );
+///
+/// assert_eq!(key_values.0.join(" "), r#"level=INFO message="Hello World!" line=42"#);
+/// # }
+/// ```
+pub fn visit_record(record: &Record, visitor: &mut dyn RecordVisitor) {
+    let entry = entry::current();
+    let (fatal, message) = match &entry {
+        Some(entry) => (entry.fatal, entry.message.clone()),
+        None => {
+            let (fatal, message) = strip_fatal_marker(record.level(), record.args().to_string());
+            (fatal, transform_message(message))
+        }
+    };
+
+    #[cfg(all(
+        feature = "timestamp",
+        not(all(feature = "wasm", target_arch = "wasm32"))
+    ))]
+    visitor.timestamp(SystemTime::from(
+        entry.map_or_else(chrono::Local::now, |entry| entry.time),
+    ));
+    #[cfg(not(any(feature = "timestamp", all(feature = "wasm", target_arch = "wasm32"))))]
+    visitor.timestamp(SystemTime::now());
+    visitor.level(record.level(), fatal);
+    visitor.target(record.target());
+    visitor.message(&message);
+
+    if let Some(module_path) = record.module_path() {
+        visitor.field("module_path", &module_path);
+    }
+    if let Some(file) = record.file() {
+        visitor.field("file", &file);
+    }
+    if let Some(line) = record.line() {
+        visitor.field("line", &line);
+    }
+    if let Some((trace_id, span_id)) = context::trace_ids() {
+        visitor.field("trace_id", &trace_id);
+        visitor.field("span_id", &span_id);
+    }
+    if let Some((number, name)) = span::current_span() {
+        visitor.field("span_name", &name);
+        visitor.field("span_number", &number);
+    }
+}
@@ -0,0 +1,52 @@
+#![cfg(not(feature = "wasm"))]
+
+use std::time::Duration;
+
+use goolog::*;
+
+#[test]
+fn debug_logs_printed_after_error() {
+    set_adaptive_debug("Adaptive", Some(Duration::from_secs(60)));
+    set_adaptive_debug("Expired", Some(Duration::ZERO));
+    init_logger(None, None, None);
+
+    let lines = capture(|| {
+        debug!("Adaptive"; "before");
+        error!("Adaptive"; "failure");
+        debug!("Adaptive"; "after");
+        trace!("Adaptive"; "trace");
+        debug!("Other"; "other");
+        error!("Expired"; "failure");
+        debug!("Expired"; "after");
+    });
+
+    assert_eq!(lines.len(), 3, "{lines:?}");
+    assert!(lines[0].ends_with("| ERROR | failure"), "{lines:?}");
+    assert!(
+        lines[1].ends_with("Adaptive         | DEBUG | after"),
+        "{lines:?}"
+    );
+    assert!(
+        lines[2].ends_with("Expired          | ERROR | failure"),
+        "{lines:?}"
+    );
+    assert_eq!(adaptive_debug("Adaptive"), Some(Duration::from_secs(60)));
+    assert!(!would_log(log::Level::Debug, "Other"));
+
+    // targets set after the logger has been initiated work as well
+    set_adaptive_debug("Adaptive", None);
+    set_adaptive_debug("Expired", None);
+    assert_eq!(log::max_level(), log::LevelFilter::Info);
+    set_adaptive_debug("Late", Some(Duration::from_secs(60)));
+    let lines = capture(|| {
+        error!("Late"; "failure");
+        debug!("Late"; "after");
+    });
+    assert_eq!(lines.len(), 2, "{lines:?}");
+    assert!(
+        lines[1].ends_with("Late             | DEBUG | after"),
+        "{lines:?}"
+    );
+    set_adaptive_debug("Late", None);
+    assert_eq!(log::max_level(), log::LevelFilter::Info);
+}
@@ -0,0 +1,45 @@
+#![cfg(not(feature = "wasm"))]
+
+use std::process::Command;
+
+use goolog::{
+    log::Record,
+    *,
+};
+
+/// The environment variable telling the test to act as the child process exiting after sending a log.
+const CHILD_VAR: &str = "GOOLOG_EXIT_FLUSH_CHILD";
+
+/// A sink only printing its logs once it gets flushed.
+struct BufferedSink;
+impl Sink for BufferedSink {
+    fn log(&self, _record: &Record, _fatal: bool) {}
+
+    fn flush(&self) {
+        println!("flushed on exit");
+    }
+}
+
+#[test]
+fn exit_flushes_sinks() {
+    // the flush only runs once the process exits, so it has to be observed from a child process
+    if std::env::var_os(CHILD_VAR).is_some() {
+        init_logger(None, None, None);
+        add_sink(BufferedSink);
+        install_exit_flush();
+        install_exit_flush();
+
+        info!("Main"; "Hello World!");
+        return;
+    }
+
+    let output = Command::new(std::env::current_exe().unwrap_or_else(|error| panic!("{error}")))
+        .args(["--exact", "exit_flushes_sinks", "--nocapture"])
+        .env(CHILD_VAR, "1")
+        .output()
+        .unwrap_or_else(|error| panic!("{error}"));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{stdout}");
+    assert_eq!(stdout.matches("flushed on exit").count(), 1, "{stdout}");
+}
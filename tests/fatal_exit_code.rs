@@ -0,0 +1,42 @@
+// without the `fatal-exit` feature, the fatal macro panics instead of exiting
+#![cfg(all(not(feature = "wasm"), feature = "fatal-exit"))]
+
+use std::process::Command;
+
+use goolog::*;
+
+/// The environment variable telling the test to act as the child process sending the fatal log, and how.
+const CHILD_VAR: &str = "GOOLOG_FATAL_EXIT_CODE_CHILD";
+
+/// Run this test as a child process sending a fatal log as described by the given `mode`, returning its exit code.
+fn exit_code(mode: &str) -> Option<i32> {
+    Command::new(std::env::current_exe().unwrap_or_else(|error| panic!("{error}")))
+        .args(["--exact", "fatal_exits_with_configured_code", "--nocapture"])
+        .env(CHILD_VAR, mode)
+        .output()
+        .unwrap_or_else(|error| panic!("{error}"))
+        .status
+        .code()
+}
+
+#[test]
+fn fatal_exits_with_configured_code() {
+    // the fatal macro exits the process, so it has to be sent by a child process
+    if let Ok(mode) = std::env::var(CHILD_VAR) {
+        init_logger(None, None, None);
+        set_fatal_exit_code(3);
+
+        match mode.as_str() {
+            "inline" => fatal!(code = 4; "Main"; "Something went terribly wrong!"),
+            "handler" => {
+                set_on_fatal(|| std::process::exit(5));
+                fatal!("Main"; "Something went terribly wrong!")
+            }
+            _ => fatal!("Main"; "Something went terribly wrong!"),
+        }
+    }
+
+    assert_eq!(exit_code("configured"), Some(3));
+    assert_eq!(exit_code("inline"), Some(4));
+    assert_eq!(exit_code("handler"), Some(5));
+}
@@ -0,0 +1,60 @@
+// without the `fatal-exit` feature, the fatal macro panics instead of exiting
+#![cfg(all(not(feature = "wasm"), feature = "fatal-exit"))]
+
+use std::{
+    fs,
+    process::Command,
+    sync::Mutex,
+};
+
+use goolog::{
+    log::Record,
+    *,
+};
+
+/// The environment variable telling the test to act as the child process sending the fatal log.
+const CHILD_VAR: &str = "GOOLOG_FATAL_FLUSH_CHILD";
+/// The file the buffered sink writes its logs to once flushed.
+const FLUSH_FILE: &str = "fatal_flush.log";
+
+/// A sink buffering every log until it gets flushed.
+struct BufferedSink(Mutex<Vec<String>>);
+impl Sink for BufferedSink {
+    fn log(&self, record: &Record, fatal: bool) {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(format!("{fatal} {}", record.args()));
+    }
+
+    fn flush(&self) {
+        let lines = self
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .join("\n");
+        fs::write(FLUSH_FILE, lines).unwrap_or_else(|error| panic!("{error}"));
+    }
+}
+
+#[test]
+fn fatal_flushes_sinks() {
+    // the fatal macro exits the process, so it has to be sent by a child process
+    if std::env::var_os(CHILD_VAR).is_some() {
+        init_logger(None, None, None);
+        add_sink(BufferedSink(Mutex::new(vec![])));
+
+        fatal!("Main"; "Something went terribly wrong!");
+    }
+
+    let output = Command::new(std::env::current_exe().unwrap_or_else(|error| panic!("{error}")))
+        .args(["--exact", "fatal_flushes_sinks", "--nocapture"])
+        .env(CHILD_VAR, "1")
+        .output()
+        .unwrap_or_else(|error| panic!("{error}"));
+    let lines = fs::read_to_string(FLUSH_FILE).unwrap_or_else(|error| panic!("{error}"));
+    fs::remove_file(FLUSH_FILE).unwrap_or_else(|error| panic!("{error}"));
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(lines, "true Something went terribly wrong!");
+}
@@ -0,0 +1,44 @@
+#![cfg(not(feature = "wasm"))]
+
+use std::{
+    fs,
+    path::PathBuf,
+};
+
+use goolog::*;
+
+#[test]
+fn logs_routed_per_tenant() {
+    let logs_dir = PathBuf::from("resolver_logs");
+    set_file_path_resolver(Some(
+        &|record| match record.target().strip_prefix("tenant:") {
+            Some(tenant) => PathBuf::from("resolver_logs/tenants").join(format!("{tenant}.log")),
+            None => PathBuf::from("resolver_logs/main.log"),
+        },
+    ));
+    // every switch between the tenants closes the file of the other one
+    set_max_open_files(1);
+    init_logger(None, None, Some(logs_dir.join("main.log")));
+
+    info!("tenant:a"; "first of a");
+    info!("tenant:b"; "first of b");
+    info!("tenant:a"; "second of a");
+    info!("Main"; "not routed");
+
+    let read = |path: PathBuf| fs::read_to_string(path).unwrap_or_else(|error| panic!("{error}"));
+    let tenant_a = read(logs_dir.join("tenants/a.log"));
+    let tenant_b = read(logs_dir.join("tenants/b.log"));
+    let main = read(logs_dir.join("main.log"));
+    fs::remove_dir_all(&logs_dir).unwrap_or_else(|error| panic!("{error}"));
+
+    assert_eq!(tenant_a.lines().count(), 2, "{tenant_a:?}");
+    assert!(
+        tenant_a.contains("first of a") && tenant_a.contains("second of a"),
+        "{tenant_a:?}"
+    );
+    assert_eq!(tenant_b.lines().count(), 1, "{tenant_b:?}");
+    assert!(tenant_b.contains("first of b"), "{tenant_b:?}");
+    assert_eq!(main.lines().count(), 1, "{main:?}");
+    assert!(main.contains("not routed"), "{main:?}");
+    assert!(effective_config().file_path_resolver);
+}
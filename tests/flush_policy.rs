@@ -0,0 +1,58 @@
+#![cfg(not(feature = "wasm"))]
+
+use std::{
+    sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+    time::Duration,
+};
+
+use goolog::{
+    log::{
+        Level,
+        Record,
+    },
+    *,
+};
+
+/// A sink counting how often it has been flushed.
+struct FlushingSink;
+impl Sink for FlushingSink {
+    fn log(&self, _record: &Record, _fatal: bool) {}
+
+    fn flush(&self) {
+        FLUSHED.fetch_add(1, Ordering::Relaxed);
+    }
+}
+/// The number of times the [`FlushingSink`] has been flushed.
+static FLUSHED: AtomicUsize = AtomicUsize::new(0);
+
+/// Send an info and an error log and get how often the sink has been flushed because of them.
+fn flushes() -> usize {
+    let before = FLUSHED.load(Ordering::Relaxed);
+    info!("Main"; "Hello World!");
+    error!("Main"; "Something went wrong!");
+    FLUSHED.load(Ordering::Relaxed) - before
+}
+
+#[test]
+fn sinks_flushed_by_policy() {
+    init_logger(None, None, None);
+    add_sink(FlushingSink);
+
+    assert_eq!(flush_policy(), FlushPolicy::Never);
+    assert_eq!(flushes(), 0);
+    set_flush_policy(FlushPolicy::Always);
+    assert_eq!(flushes(), 2);
+    set_flush_policy(FlushPolicy::OnLevel(Level::Error));
+    assert_eq!(flushes(), 1);
+    set_flush_policy(FlushPolicy::OnInterval(Duration::from_secs(3600)));
+    // the first log after a long time flushes the sinks, but the next one is too early
+    assert_eq!(flushes(), 1);
+    assert_eq!(flushes(), 0);
+    assert_eq!(
+        effective_config().flush_policy,
+        FlushPolicy::OnInterval(Duration::from_secs(3600))
+    );
+}
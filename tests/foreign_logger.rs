@@ -0,0 +1,54 @@
+// without the `fatal-exit` feature, the fatal macro panics instead of exiting
+#![cfg(all(not(feature = "wasm"), feature = "fatal-exit"))]
+
+use std::process::Command;
+
+use goolog::*;
+use log::{
+    Log,
+    Metadata,
+    Record,
+};
+
+/// The environment variable telling the test to act as the child process sending the fatal log.
+const CHILD_VAR: &str = "GOOLOG_FOREIGN_LOGGER_CHILD";
+
+/// A logger which is not the goolog logger, printing every log to stderr.
+struct ForeignLogger;
+impl Log for ForeignLogger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        eprintln!("{}: {}", record.target(), record.args());
+    }
+
+    fn flush(&self) {}
+}
+
+#[test]
+fn fatal_marker_not_sent_to_foreign_logger() {
+    // the fatal macro exits the process, so it has to be sent by a child process
+    if std::env::var_os(CHILD_VAR).is_some() {
+        log::set_logger(&ForeignLogger).unwrap_or_else(|error| panic!("{error}"));
+        log::set_max_level(log::LevelFilter::Trace);
+
+        fatal!("Main"; "Something went terribly wrong!");
+    }
+
+    let output = Command::new(std::env::current_exe().unwrap_or_else(|error| panic!("{error}")))
+        .args([
+            "--exact",
+            "fatal_marker_not_sent_to_foreign_logger",
+            "--nocapture",
+        ])
+        .env(CHILD_VAR, "1")
+        .output()
+        .unwrap_or_else(|error| panic!("{error}"));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr.contains("Main: Something went terribly wrong!"));
+    assert!(!stderr.contains("$goolog:fatal="));
+}
@@ -0,0 +1,54 @@
+use goolog::*;
+
+#[test]
+fn capture_logs() {
+    let capture = init_for_test();
+
+    trace!("Main"; "Hello World!");
+    info!("MySuperAwesomeMCManageClient"; "Hello World!");
+
+    let lines = capture.lines();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].ends_with("Main       | TRACE | Hello World!"));
+    assert!(lines[1].ends_with("MySuperAwe | INFO  | Hello World!"));
+    assert!(!lines.iter().any(|line| line.contains('\x1b')));
+
+    assert_logged!(capture, log::Level::Info, contains "Hello World!");
+    assert!(!capture.logged(log::Level::Error, "Hello World!"));
+    let message = std::panic::catch_unwind(|| {
+        assert_logged!(capture, log::Level::Error, contains "disk full");
+    })
+    .err()
+    .and_then(|payload| payload.downcast::<String>().ok())
+    .unwrap_or_else(|| panic!("the assertion should have failed"));
+    assert!(message.contains("no log line at the ERROR level containing \"disk full\""));
+    assert!(message.contains("MySuperAwe | INFO  | Hello World!"));
+
+    assert_eq!(
+        sinks(),
+        [SinkInfo {
+            name: "test capture".to_string(),
+            level: log::LevelFilter::Trace
+        }]
+    );
+
+    capture.clear();
+    assert!(capture.lines().is_empty());
+
+    // the captured lines go through the same pipeline as the console, including transactions
+    transaction(|| {
+        info!("Main"; "grouped");
+        assert!(!capture.contains("grouped"));
+    });
+    assert!(capture.contains("Main       | INFO  | grouped"));
+    // the logger counts as initiated using the configuration of the helper
+    #[cfg(not(feature = "wasm"))]
+    try_init_logger(
+        Some(log::LevelFilter::Trace),
+        Some(TEST_TARGET_LENGTH),
+        None,
+    )
+    .unwrap_or_else(|error| panic!("{error}"));
+    #[cfg(not(feature = "wasm"))]
+    assert!(try_init_logger(Some(log::LevelFilter::Info), Some(TEST_TARGET_LENGTH), None).is_err());
+}
@@ -0,0 +1,48 @@
+#![cfg(not(feature = "wasm"))]
+
+use std::{
+    fmt::{
+        self,
+        Display,
+    },
+    process::Command,
+};
+
+use goolog::*;
+
+/// The environment variable telling the test to act as the child process sending the logs.
+const CHILD_VAR: &str = "GOOLOG_NESTED_CHILD";
+
+/// A message argument sending logs while being formatted.
+struct Chatty;
+impl Display for Chatty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        info!("Nested"; "printed");
+        info!("Muted"; "leaked");
+        trace!("Nested"; "too verbose");
+        f.write_str("outer")
+    }
+}
+
+#[test]
+fn nested_logs_filtered() {
+    // nested logs are printed to stderr directly, so they have to be sent by a child process
+    if std::env::var_os(CHILD_VAR).is_some() {
+        init_logger(None, None, None);
+        let _guard = mute_target("Muted");
+        info!("Main"; "{}", Chatty);
+        return;
+    }
+
+    let output = Command::new(std::env::current_exe().unwrap_or_else(|error| panic!("{error}")))
+        .args(["--exact", "nested_logs_filtered", "--nocapture"])
+        .env(CHILD_VAR, "1")
+        .output()
+        .unwrap_or_else(|error| panic!("{error}"));
+
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Nested | INFO | printed"), "{stderr}");
+    assert!(!stderr.contains("leaked"), "{stderr}");
+    assert!(!stderr.contains("too verbose"), "{stderr}");
+}
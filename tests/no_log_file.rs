@@ -0,0 +1,20 @@
+#![cfg(not(feature = "wasm"))]
+
+use goolog::*;
+
+#[test]
+fn no_log_file_set() {
+    let capture = init_for_test();
+
+    info!("Main"; "Hello World!");
+    info!("MySuperAwesomeMCManageClient"; "Hello World!");
+
+    assert_logged!(capture, log::Level::Info, contains "Main       | INFO  | Hello World!");
+    assert_logged!(capture, log::Level::Info, contains "MySuperAwe | INFO  | Hello World!");
+
+    // calling the helper again must neither panic nor exit, but hand out the same capture
+    let again = init_for_test();
+    info!("Main"; "Hello again!");
+    assert!(capture.contains("Main       | INFO  | Hello again!"));
+    assert_eq!(again.lines(), capture.lines());
+}
@@ -0,0 +1,31 @@
+#![cfg(not(feature = "wasm"))]
+
+use std::{
+    fs,
+    path::PathBuf,
+};
+
+use goolog::*;
+
+#[test]
+fn replay_buffered_logs() {
+    let log_file_path = PathBuf::from("pre_init_logs/main.log");
+
+    pre_init();
+    info!("Main"; "First");
+    info!("Main"; "Second");
+
+    init_logger(None, None, Some(log_file_path.clone()));
+    info!("Main"; "Third");
+
+    let log = fs::read_to_string(&log_file_path)
+        .unwrap_or_else(|erro| fatal!("Main"; "Could not read the log file. Error: {erro}"));
+    fs::remove_dir_all("pre_init_logs")
+        .unwrap_or_else(|erro| fatal!("Main"; "Could not remove the log file. Error: {erro}"));
+
+    let messages: Vec<&str> = log
+        .lines()
+        .filter_map(|line| line.rsplit(" | ").next())
+        .collect();
+    assert_eq!(messages, ["First", "Second", "Third"]);
+}
@@ -0,0 +1,37 @@
+#![cfg(not(feature = "wasm"))]
+
+use std::{
+    fs,
+    path::PathBuf,
+};
+
+use goolog::*;
+
+#[test]
+fn reopen_after_external_rotation() {
+    let logs_dir = PathBuf::from("reopen_logs");
+    let log_file = logs_dir.join("main.log");
+    let moved_file = logs_dir.join("main.log.1");
+
+    init_logger(None, None, Some(log_file.clone()));
+
+    info!("Main"; "before the rotation");
+    // this is what an external tool like logrotate does
+    fs::rename(&log_file, &moved_file).unwrap_or_else(|error| panic!("{error}"));
+    info!("Main"; "still written to the moved file");
+    reopen_files().unwrap_or_else(|error| panic!("{error}"));
+    info!("Main"; "after the rotation");
+
+    let moved = fs::read_to_string(&moved_file).unwrap_or_else(|error| panic!("{error}"));
+    let current = fs::read_to_string(&log_file).unwrap_or_else(|error| panic!("{error}"));
+    fs::remove_dir_all(&logs_dir).unwrap_or_else(|error| panic!("{error}"));
+
+    assert_eq!(moved.lines().count(), 2, "{moved:?}");
+    assert!(moved.contains("before the rotation"), "{moved:?}");
+    assert!(
+        moved.contains("still written to the moved file"),
+        "{moved:?}"
+    );
+    assert_eq!(current.lines().count(), 1, "{current:?}");
+    assert!(current.contains("after the rotation"), "{current:?}");
+}
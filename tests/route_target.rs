@@ -0,0 +1,61 @@
+#![cfg(not(feature = "wasm"))]
+
+use std::sync::Mutex;
+
+use goolog::{
+    log::Record,
+    *,
+};
+
+/// A sink remembering the messages of every log sent to it.
+struct NamedSink(&'static str, &'static Mutex<Vec<String>>);
+impl Sink for NamedSink {
+    fn log(&self, record: &Record, _fatal: bool) {
+        self.1
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(record.args().to_string());
+    }
+
+    fn name(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+#[test]
+fn targets_routed_to_sinks() {
+    static AUDIT: Mutex<Vec<String>> = Mutex::new(vec![]);
+    static OTHER: Mutex<Vec<String>> = Mutex::new(vec![]);
+
+    init_logger(None, None, None);
+    add_sink(NamedSink("audit", &AUDIT));
+    add_sink(NamedSink("other", &OTHER));
+    route_target("Audit", "audit", RouteMode::Only);
+    route_target("Billing", "audit", RouteMode::Also);
+
+    let console = capture(|| {
+        info!("Audit"; "alice logged in");
+        info!("Billing"; "invoice sent");
+        info!("Main"; "Hello World!");
+        debug!("Audit"; "below the log level");
+    });
+    clear_route("Audit");
+    info!("Audit"; "no longer routed");
+
+    let lines = |sink: &Mutex<Vec<String>>| {
+        sink.lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    };
+    assert_eq!(lines(&AUDIT), ["alice logged in", "invoice sent"]);
+    assert_eq!(
+        lines(&OTHER),
+        ["invoice sent", "Hello World!", "no longer routed"]
+    );
+    assert_eq!(console.len(), 2, "{console:?}");
+    assert!(console[0].ends_with("| invoice sent"), "{console:?}");
+    assert_eq!(
+        target_route("Billing"),
+        Some((vec!["audit".to_string()], RouteMode::Also))
+    );
+}
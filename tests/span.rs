@@ -0,0 +1,49 @@
+#![cfg(not(feature = "wasm"))]
+
+use goolog::*;
+
+#[test]
+fn spans_nest_and_log_their_duration() {
+    let capture = init_for_test();
+
+    {
+        let request = span!("Server"; "request", id = 42, user = "alice");
+        assert_eq!(current_span(), Some((request.id(), "request".to_string())));
+        info!("Server"; "parsing");
+        {
+            let query = span!("Database"; "query");
+            assert!(query.id() > request.id());
+            assert_eq!(
+                current_span().map(|(_, name)| name),
+                Some("query".to_string())
+            );
+            debug!("Database"; "fetching");
+        }
+        assert_eq!(
+            current_span().map(|(_, name)| name),
+            Some("request".to_string())
+        );
+    }
+    assert_eq!(current_span(), None);
+
+    let lines = capture.lines();
+    assert_eq!(lines.len(), 6, "{lines:?}");
+    assert!(
+        lines[0].ends_with("| INFO  | request (span #1, id=42, user=alice) started"),
+        "{lines:?}"
+    );
+    assert!(lines[1].ends_with("| INFO  |   parsing"), "{lines:?}");
+    assert!(
+        lines[2].ends_with("| INFO  |   query (span #2) started"),
+        "{lines:?}"
+    );
+    assert!(lines[3].ends_with("| DEBUG |     fetching"), "{lines:?}");
+    assert!(
+        lines[4].contains("| INFO  |   query (span #2) finished in "),
+        "{lines:?}"
+    );
+    assert!(
+        lines[5].contains("| INFO  | request (span #1) finished in "),
+        "{lines:?}"
+    );
+}
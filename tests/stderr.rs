@@ -0,0 +1,32 @@
+#![cfg(not(feature = "wasm"))]
+
+use std::process::Command;
+
+use goolog::*;
+
+/// The environment variable telling the test to act as the child process sending the log.
+const CHILD_VAR: &str = "GOOLOG_STDERR_CHILD";
+
+#[test]
+fn logs_printed_to_stderr() {
+    // the output of the test itself cannot be captured, so the log has to be sent by a child process
+    if std::env::var_os(CHILD_VAR).is_some() {
+        init_logger_stderr(None, None, None);
+        set_colors_enabled(false);
+        info!("Main"; "Hello World!");
+        assert_eq!(sinks()[0].name, "stderr");
+        return;
+    }
+
+    let output = Command::new(std::env::current_exe().unwrap_or_else(|error| panic!("{error}")))
+        .args(["--exact", "logs_printed_to_stderr", "--nocapture"])
+        .env(CHILD_VAR, "1")
+        .output()
+        .unwrap_or_else(|error| panic!("{error}"));
+
+    assert!(output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("Main             | INFO  | Hello World!")
+    );
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("Hello World!"));
+}
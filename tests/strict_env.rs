@@ -0,0 +1,24 @@
+#![cfg(not(feature = "wasm"))]
+
+use goolog::*;
+
+#[test]
+fn strict_env_rejects_invalid_values() {
+    // this is the only test of this process, so no other test reads the environment variable
+    std::env::set_var("GOOLOG_TARGET_LENGTH", "sixteen");
+
+    set_strict_env(true);
+    assert!(matches!(
+        try_init_logger(None, None, None),
+        Err(InitError::InvalidEnvVar {
+            name: "GOOLOG_TARGET_LENGTH",
+            value,
+        }) if value == "sixteen"
+    ));
+    // the logger has not been set up
+    assert!(sinks().is_empty());
+
+    set_strict_env(false);
+    try_init_logger(None, None, None).unwrap_or_else(|error| panic!("{error}"));
+    assert_eq!(effective_config().max_name_length, 16);
+}
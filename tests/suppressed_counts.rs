@@ -0,0 +1,33 @@
+#![cfg(not(feature = "wasm"))]
+
+use goolog::*;
+
+#[test]
+fn level_filtered_logs_are_counted() {
+    init_logger(Some(log::LevelFilter::Info), None, None);
+
+    debug!("Main"; "not counted before counting has been enabled");
+    assert_eq!(suppressed_counts(), [0; 5]);
+    set_count_suppressed(true);
+    assert!(effective_config().count_suppressed);
+
+    {
+        let _guard = mute_target("Muted");
+        debug!("Muted"; "muted logs are not counted");
+        info!("Muted"; "neither are the ones passing the level");
+    }
+    for _ in 0..3 {
+        debug!("Main"; "suppressed");
+    }
+    trace!("Main"; "suppressed");
+    info!("Main"; "printed");
+    log_at!(std::panic::Location::caller(); log::Level::Trace; "Main"; "suppressed");
+
+    assert_eq!(suppressed_counts(), [0, 0, 0, 4, 2]);
+    assert_eq!(effective_config().suppressed_counts, [0, 0, 0, 4, 2]);
+
+    // the macros taking the level as an argument are counted as well
+    leveled!(log::Level::Trace; "Main"; "suppressed");
+    log_backtrace!(log::Level::Trace; "Main"; "suppressed");
+    assert_eq!(suppressed_counts(), [0, 0, 0, 4, 4]);
+}
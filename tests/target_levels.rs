@@ -0,0 +1,70 @@
+#![cfg(not(feature = "wasm"))]
+
+use goolog::*;
+use log::LevelFilter;
+
+#[test]
+fn target_levels_adjusted_at_runtime() {
+    set_target_level("Verbose", LevelFilter::Debug);
+    init_logger(None, None, None);
+    set_count_suppressed(true);
+    set_target_level("Quiet", LevelFilter::Warn);
+
+    assert_eq!(
+        target_levels(),
+        [
+            ("Quiet".to_string(), LevelFilter::Warn),
+            ("Verbose".to_string(), LevelFilter::Debug)
+        ]
+    );
+    let lines = capture(|| {
+        debug!("Verbose"; "printed");
+        trace!("Verbose"; "too verbose");
+        debug!("Other"; "not printed");
+        info!("Quiet"; "not printed");
+        warn!("Quiet"; "printed");
+    });
+    assert_eq!(lines.len(), 2, "{lines:?}");
+    assert!(
+        lines[0].ends_with("Verbose          | DEBUG | printed"),
+        "{lines:?}"
+    );
+    assert!(
+        lines[1].ends_with("Quiet            | WARN  | printed"),
+        "{lines:?}"
+    );
+    assert!(!would_log(log::Level::Info, "Quiet"));
+
+    clear_target_level("Quiet");
+    clear_target_level("Verbose");
+    assert_eq!(target_level("Verbose"), None);
+    assert!(target_levels().is_empty());
+    let lines = capture(|| {
+        debug!("Verbose"; "not printed");
+        info!("Quiet"; "printed");
+    });
+    assert_eq!(lines.len(), 1, "{lines:?}");
+    assert!(
+        lines[0].ends_with("Quiet            | INFO  | printed"),
+        "{lines:?}"
+    );
+    assert!(suppressed_counts()[3] >= 2, "{:?}", suppressed_counts());
+    assert_eq!(log::max_level(), LevelFilter::Info);
+
+    // a level more verbose than the one of the logger takes effect even after the logger has been initiated
+    set_target_level("Late", LevelFilter::Trace);
+    assert!(would_log(log::Level::Trace, "Late"));
+    let lines = capture(|| {
+        trace!("Late"; "printed");
+        trace!("Other"; "not printed");
+    });
+    assert_eq!(lines.len(), 1, "{lines:?}");
+    assert!(
+        lines[0].ends_with("Late             | TRACE | printed"),
+        "{lines:?}"
+    );
+
+    clear_target_level("Late");
+    assert_eq!(log::max_level(), LevelFilter::Info);
+    assert!(!would_log(log::Level::Trace, "Late"));
+}
@@ -0,0 +1,86 @@
+#![cfg(not(feature = "wasm"))]
+
+use std::{
+    process::Command,
+    thread,
+};
+
+use goolog::*;
+
+/// The environment variable telling the test to act as the child process logging from several threads.
+const CHILD_VAR: &str = "GOOLOG_TRANSACTION_CHILD";
+/// The number of lines every transaction sends.
+const LINES: usize = 20;
+
+#[test]
+fn transaction_lines_stay_contiguous() {
+    // the console output can only be observed from a parent process
+    if std::env::var_os(CHILD_VAR).is_some() {
+        init_logger(None, None, None);
+
+        let noise = thread::spawn(|| {
+            for line in 0..500 {
+                info!("Noise"; "line {line}");
+            }
+        });
+        let groups: Vec<_> = (0..4)
+            .map(|group| {
+                thread::spawn(move || {
+                    for _ in 0..5 {
+                        transaction(|| {
+                            for line in 0..LINES {
+                                info!("Group"; "group {group} line {line}");
+                                thread::yield_now();
+                            }
+                        });
+                    }
+                })
+            })
+            .collect();
+        for handle in groups.into_iter().chain([noise]) {
+            handle
+                .join()
+                .unwrap_or_else(|_| panic!("a logging thread panicked"));
+        }
+        return;
+    }
+
+    let output = Command::new(std::env::current_exe().unwrap_or_else(|error| panic!("{error}")))
+        .args([
+            "--exact",
+            "transaction_lines_stay_contiguous",
+            "--nocapture",
+        ])
+        .env(CHILD_VAR, "1")
+        .output()
+        .unwrap_or_else(|error| panic!("{error}"));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{stdout}");
+
+    let lines: Vec<_> = stdout
+        .lines()
+        .filter(|line| line.contains("Noise  ") || line.contains("Group  "))
+        .collect();
+    assert_eq!(lines.len(), 500 + 4 * 5 * LINES);
+    let mut index = 0;
+    while index < lines.len() {
+        if lines[index].contains("Noise  ") {
+            index += 1;
+            continue;
+        }
+        // every transaction is printed as one block of its lines in order
+        let group = lines[index]
+            .split("group ")
+            .nth(1)
+            .and_then(|rest| rest.split(' ').next())
+            .unwrap_or_else(|| panic!("{}", lines[index]));
+        for line in 0..LINES {
+            assert!(
+                lines[index + line].ends_with(&format!("group {group} line {line}")),
+                "{}",
+                lines[index..].join("\n")
+            );
+        }
+        index += LINES;
+    }
+}
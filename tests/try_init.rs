@@ -0,0 +1,46 @@
+#![cfg(not(feature = "wasm"))]
+
+use std::sync::{
+    Arc,
+    Barrier,
+};
+
+use goolog::*;
+
+#[test]
+fn racing_initiations() {
+    const THREADS: usize = 32;
+
+    let barrier = Arc::new(Barrier::new(THREADS));
+    let threads: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let barrier = barrier.clone();
+            std::thread::spawn(move || {
+                barrier.wait();
+                try_init_logger(Some(log::LevelFilter::Debug), Some(12), None)
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread
+            .join()
+            .unwrap_or_else(|_| panic!("initiating thread panicked"))
+            .unwrap_or_else(|error| panic!("{error}"));
+    }
+
+    // the logger has been set up exactly once
+    assert_eq!(
+        sinks(),
+        [SinkInfo {
+            name: "stdout".to_string(),
+            level: log::LevelFilter::Debug
+        }]
+    );
+    assert_eq!(log::max_level(), log::LevelFilter::Debug);
+
+    assert!(try_init_logger(Some(log::LevelFilter::Debug), Some(12), None).is_ok());
+    assert!(matches!(
+        try_init_logger(Some(log::LevelFilter::Trace), Some(12), None),
+        Err(InitError::ConfigMismatch)
+    ));
+}
@@ -0,0 +1,64 @@
+#![cfg(not(feature = "wasm"))]
+
+use std::process::{
+    Command,
+    Output,
+};
+
+use goolog::*;
+
+/// The environment variable telling a test to act as the child process sending the logs.
+const CHILD_VAR: &str = "GOOLOG_UNINIT_CHILD";
+
+/// Run the given `test` in a child process and get its output.
+fn run_child(test: &str) -> Output {
+    Command::new(std::env::current_exe().unwrap_or_else(|error| panic!("{error}")))
+        .args(["--exact", test, "--nocapture"])
+        .env(CHILD_VAR, "1")
+        .output()
+        .unwrap_or_else(|error| panic!("{error}"))
+}
+
+#[test]
+fn uninit_stderr() {
+    // the output of the test itself cannot be captured, so the logs have to be sent by a child process
+    if std::env::var_os(CHILD_VAR).is_some() {
+        set_uninitialized_behavior(Uninit::Stderr);
+        info!("Main"; "Sent too early!");
+
+        init_logger_stderr(None, None, None);
+        set_colors_enabled(false);
+        info!("Main"; "Sent after init!");
+        return;
+    }
+
+    let output = run_child("uninit_stderr");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success());
+    assert!(stderr.contains("Main | INFO | Sent too early!"), "{stderr}");
+    assert!(
+        stderr.contains("Main             | INFO  | Sent after init!"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn uninit_panic() {
+    if std::env::var_os(CHILD_VAR).is_some() {
+        set_uninitialized_behavior(Uninit::Panic);
+        info!("Main"; "Sent too early!");
+        return;
+    }
+
+    let output = run_child("uninit_panic");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(!output.status.success());
+    assert!(
+        stdout.contains("FAILED") || !stdout.contains("test result: ok"),
+        "{stdout}"
+    );
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("A log has been sent before the goolog logger has been initiated: Main | INFO | Sent too early!"));
+}
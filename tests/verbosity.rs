@@ -0,0 +1,12 @@
+#![cfg(not(feature = "wasm"))]
+
+use goolog::*;
+
+#[test]
+fn level_raised_by_verbosity() {
+    // `-vv` on top of a warn base level
+    init_logger_from_verbosity(2, Some(log::LevelFilter::Warn), None, None);
+
+    assert_eq!(log::max_level(), log::LevelFilter::Debug);
+    assert_eq!(sinks()[0].level, log::LevelFilter::Debug);
+}